@@ -59,6 +59,14 @@ impl sRGBColor {
         let max = self.R.max(self.G).max(self.B);
         (self.R / max, self.G / max, self.B / max)
     }
+
+    pub(crate) fn scaled_per_channel(&self, factors: [f64; 3]) -> sRGBColor {
+        sRGBColor {
+            R: self.R * factors[0],
+            G: self.G * factors[1],
+            B: self.B * factors[2],
+        }
+    }
 }
 
 impl Serialize for sRGBColor {
@@ -5,6 +5,9 @@ pub enum AstroUtilError {
     AstroCoordsError(astro_coords::error::AstroCoordsError),
     DataNotAvailable(String),
     GaiaAccess(gaia_access::error::GaiaError),
+    InvalidIntegration(String),
+    InvalidOrbit(String),
+    InvalidSpectralType(String),
     Io(std::io::Error),
     Json(serde_json::Error),
     MutexPoison,
@@ -19,6 +22,11 @@ impl fmt::Display for AstroUtilError {
             AstroUtilError::AstroCoordsError(err) => write!(f, "AstroCoords error: {}", err),
             AstroUtilError::DataNotAvailable(data) => write!(f, "Data {} not available", data),
             AstroUtilError::GaiaAccess(err) => write!(f, "Gaia access error: {:?}", err),
+            AstroUtilError::InvalidIntegration(err) => write!(f, "Invalid integration: {}", err),
+            AstroUtilError::InvalidOrbit(err) => write!(f, "Invalid orbit: {}", err),
+            AstroUtilError::InvalidSpectralType(notation) => {
+                write!(f, "Invalid spectral type: {}", notation)
+            }
             AstroUtilError::Io(err) => write!(f, "I/O error: {}", err),
             AstroUtilError::Json(err) => write!(f, "JSON error: {}", err),
             AstroUtilError::MutexPoison => write!(f, "Mutex poisoned"),
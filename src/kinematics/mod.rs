@@ -0,0 +1,3 @@
+pub mod state_vector;
+pub(crate) mod vector_math;
+pub mod velocity_vector;
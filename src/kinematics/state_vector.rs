@@ -0,0 +1,32 @@
+use astro_coords::cartesian::Cartesian;
+use serde::{Deserialize, Serialize};
+
+use crate::astro_display::AstroDisplay;
+
+use super::velocity_vector::VelocityVector;
+
+/*
+ * Position and velocity of a body relative to some reference body, as used for instance by simulations.
+ * https://en.wikipedia.org/wiki/Orbital_state_vectors
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateVector {
+    pub position: Cartesian,
+    pub velocity: VelocityVector,
+}
+
+impl StateVector {
+    pub const fn new(position: Cartesian, velocity: VelocityVector) -> Self {
+        Self { position, velocity }
+    }
+}
+
+impl AstroDisplay for StateVector {
+    fn astro_display(&self) -> String {
+        format!(
+            "position: {}, velocity: {}",
+            self.position,
+            self.velocity.astro_display()
+        )
+    }
+}
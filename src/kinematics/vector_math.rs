@@ -0,0 +1,30 @@
+// Plain vector arithmetic on dimensionless components, for algorithms that mix lengths and velocities.
+
+pub(crate) fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn norm(a: &[f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+pub(crate) fn scaled(a: &[f64; 3], factor: f64) -> [f64; 3] {
+    a.map(|component| component * factor)
+}
+
+pub(crate) fn normalized(a: &[f64; 3]) -> [f64; 3] {
+    scaled(a, 1. / norm(a))
+}
+
+// The angle from a to b, counted positive around the given axis.
+pub(crate) fn signed_angle(a: &[f64; 3], b: &[f64; 3], axis: &[f64; 3]) -> f64 {
+    dot(&cross(a, b), axis).atan2(dot(a, b))
+}
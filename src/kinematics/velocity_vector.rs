@@ -0,0 +1,162 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use astro_coords::{cartesian::Cartesian, direction::Direction};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{Length, Time, Velocity},
+    length::meter,
+    velocity::meter_per_second,
+};
+
+use crate::astro_display::AstroDisplay;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VelocityVector {
+    pub x: Velocity,
+    pub y: Velocity,
+    pub z: Velocity,
+}
+
+impl VelocityVector {
+    pub const fn new(x: Velocity, y: Velocity, z: Velocity) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        let zero = Velocity::new::<meter_per_second>(0.);
+        Self::new(zero, zero, zero)
+    }
+
+    pub fn from_direction(direction: &Direction, speed: Velocity) -> Self {
+        let unit = direction.to_cartesian(Length::new::<meter>(1.));
+        Self::new(
+            speed * unit.x.get::<meter>(),
+            speed * unit.y.get::<meter>(),
+            speed * unit.z.get::<meter>(),
+        )
+    }
+
+    pub fn from_displacement(displacement: &Cartesian, time: Time) -> Self {
+        Self::new(
+            displacement.x / time,
+            displacement.y / time,
+            displacement.z / time,
+        )
+    }
+
+    pub fn length(&self) -> Velocity {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.x.value == 0. && self.y.value == 0. && self.z.value == 0.
+    }
+
+    pub fn displacement(&self, time: Time) -> Cartesian {
+        Cartesian::new(self.x * time, self.y * time, self.z * time)
+    }
+}
+
+impl Default for VelocityVector {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl AstroDisplay for VelocityVector {
+    fn astro_display(&self) -> String {
+        format!(
+            "({}, {}, {})",
+            self.x.astro_display(),
+            self.y.astro_display(),
+            self.z.astro_display()
+        )
+    }
+}
+
+impl Add for &VelocityVector {
+    type Output = VelocityVector;
+
+    fn add(self, other: Self) -> VelocityVector {
+        VelocityVector::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for &VelocityVector {
+    type Output = VelocityVector;
+
+    fn sub(self, other: Self) -> VelocityVector {
+        VelocityVector::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for &VelocityVector {
+    type Output = VelocityVector;
+
+    fn neg(self) -> VelocityVector {
+        VelocityVector::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for &VelocityVector {
+    type Output = VelocityVector;
+
+    fn mul(self, scalar: f64) -> VelocityVector {
+        VelocityVector::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{length::kilometer, time::second, velocity::kilometer_per_second};
+
+    use crate::tests::eq;
+
+    use super::*;
+
+    #[test]
+    fn zero_velocity_does_not_displace() {
+        let displacement = VelocityVector::zero().displacement(Time::new::<second>(1e9));
+        assert!(eq(displacement.length().get::<meter>(), 0.));
+    }
+
+    #[test]
+    fn velocity_along_direction_has_requested_speed() {
+        let speed = Velocity::new::<kilometer_per_second>(12.3);
+        let velocity = VelocityVector::from_direction(&Direction::Y, speed);
+        assert!(eq(
+            velocity.length().get::<kilometer_per_second>(),
+            speed.get::<kilometer_per_second>()
+        ));
+        assert!(eq(velocity.x.get::<kilometer_per_second>(), 0.));
+        assert!(eq(velocity.y.get::<kilometer_per_second>(), 12.3));
+        assert!(eq(velocity.z.get::<kilometer_per_second>(), 0.));
+    }
+
+    #[test]
+    fn displacement_is_velocity_times_time() {
+        let velocity = VelocityVector::new(
+            Velocity::new::<kilometer_per_second>(1.),
+            Velocity::new::<kilometer_per_second>(-2.),
+            Velocity::new::<kilometer_per_second>(3.),
+        );
+        let displacement = velocity.displacement(Time::new::<second>(10.));
+        assert!(eq(displacement.x.get::<kilometer>(), 10.));
+        assert!(eq(displacement.y.get::<kilometer>(), -20.));
+        assert!(eq(displacement.z.get::<kilometer>(), 30.));
+    }
+
+    #[test]
+    fn velocity_from_displacement_roundtrip() {
+        let velocity = VelocityVector::new(
+            Velocity::new::<kilometer_per_second>(4.),
+            Velocity::new::<kilometer_per_second>(0.),
+            Velocity::new::<kilometer_per_second>(-7.),
+        );
+        let time = Time::new::<second>(3.);
+        let recovered = VelocityVector::from_displacement(&velocity.displacement(time), time);
+        assert!(eq(recovered.x.get::<kilometer_per_second>(), 4.));
+        assert!(eq(recovered.y.get::<kilometer_per_second>(), 0.));
+        assert!(eq(recovered.z.get::<kilometer_per_second>(), -7.));
+    }
+}
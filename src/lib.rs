@@ -3,6 +3,8 @@
 pub mod astro_display;
 pub mod color;
 pub mod error;
+pub mod kinematics;
+pub mod n_body;
 pub mod planets;
 pub mod real_data;
 pub mod stars;
@@ -0,0 +1,38 @@
+use astro_coords::cartesian::Cartesian;
+use serde::{Deserialize, Serialize};
+use uom::si::f64::Mass;
+
+use crate::kinematics::{state_vector::StateVector, velocity_vector::VelocityVector};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Body {
+    pub(super) name: String,
+    pub(super) mass: Mass,
+    pub(super) state: StateVector,
+}
+
+impl Body {
+    pub fn new(name: String, mass: Mass, state: StateVector) -> Self {
+        Self { name, mass, state }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_mass(&self) -> Mass {
+        self.mass
+    }
+
+    pub fn get_state(&self) -> &StateVector {
+        &self.state
+    }
+
+    pub fn get_position(&self) -> &Cartesian {
+        &self.state.position
+    }
+
+    pub fn get_velocity(&self) -> &VelocityVector {
+        &self.state.velocity
+    }
+}
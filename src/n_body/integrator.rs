@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use uom::si::{f64::Time, time::second};
+
+use crate::{error::AstroUtilError, planets::kepler_orbit::GRAVITATIONAL_CONSTANT};
+
+const MAX_STEPS: usize = 10_000_000;
+
+/*
+ * The leapfrog integrator is symplectic: it does not conserve energy exactly, but the energy error stays bounded,
+ * which makes it suitable for long term integrations with a fixed time step.
+ * https://en.wikipedia.org/wiki/Leapfrog_integration
+ *
+ * The Dormand-Prince method is an adaptive Runge-Kutta method of order 5(4).
+ * Its tolerance is relative to the size of the system and its largest velocity.
+ * https://en.wikipedia.org/wiki/Dormand%E2%80%93Prince_method
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Integrator {
+    Leapfrog {
+        time_step: Time,
+    },
+    DormandPrince {
+        initial_time_step: Time,
+        tolerance: f64,
+    },
+}
+
+// Positions in meters and velocities in meters per second, together with the gravitational parameters in m³/s².
+#[derive(Debug, Clone)]
+pub(super) struct Phase {
+    pub(super) gravitational_parameters: Vec<f64>,
+    pub(super) positions: Vec<[f64; 3]>,
+    pub(super) velocities: Vec<[f64; 3]>,
+}
+
+impl Phase {
+    pub(super) fn new(masses_in_kg: &[f64]) -> Self {
+        Self {
+            gravitational_parameters: masses_in_kg
+                .iter()
+                .map(|mass| GRAVITATIONAL_CONSTANT * mass)
+                .collect(),
+            positions: vec![[0.; 3]; masses_in_kg.len()],
+            velocities: vec![[0.; 3]; masses_in_kg.len()],
+        }
+    }
+
+    fn accelerations(&self, positions: &[[f64; 3]]) -> Vec<[f64; 3]> {
+        let mut accelerations = vec![[0.; 3]; positions.len()];
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let separation = [0, 1, 2].map(|k| positions[j][k] - positions[i][k]);
+                let distance_squared = separation.iter().map(|s| s * s).sum::<f64>();
+                let inverse_cube = 1. / (distance_squared * distance_squared.sqrt());
+                for k in 0..3 {
+                    accelerations[i][k] +=
+                        self.gravitational_parameters[j] * separation[k] * inverse_cube;
+                    accelerations[j][k] -=
+                        self.gravitational_parameters[i] * separation[k] * inverse_cube;
+                }
+            }
+        }
+        accelerations
+    }
+
+    pub(super) fn energy_in_joule(&self) -> f64 {
+        let mut energy = 0.;
+        for i in 0..self.positions.len() {
+            let mass = self.gravitational_parameters[i] / GRAVITATIONAL_CONSTANT;
+            let speed_squared = self.velocities[i].iter().map(|v| v * v).sum::<f64>();
+            energy += 0.5 * mass * speed_squared;
+            for j in (i + 1)..self.positions.len() {
+                let distance = [0, 1, 2]
+                    .map(|k| self.positions[j][k] - self.positions[i][k])
+                    .iter()
+                    .map(|s| s * s)
+                    .sum::<f64>()
+                    .sqrt();
+                energy -= self.gravitational_parameters[i] * self.gravitational_parameters[j]
+                    / GRAVITATIONAL_CONSTANT
+                    / distance;
+            }
+        }
+        energy
+    }
+
+    fn to_flat(&self) -> Vec<f64> {
+        self.positions
+            .iter()
+            .chain(self.velocities.iter())
+            .flat_map(|v| v.iter().copied())
+            .collect()
+    }
+
+    fn set_from_flat(&mut self, flat: &[f64]) {
+        let n = self.positions.len();
+        for i in 0..n {
+            self.positions[i] = [0, 1, 2].map(|k| flat[3 * i + k]);
+            self.velocities[i] = [0, 1, 2].map(|k| flat[3 * (n + i) + k]);
+        }
+    }
+
+    fn derivative(&self, flat: &[f64]) -> Vec<f64> {
+        let n = self.positions.len();
+        let positions: Vec<[f64; 3]> = (0..n).map(|i| [0, 1, 2].map(|k| flat[3 * i + k])).collect();
+        let accelerations = self.accelerations(&positions);
+        let mut derivative = Vec::with_capacity(flat.len());
+        derivative.extend_from_slice(&flat[3 * n..]);
+        derivative.extend(accelerations.iter().flat_map(|a| a.iter().copied()));
+        derivative
+    }
+}
+
+impl Integrator {
+    pub(super) fn integrate(
+        &self,
+        phase: &mut Phase,
+        duration: Time,
+    ) -> Result<(), AstroUtilError> {
+        let duration = duration.get::<second>();
+        if duration == 0. || phase.positions.is_empty() {
+            return Ok(());
+        }
+        if !duration.is_finite() {
+            return Err(AstroUtilError::InvalidIntegration(format!(
+                "Cannot integrate over {} seconds",
+                duration
+            )));
+        }
+        match self {
+            Integrator::Leapfrog { time_step } => {
+                leapfrog(phase, duration, time_step.get::<second>().abs())
+            }
+            Integrator::DormandPrince {
+                initial_time_step,
+                tolerance,
+            } => dormand_prince(
+                phase,
+                duration,
+                initial_time_step.get::<second>().abs(),
+                *tolerance,
+            ),
+        }
+    }
+}
+
+fn leapfrog(phase: &mut Phase, duration: f64, time_step: f64) -> Result<(), AstroUtilError> {
+    if time_step <= 0. || !time_step.is_finite() {
+        return Err(AstroUtilError::InvalidIntegration(format!(
+            "Time step {} s is not positive",
+            time_step
+        )));
+    }
+    let steps = (duration.abs() / time_step).ceil();
+    if steps > MAX_STEPS as f64 {
+        return Err(AstroUtilError::InvalidIntegration(format!(
+            "Integration would take {} steps",
+            steps
+        )));
+    }
+    let steps = steps as usize;
+    let dt = duration / steps as f64;
+    let mut accelerations = phase.accelerations(&phase.positions);
+    for _ in 0..steps {
+        for (velocity, acceleration) in phase.velocities.iter_mut().zip(accelerations.iter()) {
+            for k in 0..3 {
+                velocity[k] += 0.5 * dt * acceleration[k];
+            }
+        }
+        for (position, velocity) in phase.positions.iter_mut().zip(phase.velocities.iter()) {
+            for k in 0..3 {
+                position[k] += dt * velocity[k];
+            }
+        }
+        accelerations = phase.accelerations(&phase.positions);
+        for (velocity, acceleration) in phase.velocities.iter_mut().zip(accelerations.iter()) {
+            for k in 0..3 {
+                velocity[k] += 0.5 * dt * acceleration[k];
+            }
+        }
+    }
+    Ok(())
+}
+
+// The system is autonomous, so the nodes c_i of the Butcher tableau are not needed.
+const DP_A: [[f64; 6]; 7] = [
+    [0., 0., 0., 0., 0., 0.],
+    [1. / 5., 0., 0., 0., 0., 0.],
+    [3. / 40., 9. / 40., 0., 0., 0., 0.],
+    [44. / 45., -56. / 15., 32. / 9., 0., 0., 0.],
+    [
+        19372. / 6561.,
+        -25360. / 2187.,
+        64448. / 6561.,
+        -212. / 729.,
+        0.,
+        0.,
+    ],
+    [
+        9017. / 3168.,
+        -355. / 33.,
+        46732. / 5247.,
+        49. / 176.,
+        -5103. / 18656.,
+        0.,
+    ],
+    [
+        35. / 384.,
+        0.,
+        500. / 1113.,
+        125. / 192.,
+        -2187. / 6784.,
+        11. / 84.,
+    ],
+];
+const DP_B5: [f64; 7] = [
+    35. / 384.,
+    0.,
+    500. / 1113.,
+    125. / 192.,
+    -2187. / 6784.,
+    11. / 84.,
+    0.,
+];
+const DP_B4: [f64; 7] = [
+    5179. / 57600.,
+    0.,
+    7571. / 16695.,
+    393. / 640.,
+    -92097. / 339200.,
+    187. / 2100.,
+    1. / 40.,
+];
+
+fn dormand_prince(
+    phase: &mut Phase,
+    duration: f64,
+    initial_time_step: f64,
+    tolerance: f64,
+) -> Result<(), AstroUtilError> {
+    if initial_time_step <= 0.
+        || initial_time_step.is_nan()
+        || tolerance <= 0.
+        || tolerance.is_nan()
+    {
+        return Err(AstroUtilError::InvalidIntegration(format!(
+            "Time step {} s and tolerance {} need to be positive",
+            initial_time_step, tolerance
+        )));
+    }
+    let n = phase.positions.len();
+    let direction = duration.signum();
+    let mut y = phase.to_flat();
+    let mut elapsed = 0.;
+    let mut h = initial_time_step.min(duration.abs());
+    for _ in 0..MAX_STEPS {
+        let remaining = duration.abs() - elapsed;
+        if remaining <= 0. {
+            phase.set_from_flat(&y);
+            return Ok(());
+        }
+        h = h.min(remaining);
+        let dt = direction * h;
+
+        let mut k: Vec<Vec<f64>> = Vec::with_capacity(7);
+        for coefficients in DP_A.iter() {
+            let mut y_stage = y.clone();
+            for (previous, a) in k.iter().zip(coefficients.iter()) {
+                for (value, derivative) in y_stage.iter_mut().zip(previous.iter()) {
+                    *value += dt * a * derivative;
+                }
+            }
+            k.push(phase.derivative(&y_stage));
+        }
+
+        let mut y_next = y.clone();
+        let mut error = vec![0.; y.len()];
+        for (stage, derivative) in k.iter().enumerate() {
+            for i in 0..y.len() {
+                y_next[i] += dt * DP_B5[stage] * derivative[i];
+                error[i] += dt * (DP_B5[stage] - DP_B4[stage]) * derivative[i];
+            }
+        }
+
+        let position_scale = tolerance * max_norm(&y_next[..3 * n]).max(f64::MIN_POSITIVE);
+        let velocity_scale = tolerance * max_norm(&y_next[3 * n..]).max(f64::MIN_POSITIVE);
+        let position_error = max_norm(&error[..3 * n]) / position_scale;
+        let velocity_error = max_norm(&error[3 * n..]) / velocity_scale;
+        let error = position_error.max(velocity_error);
+        if !error.is_finite() {
+            return Err(AstroUtilError::InvalidIntegration(
+                "Integration error is not finite, bodies may have collided".to_string(),
+            ));
+        }
+
+        if error <= 1. {
+            y = y_next;
+            elapsed += h;
+        }
+        let factor = if error > 0. {
+            (0.9 * error.powf(-0.2)).clamp(0.2, 5.)
+        } else {
+            5.
+        };
+        h *= factor;
+        if h <= f64::EPSILON * duration.abs() {
+            return Err(AstroUtilError::InvalidIntegration(
+                "Time step became too small".to_string(),
+            ));
+        }
+    }
+    Err(AstroUtilError::InvalidIntegration(format!(
+        "Integration did not finish within {} steps",
+        MAX_STEPS
+    )))
+}
+
+// The largest length of the three dimensional vectors stored consecutively in the slice.
+fn max_norm(flat: &[f64]) -> f64 {
+    flat.chunks(3)
+        .map(|v| v.iter().map(|x| x * x).sum::<f64>().sqrt())
+        .fold(0., f64::max)
+}
@@ -0,0 +1,150 @@
+use uom::si::f64::{Length, Time};
+
+use crate::{error::AstroUtilError, planets::planet_data::PlanetData, stars::data::StarData};
+
+use super::{integrator::Integrator, system::NBodySystem};
+
+/*
+ * Integrates a star with its planets and compares the result with the unperturbed Kepler orbits.
+ * The deviations contain the perturbations by the other planets as well as the integration error,
+ * while the relative energy drift measures only the latter.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeplerComparison {
+    pub time: Time,
+    pub position_deviations: Vec<Length>, // One per planet, measured relative to the star
+    pub relative_energy_drift: f64,
+}
+
+pub fn compare_with_kepler(
+    star: &StarData,
+    planets: &[PlanetData],
+    integrator: &Integrator,
+    start_time: Time,
+    times: &[Time],
+) -> Result<Vec<KeplerComparison>, AstroUtilError> {
+    let mut system = NBodySystem::from_star_and_planets(star, planets, start_time)?;
+    let initial_energy = system.total_energy();
+    let star_mass = system.get_bodies()[0].get_mass();
+
+    let mut comparisons = Vec::with_capacity(times.len());
+    for &time in times {
+        system.integrate_to(integrator, time)?;
+        let bodies = system.get_bodies();
+        let star_position = bodies[0].get_position();
+        let mut position_deviations = Vec::with_capacity(planets.len());
+        for (planet, body) in planets.iter().zip(bodies[1..].iter()) {
+            let kepler_position = planet.get_orbital_parameters().calculate_position(
+                planet.get_mass(),
+                star_mass,
+                time,
+            )?;
+            let integrated_position = body.get_position() - star_position;
+            position_deviations.push(integrated_position.distance(&kepler_position));
+        }
+        let energy = system.total_energy();
+        let relative_energy_drift = ((energy - initial_energy) / initial_energy).value.abs();
+        comparisons.push(KeplerComparison {
+            time,
+            position_deviations,
+            relative_energy_drift,
+        });
+    }
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{length::astronomical_unit, time::year};
+
+    use crate::real_data::{planets::*, stars::sun};
+
+    use super::*;
+
+    fn years(years: &[f64]) -> Vec<Time> {
+        years.iter().map(|&t| Time::new::<year>(t)).collect()
+    }
+
+    #[test]
+    fn single_planet_follows_kepler_orbit_with_dormand_prince() {
+        let integrator = Integrator::DormandPrince {
+            initial_time_step: Time::new::<year>(1e-3),
+            tolerance: 1e-10,
+        };
+        let comparisons = compare_with_kepler(
+            &sun().to_star_data(),
+            &[earth().to_planet_data()],
+            &integrator,
+            Time::new::<year>(0.),
+            &years(&[0.5, 1., 10.]),
+        )
+        .unwrap();
+        for comparison in comparisons {
+            let deviation = comparison.position_deviations[0].get::<astronomical_unit>();
+            println!(
+                "deviation: {} AU, energy drift: {}",
+                deviation, comparison.relative_energy_drift
+            );
+            assert!(deviation < 1e-5);
+            assert!(comparison.relative_energy_drift < 1e-8);
+        }
+    }
+
+    #[test]
+    fn single_planet_follows_kepler_orbit_with_leapfrog() {
+        let integrator = Integrator::Leapfrog {
+            time_step: Time::new::<year>(1e-4),
+        };
+        let comparisons = compare_with_kepler(
+            &sun().to_star_data(),
+            &[mercury().to_planet_data()],
+            &integrator,
+            Time::new::<year>(0.),
+            &years(&[0.5, 1., 10.]),
+        )
+        .unwrap();
+        for comparison in comparisons {
+            let deviation = comparison.position_deviations[0].get::<astronomical_unit>();
+            println!(
+                "deviation: {} AU, energy drift: {}",
+                deviation, comparison.relative_energy_drift
+            );
+            assert!(deviation < 1e-3);
+            assert!(comparison.relative_energy_drift < 1e-5);
+        }
+    }
+
+    #[test]
+    fn jupiter_perturbs_saturn() {
+        let integrator = Integrator::DormandPrince {
+            initial_time_step: Time::new::<year>(1e-2),
+            tolerance: 1e-10,
+        };
+        let times = years(&[50.]);
+        let alone = compare_with_kepler(
+            &sun().to_star_data(),
+            &[saturn().to_planet_data()],
+            &integrator,
+            Time::new::<year>(0.),
+            &times,
+        )
+        .unwrap();
+        let perturbed = compare_with_kepler(
+            &sun().to_star_data(),
+            &[jupiter().to_planet_data(), saturn().to_planet_data()],
+            &integrator,
+            Time::new::<year>(0.),
+            &times,
+        )
+        .unwrap();
+        let unperturbed_deviation = alone[0].position_deviations[0].get::<astronomical_unit>();
+        let perturbed_deviation = perturbed[0].position_deviations[1].get::<astronomical_unit>();
+        println!(
+            "Saturn alone: {} AU, with Jupiter: {} AU, energy drift: {}",
+            unperturbed_deviation, perturbed_deviation, perturbed[0].relative_energy_drift
+        );
+        assert!(unperturbed_deviation < 1e-4);
+        assert!(perturbed_deviation > 1e-2);
+        assert!(perturbed[0].relative_energy_drift < 1e-8);
+    }
+}
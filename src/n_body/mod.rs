@@ -0,0 +1,4 @@
+pub mod body;
+pub mod integrator;
+pub mod kepler_comparison;
+pub mod system;
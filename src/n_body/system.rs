@@ -0,0 +1,311 @@
+use astro_coords::cartesian::Cartesian;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    energy::joule,
+    f64::{Energy, Length, Mass, Time, Velocity},
+    length::meter,
+    mass::kilogram,
+    velocity::meter_per_second,
+};
+
+use crate::{
+    error::AstroUtilError,
+    kinematics::{state_vector::StateVector, velocity_vector::VelocityVector},
+    planets::{orbit_parameters::OrbitParameters, planet_data::PlanetData},
+    stars::data::StarData,
+};
+
+use super::{
+    body::Body,
+    integrator::{Integrator, Phase},
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NBodySystem {
+    bodies: Vec<Body>,
+    time: Time,
+}
+
+impl NBodySystem {
+    pub fn new(time: Time) -> Self {
+        Self {
+            bodies: Vec::new(),
+            time,
+        }
+    }
+
+    /*
+     * Places the star at the origin and every planet on its Kepler orbit around it,
+     * then moves everything into the barycentric frame.
+     */
+    pub fn from_star_and_planets(
+        star: &StarData,
+        planets: &[PlanetData],
+        time: Time,
+    ) -> Result<Self, AstroUtilError> {
+        let star_mass = star.get_mass(time).ok_or_else(|| {
+            AstroUtilError::DataNotAvailable(format!("Mass of {}", star.get_name()))
+        })?;
+        let mut system = Self::new(time);
+        let star_index = system.add_body(Body::new(
+            star.get_name().clone(),
+            star_mass,
+            StateVector::new(Cartesian::origin(), VelocityVector::zero()),
+        ));
+        for planet in planets {
+            system.add_orbiting_body(
+                planet.get_name().clone(),
+                planet.get_mass(),
+                planet.get_orbital_parameters(),
+                star_index,
+            )?;
+        }
+        system.move_to_barycentric_frame();
+        Ok(system)
+    }
+
+    pub fn get_bodies(&self) -> &[Body] {
+        &self.bodies
+    }
+
+    pub fn get_time(&self) -> Time {
+        self.time
+    }
+
+    pub fn add_body(&mut self, body: Body) -> usize {
+        self.bodies.push(body);
+        self.bodies.len() - 1
+    }
+
+    // Adds a body on a Kepler orbit around another body of the system, for example a moon around its planet.
+    pub fn add_orbiting_body(
+        &mut self,
+        name: String,
+        mass: Mass,
+        orbit: &OrbitParameters,
+        central_body: usize,
+    ) -> Result<usize, AstroUtilError> {
+        let central_body = self.bodies.get(central_body).ok_or_else(|| {
+            AstroUtilError::DataNotAvailable(format!("Central body with index {}", central_body))
+        })?;
+        let relative = orbit.calculate_state_vector(mass, central_body.mass, self.time)?;
+        let state = StateVector::new(
+            &central_body.state.position + &relative.position,
+            &central_body.state.velocity + &relative.velocity,
+        );
+        Ok(self.add_body(Body::new(name, mass, state)))
+    }
+
+    pub fn total_mass(&self) -> Mass {
+        self.bodies
+            .iter()
+            .fold(Mass::new::<kilogram>(0.), |total, body| total + body.mass)
+    }
+
+    pub fn center_of_mass(&self) -> StateVector {
+        let total_mass = self.total_mass();
+        let zero = Length::new::<meter>(0.);
+        let mut position = Cartesian::new(zero, zero, zero);
+        let mut velocity = VelocityVector::zero();
+        if total_mass.value == 0. {
+            return StateVector::new(position, velocity);
+        }
+        for body in &self.bodies {
+            let weight = (body.mass / total_mass).value;
+            position = &position + &(&body.state.position * weight);
+            velocity = &velocity + &(&body.state.velocity * weight);
+        }
+        StateVector::new(position, velocity)
+    }
+
+    pub fn move_to_barycentric_frame(&mut self) {
+        let center = self.center_of_mass();
+        for body in &mut self.bodies {
+            body.state.position = &body.state.position - &center.position;
+            body.state.velocity = &body.state.velocity - &center.velocity;
+        }
+    }
+
+    pub fn total_energy(&self) -> Energy {
+        Energy::new::<joule>(self.to_phase().energy_in_joule())
+    }
+
+    pub fn integrate_to(
+        &mut self,
+        integrator: &Integrator,
+        time: Time,
+    ) -> Result<(), AstroUtilError> {
+        let mut phase = self.to_phase();
+        integrator.integrate(&mut phase, time - self.time)?;
+        self.apply_phase(&phase);
+        self.time = time;
+        Ok(())
+    }
+
+    // Integrates through the given times in order and returns the positions of all bodies at each of them.
+    pub fn positions_at(
+        &mut self,
+        integrator: &Integrator,
+        times: &[Time],
+    ) -> Result<Vec<Vec<Cartesian>>, AstroUtilError> {
+        let mut positions = Vec::with_capacity(times.len());
+        for time in times {
+            self.integrate_to(integrator, *time)?;
+            positions.push(
+                self.bodies
+                    .iter()
+                    .map(|body| body.state.position.clone())
+                    .collect(),
+            );
+        }
+        Ok(positions)
+    }
+
+    fn to_phase(&self) -> Phase {
+        let masses: Vec<f64> = self
+            .bodies
+            .iter()
+            .map(|body| body.mass.get::<kilogram>())
+            .collect();
+        let mut phase = Phase::new(&masses);
+        for (i, body) in self.bodies.iter().enumerate() {
+            let position = &body.state.position;
+            let velocity = &body.state.velocity;
+            phase.positions[i] = [
+                position.x.get::<meter>(),
+                position.y.get::<meter>(),
+                position.z.get::<meter>(),
+            ];
+            phase.velocities[i] = [
+                velocity.x.get::<meter_per_second>(),
+                velocity.y.get::<meter_per_second>(),
+                velocity.z.get::<meter_per_second>(),
+            ];
+        }
+        phase
+    }
+
+    fn apply_phase(&mut self, phase: &Phase) {
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            let [x, y, z] = phase.positions[i];
+            let [vx, vy, vz] = phase.velocities[i];
+            body.state = StateVector::new(
+                Cartesian::new(
+                    Length::new::<meter>(x),
+                    Length::new::<meter>(y),
+                    Length::new::<meter>(z),
+                ),
+                VelocityVector::new(
+                    Velocity::new::<meter_per_second>(vx),
+                    Velocity::new::<meter_per_second>(vy),
+                    Velocity::new::<meter_per_second>(vz),
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_units::mass::solar_mass;
+    use uom::si::{
+        length::{astronomical_unit, kilometer},
+        time::{day, year},
+    };
+
+    use crate::real_data::{planets::*, stars::sun};
+
+    use super::*;
+
+    fn dormand_prince() -> Integrator {
+        Integrator::DormandPrince {
+            initial_time_step: Time::new::<day>(1.),
+            tolerance: 1e-10,
+        }
+    }
+
+    #[test]
+    fn barycenter_is_at_rest_at_the_origin() {
+        let planets = [earth().to_planet_data(), jupiter().to_planet_data()];
+        let system = NBodySystem::from_star_and_planets(
+            &sun().to_star_data(),
+            &planets,
+            Time::new::<year>(0.),
+        )
+        .unwrap();
+        let center = system.center_of_mass();
+        assert!(center.position.length().get::<meter>() < 1.);
+        assert!(center.velocity.length().get::<meter_per_second>() < 1e-9);
+        assert_eq!(system.get_bodies().len(), 3);
+    }
+
+    #[test]
+    fn moon_stays_bound_to_earth() {
+        let mut system = NBodySystem::from_star_and_planets(
+            &sun().to_star_data(),
+            &[earth().to_planet_data()],
+            Time::new::<year>(0.),
+        )
+        .unwrap();
+        let moon = system
+            .add_orbiting_body("Moon".to_string(), luna().mass, &luna().orbit, 1)
+            .unwrap();
+        system.move_to_barycentric_frame();
+        let times: Vec<Time> = (1..=12).map(|i| Time::new::<day>(30. * i as f64)).collect();
+        let positions = system.positions_at(&dormand_prince(), &times).unwrap();
+        for positions in positions {
+            let distance = positions[moon].distance(&positions[1]);
+            println!("Earth-Moon distance: {} km", distance.get::<kilometer>());
+            assert!(distance.get::<kilometer>() > 340_000.);
+            assert!(distance.get::<kilometer>() < 420_000.);
+        }
+    }
+
+    #[test]
+    fn integrating_back_returns_to_the_start() {
+        let integrator = Integrator::Leapfrog {
+            time_step: Time::new::<day>(1.),
+        };
+        let mut system = NBodySystem::from_star_and_planets(
+            &sun().to_star_data(),
+            &[mars().to_planet_data(), jupiter().to_planet_data()],
+            Time::new::<year>(0.),
+        )
+        .unwrap();
+        let start = system.clone();
+        system
+            .integrate_to(&integrator, Time::new::<year>(10.))
+            .unwrap();
+        system
+            .integrate_to(&integrator, Time::new::<year>(0.))
+            .unwrap();
+        for (before, after) in start.get_bodies().iter().zip(system.get_bodies()) {
+            let deviation = before.get_position().distance(after.get_position());
+            assert!(deviation.get::<astronomical_unit>() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn star_without_mass_is_an_error() {
+        let mut star = sun().to_star_data();
+        star.set_mass_at_epoch(None);
+        let result = NBodySystem::from_star_and_planets(&star, &[], Time::new::<year>(0.));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_time_step_is_an_error() {
+        let mut system = NBodySystem::new(Time::new::<year>(0.));
+        system.add_body(Body::new(
+            "Lonely".to_string(),
+            Mass::new::<solar_mass>(1.),
+            StateVector::new(Cartesian::origin(), VelocityVector::zero()),
+        ));
+        let integrator = Integrator::Leapfrog {
+            time_step: Time::new::<day>(0.),
+        };
+        assert!(system
+            .integrate_to(&integrator, Time::new::<year>(1.))
+            .is_err());
+    }
+}
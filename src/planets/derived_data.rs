@@ -17,16 +17,19 @@ use uom::si::{
 
 use crate::{astro_display::AstroDisplay, error::AstroUtilError, stars::data::StarData};
 
-use super::{kepler_orbit::orbital_period, planet_data::PlanetData};
+use super::{
+    kepler_orbit::{orbital_period, OrbitShape},
+    planet_data::PlanetData,
+};
 
 #[derive(Debug, Clone)]
 pub struct DerivedPlanetData {
     density: MassDensity,
     surface_gravity: Acceleration,
     escape_velocity: Velocity,
-    orbital_period: Time,
+    orbital_period: Option<Time>, // None for open orbits
     orbital_resonance: Option<Fraction>,
-    mean_synodic_day: Time,
+    mean_synodic_day: Option<Time>,
     axial_tilt: Angle,
     black_body_temperature: ThermodynamicTemperature,
 }
@@ -50,18 +53,23 @@ impl DerivedPlanetData {
         let volume = 4. / 3. * PI * radius * radius * radius;
         let density = data.get_mass() / volume;
 
-        let orbital_period = orbital_period(
-            data.get_orbital_parameters().semi_major_axis,
-            data.get_mass(),
-            central_body_mass,
-        );
+        let orbital_parameters = data.get_orbital_parameters();
+        let orbital_period = match orbital_parameters.get_shape()? {
+            OrbitShape::Elliptic => Some(orbital_period(
+                orbital_parameters.semi_major_axis,
+                data.get_mass(),
+                central_body_mass,
+            )),
+            OrbitShape::Parabolic | OrbitShape::Hyperbolic => None,
+        };
 
-        let orbital_resonance = match previous {
-            Some(previous) => orbital_resonance(orbital_period, previous.orbital_period),
-            None => None,
+        let orbital_resonance = match (orbital_period, previous.and_then(|p| p.orbital_period)) {
+            (Some(period), Some(previous_period)) => orbital_resonance(period, previous_period),
+            _ => None,
         };
 
-        let mean_synodic_day = mean_synodic_day(data.get_sideral_rotation_period(), orbital_period);
+        let mean_synodic_day = orbital_period
+            .map(|period| mean_synodic_day(data.get_sideral_rotation_period(), period));
 
         let axial_tilt = axis_tilt(data);
 
@@ -92,7 +100,7 @@ impl DerivedPlanetData {
         self.escape_velocity
     }
 
-    pub fn get_orbital_period(&self) -> Time {
+    pub fn get_orbital_period(&self) -> Option<Time> {
         self.orbital_period
     }
 
@@ -100,7 +108,7 @@ impl DerivedPlanetData {
         self.orbital_resonance
     }
 
-    pub fn get_mean_synodic_day(&self) -> Time {
+    pub fn get_mean_synodic_day(&self) -> Option<Time> {
         self.mean_synodic_day
     }
 
@@ -318,6 +326,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn open_orbits_have_no_period() {
+        let sun = sun().to_star_data();
+        let earth = earth().to_planet_data();
+        let mut escaping = earth.clone();
+        escaping.orbital_parameters.eccentricity = 1.5;
+        let previous = DerivedPlanetData::new(&earth, &sun, None).unwrap();
+        let derived = DerivedPlanetData::new(&escaping, &sun, Some(&previous)).unwrap();
+        assert!(previous.get_orbital_period().is_some());
+        assert!(derived.get_orbital_period().is_none());
+        assert!(derived.get_orbital_resonance().is_none());
+        assert!(derived.get_mean_synodic_day().is_none());
+    }
+
     #[test]
     fn axis_tilt_of_earth() {
         let tilt = axis_tilt(&earth().to_planet_data());
@@ -4,14 +4,49 @@ use astro_coords::{cartesian::Cartesian, spherical::Spherical};
 use astro_units::angle::{full_circ, normalized_angle};
 use uom::si::{
     angle::radian,
-    f64::{Angle, Length, Mass, Time},
+    f64::{Angle, Length, Mass, Time, Velocity},
+    length::meter,
+    mass::kilogram,
     specific_volume::cubic_meter_per_kilogram,
     time::second,
+    velocity::meter_per_second,
 };
 
+use crate::{error::AstroUtilError, kinematics::velocity_vector::VelocityVector};
+
 use super::orbit_parameters::OrbitParameters;
 
 pub(crate) const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11;
+// Eccentricities closer to 1 than this are treated as parabolic.
+const PARABOLIC_TOLERANCE: f64 = 1e-9;
+const MAX_ITERATIONS: usize = 100;
+
+/*
+ * Depending on the eccentricity, a Kepler orbit is an ellipse, a parabola or a hyperbola.
+ * https://en.wikipedia.org/wiki/Kepler_orbit#Classification
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrbitShape {
+    Elliptic,
+    Parabolic,
+    Hyperbolic,
+}
+
+pub fn orbit_shape(eccentricity: f64) -> Result<OrbitShape, AstroUtilError> {
+    if !eccentricity.is_finite() || eccentricity < 0. {
+        return Err(AstroUtilError::InvalidOrbit(format!(
+            "Eccentricity {} is not a finite, non-negative number",
+            eccentricity
+        )));
+    }
+    if (eccentricity - 1.).abs() < PARABOLIC_TOLERANCE {
+        Ok(OrbitShape::Parabolic)
+    } else if eccentricity < 1. {
+        Ok(OrbitShape::Elliptic)
+    } else {
+        Ok(OrbitShape::Hyperbolic)
+    }
+}
 
 /*
  * The orbital period is the time it takes for a given object to make one full orbit around another object.
@@ -42,26 +77,150 @@ pub fn mean_anomaly(orbital_period: Time, time: Time) -> Angle {
     normalized_angle(mean_anomaly.into())
 }
 
+/*
+ * Orbital elements are given for a reference epoch, at which the body has a known mean anomaly.
+ * From there, the mean anomaly grows linearly with time.
+ * https://en.wikipedia.org/wiki/Mean_anomaly#Formulae
+ *
+ * Output is normalised to the range [-π, π].
+ */
+pub fn mean_anomaly_since_epoch(
+    orbital_period: Time,
+    mean_anomaly_at_epoch: Angle,
+    time_since_epoch: Time,
+) -> Angle {
+    normalized_angle(mean_anomaly_at_epoch + mean_anomaly(orbital_period, time_since_epoch))
+}
+
+/*
+ * For open orbits the mean anomaly is not periodic, but it still grows linearly with the time since periapsis.
+ * The mean motion of a hyperbola is defined via the absolute value of its (negative) semi-major axis,
+ * the one of a parabola via its periapsis distance, which is passed as semi-major axis.
+ * https://en.wikipedia.org/wiki/Hyperbolic_trajectory#Equations_of_motion
+ * https://en.wikipedia.org/wiki/Parabolic_trajectory#Barker's_equation
+ *
+ * The output is not normalised.
+ */
+pub fn open_orbit_mean_anomaly(
+    semi_major_axis: Length,
+    eccentricity: f64,
+    mass1: Mass,
+    mass2: Mass,
+    mean_anomaly_at_epoch: Angle,
+    time_since_epoch: Time,
+) -> Result<Angle, AstroUtilError> {
+    let length_cubed = match orbit_shape(eccentricity)? {
+        OrbitShape::Elliptic => {
+            return Err(AstroUtilError::InvalidOrbit(format!(
+                "Eccentricity {} describes a closed orbit",
+                eccentricity
+            )))
+        }
+        OrbitShape::Parabolic => 2. * semi_major_axis * semi_major_axis * semi_major_axis,
+        OrbitShape::Hyperbolic => {
+            semi_major_axis.abs() * semi_major_axis.abs() * semi_major_axis.abs()
+        }
+    };
+    let total_mass = mass1 + mass2;
+    let mean_motion = (GRAVITATIONAL_CONSTANT
+        / (length_cubed / total_mass).get::<cubic_meter_per_kilogram>())
+    .sqrt();
+    let mean_anomaly =
+        mean_anomaly_at_epoch.get::<radian>() + mean_motion * time_since_epoch.get::<second>();
+    if !mean_anomaly.is_finite() {
+        return Err(AstroUtilError::InvalidOrbit(
+            "Mean anomaly of open orbit is not finite".to_string(),
+        ));
+    }
+    Ok(Angle::new::<radian>(mean_anomaly))
+}
+
 /*
  * The eccentric anomaly is the angle between the direction of periapsis and the current position of the orbiting body,
  * as seen from the center of the ellipse (the point around which the object orbits).
  * https://en.wikipedia.org/wiki/Eccentric_anomaly
  */
-pub fn eccentric_anomaly(mean_anomaly: Angle, eccentricity: f64) -> Angle {
-    const ACCURACY: f64 = 1e-6;
+pub fn eccentric_anomaly(mean_anomaly: Angle, eccentricity: f64) -> Result<Angle, AstroUtilError> {
+    if orbit_shape(eccentricity)? != OrbitShape::Elliptic {
+        return Err(AstroUtilError::InvalidOrbit(format!(
+            "Eccentric anomaly is only defined for elliptic orbits, but eccentricity is {}",
+            eccentricity
+        )));
+    }
     let mean_anomaly = mean_anomaly.get::<radian>();
-    let mut eccentric_anomaly = mean_anomaly;
-    let mut error = 10. * ACCURACY;
-    while error > ACCURACY {
+    // For highly eccentric orbits, starting at the mean anomaly can make the Newton iteration overshoot.
+    let start = if eccentricity > 0.8 {
+        PI.copysign(mean_anomaly)
+    } else {
+        mean_anomaly
+    };
+    let eccentric_anomaly = solve_with_newton(start, |eccentric_anomaly| {
         let numerator = eccentric_anomaly - eccentricity * eccentric_anomaly.sin() - mean_anomaly;
         let denominator = 1. - eccentricity * eccentric_anomaly.cos();
-        let next_correction = numerator / denominator;
-        let next_eccentric_anomaly = eccentric_anomaly - next_correction;
-        error = next_correction.abs();
-        eccentric_anomaly = next_eccentric_anomaly;
+        numerator / denominator
+    })?;
+    Ok(Angle::new::<radian>(eccentric_anomaly))
+}
+
+/*
+ * The hyperbolic anomaly is the hyperbolic analogue of the eccentric anomaly.
+ * It solves the hyperbolic Kepler equation M = e sinh(H) - H.
+ * https://en.wikipedia.org/wiki/Hyperbolic_trajectory#Hyperbolic_anomaly
+ */
+pub fn hyperbolic_anomaly(mean_anomaly: Angle, eccentricity: f64) -> Result<f64, AstroUtilError> {
+    if orbit_shape(eccentricity)? != OrbitShape::Hyperbolic {
+        return Err(AstroUtilError::InvalidOrbit(format!(
+            "Hyperbolic anomaly is only defined for hyperbolic orbits, but eccentricity is {}",
+            eccentricity
+        )));
     }
+    let mean_anomaly = mean_anomaly.get::<radian>();
+    let start = (2. * mean_anomaly.abs() / eccentricity + 1.8)
+        .ln()
+        .copysign(mean_anomaly);
+    solve_with_newton(start, |hyperbolic_anomaly| {
+        let numerator =
+            eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly - mean_anomaly;
+        let denominator = eccentricity * hyperbolic_anomaly.cosh() - 1.;
+        numerator / denominator
+    })
+}
 
-    Angle::new::<radian>(eccentric_anomaly)
+/*
+ * Barker's equation relates the time since periapsis of a parabolic orbit to D = tan(ν/2).
+ * With the mean anomaly defined as M = sqrt(μ / 2q³) (t - T), it reads M = D + D³/3,
+ * which is a cubic with the closed solution used here.
+ * https://en.wikipedia.org/wiki/Parabolic_trajectory#Barker's_equation
+ */
+pub fn parabolic_anomaly(mean_anomaly: Angle) -> f64 {
+    let w = 1.5 * mean_anomaly.get::<radian>();
+    let a = (w + (w * w + 1.).sqrt()).cbrt();
+    a - 1. / a
+}
+
+fn solve_with_newton<F>(start: f64, correction: F) -> Result<f64, AstroUtilError>
+where
+    F: Fn(f64) -> f64,
+{
+    const ACCURACY: f64 = 1e-6;
+    let mut value = start;
+    for _ in 0..MAX_ITERATIONS {
+        let next_correction = correction(value);
+        if !next_correction.is_finite() {
+            return Err(AstroUtilError::InvalidOrbit(format!(
+                "Kepler equation diverged at {}",
+                value
+            )));
+        }
+        value -= next_correction;
+        if next_correction.abs() <= ACCURACY {
+            return Ok(value);
+        }
+    }
+    Err(AstroUtilError::InvalidOrbit(format!(
+        "Kepler equation did not converge within {} iterations",
+        MAX_ITERATIONS
+    )))
 }
 
 /*
@@ -77,14 +236,126 @@ pub fn true_anomaly(eccentric_anomaly: Angle, eccentricity: f64) -> Angle {
 }
 
 /*
- * The distance from the focus is the distance between the orbiting body and the main focus of the ellipse
+ * The true anomaly of a hyperbolic orbit follows from its hyperbolic anomaly.
+ * https://en.wikipedia.org/wiki/Hyperbolic_trajectory#True_anomaly
+ */
+pub fn true_anomaly_from_hyperbolic_anomaly(hyperbolic_anomaly: f64, eccentricity: f64) -> Angle {
+    let sqrt_arg = (eccentricity + 1.) / (eccentricity - 1.);
+    let artan_arg = (hyperbolic_anomaly / 2.).tanh() * sqrt_arg.sqrt();
+    Angle::new::<radian>(2. * artan_arg.atan())
+}
+
+/*
+ * The true anomaly of a parabolic orbit follows from D = tan(ν/2).
+ * https://en.wikipedia.org/wiki/Parabolic_trajectory#Barker's_equation
+ */
+pub fn true_anomaly_from_parabolic_anomaly(parabolic_anomaly: f64) -> Angle {
+    Angle::new::<radian>(2. * parabolic_anomaly.atan())
+}
+
+/*
+ * The inverse of the chain mean anomaly -> eccentric, parabolic or hyperbolic anomaly -> true anomaly.
+ * https://en.wikipedia.org/wiki/Eccentric_anomaly#From_the_true_anomaly
+ * https://en.wikipedia.org/wiki/Hyperbolic_trajectory#Hyperbolic_anomaly
+ */
+pub fn mean_anomaly_from_true_anomaly(
+    true_anomaly: Angle,
+    eccentricity: f64,
+) -> Result<Angle, AstroUtilError> {
+    let half_tan = (true_anomaly.get::<radian>() / 2.).tan();
+    let mean_anomaly = match orbit_shape(eccentricity)? {
+        OrbitShape::Elliptic => {
+            let sqrt_arg = (1. - eccentricity) / (1. + eccentricity);
+            let eccentric_anomaly = 2. * (sqrt_arg.sqrt() * half_tan).atan();
+            eccentric_anomaly - eccentricity * eccentric_anomaly.sin()
+        }
+        OrbitShape::Parabolic => half_tan + half_tan * half_tan * half_tan / 3.,
+        OrbitShape::Hyperbolic => {
+            let sqrt_arg = (eccentricity - 1.) / (eccentricity + 1.);
+            let tanh_arg = sqrt_arg.sqrt() * half_tan;
+            if tanh_arg.abs() >= 1. {
+                return Err(AstroUtilError::InvalidOrbit(format!(
+                    "True anomaly {} lies beyond the asymptote of the hyperbola",
+                    true_anomaly.get::<radian>()
+                )));
+            }
+            let hyperbolic_anomaly = 2. * tanh_arg.atanh();
+            eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly
+        }
+    };
+    Ok(Angle::new::<radian>(mean_anomaly))
+}
+
+/*
+ * The standard gravitational parameter μ = G (m1 + m2) of a two body system, in m³/s².
+ * https://en.wikipedia.org/wiki/Standard_gravitational_parameter
+ */
+pub(crate) fn gravitational_parameter(mass1: Mass, mass2: Mass) -> f64 {
+    GRAVITATIONAL_CONSTANT * (mass1 + mass2).get::<kilogram>()
+}
+
+/*
+ * The semi-latus rectum is the distance from the focus to the orbit, measured perpendicular to the major axis.
+ * For a parabola, the periapsis distance is passed as semi-major axis.
+ * https://en.wikipedia.org/wiki/Conic_section#Conic_parameters
+ */
+pub(crate) fn semi_latus_rectum(semi_major_axis: Length, eccentricity: f64) -> Length {
+    if (eccentricity - 1.).abs() < PARABOLIC_TOLERANCE {
+        2. * semi_major_axis
+    } else {
+        semi_major_axis.abs() * (1. - eccentricity * eccentricity).abs()
+    }
+}
+
+/*
+ * The distance from the focus is the distance between the orbiting body and the main focus of the conic section
  * (the point around which the object orbits).
  * https://en.wikipedia.org/wiki/Ellipse#Length_from_focus
+ * https://en.wikipedia.org/wiki/Kepler_orbit#Properties_of_trajectory_equation
  */
 fn distance_from_focus(semi_major_axis: Length, true_anomaly: Angle, eccentricity: f64) -> Length {
-    let numerator = 1. - eccentricity * eccentricity;
     let denominator = 1. + eccentricity * true_anomaly.get::<radian>().cos();
-    semi_major_axis * numerator / denominator
+    semi_latus_rectum(semi_major_axis, eccentricity) / denominator
+}
+
+/*
+ * The velocity of the orbiting body in its orbital plane, where the x-axis points towards periapsis.
+ * https://en.wikipedia.org/wiki/Perifocal_coordinate_system
+ */
+pub fn velocity_in_orbital_plane(
+    semi_major_axis: Length,
+    eccentricity: f64,
+    true_anomaly: Angle,
+    mass1: Mass,
+    mass2: Mass,
+) -> VelocityVector {
+    let semi_latus_rectum = semi_latus_rectum(semi_major_axis, eccentricity).get::<meter>();
+    let speed = (gravitational_parameter(mass1, mass2) / semi_latus_rectum).sqrt();
+    let true_anomaly = true_anomaly.get::<radian>();
+    VelocityVector::new(
+        Velocity::new::<meter_per_second>(-speed * true_anomaly.sin()),
+        Velocity::new::<meter_per_second>(speed * (eccentricity + true_anomaly.cos())),
+        Velocity::new::<meter_per_second>(0.),
+    )
+}
+
+/*
+ * The velocity relative to the central body is the orbital velocity rotated into the reference frame.
+ * https://en.wikipedia.org/wiki/Orbital_state_vectors
+ */
+pub fn velocity_relative_to_central_body(
+    semi_major_axis: Length,
+    eccentricity: f64,
+    true_anomaly: Angle,
+    mass1: Mass,
+    mass2: Mass,
+    orientation: &OrbitParameters,
+) -> VelocityVector {
+    let velocity =
+        velocity_in_orbital_plane(semi_major_axis, eccentricity, true_anomaly, mass1, mass2);
+    let unit_time = Time::new::<second>(1.);
+    let displacement = orientation.apply_orientation_to(velocity.displacement(unit_time));
+    VelocityVector::from_displacement(&displacement, unit_time)
 }
 
 /*
@@ -251,7 +522,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_quarter_circle_mean_anomaly_and_zero_eccentricity() {
         let expected_eccentric_anomaly = quarter_circ();
-        let eccentric_anomaly = eccentric_anomaly(quarter_circ(), 0.);
+        let eccentric_anomaly = eccentric_anomaly(quarter_circ(), 0.).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -266,7 +537,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_half_circle_mean_anomaly_and_zero_eccentricity() {
         let expected_eccentric_anomaly = half_circ();
-        let eccentric_anomaly = eccentric_anomaly(half_circ(), 0.);
+        let eccentric_anomaly = eccentric_anomaly(half_circ(), 0.).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -281,7 +552,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_three_quarters_circle_mean_anomaly_and_zero_eccentricity() {
         let expected_eccentric_anomaly = three_quarter_circ();
-        let eccentric_anomaly = eccentric_anomaly(three_quarter_circ(), 0.);
+        let eccentric_anomaly = eccentric_anomaly(three_quarter_circ(), 0.).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -296,7 +567,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_negative_quarter_circle_mean_anomaly_and_zero_eccentricity() {
         let expected_eccentric_anomaly = -quarter_circ();
-        let eccentric_anomaly = eccentric_anomaly(-quarter_circ(), 0.);
+        let eccentric_anomaly = eccentric_anomaly(-quarter_circ(), 0.).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -311,7 +582,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_quarter_circle_mean_anomaly_and_half_eccentricity() {
         let expected_eccentric_anomaly = Angle::new::<degree>(115.79362093315422);
-        let eccentric_anomaly = eccentric_anomaly(quarter_circ(), 0.5);
+        let eccentric_anomaly = eccentric_anomaly(quarter_circ(), 0.5).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -326,7 +597,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_half_circle_mean_anomaly_and_half_eccentricity() {
         let expected_eccentric_anomaly = half_circ();
-        let eccentric_anomaly = eccentric_anomaly(half_circ(), 0.5);
+        let eccentric_anomaly = eccentric_anomaly(half_circ(), 0.5).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -341,7 +612,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_three_quarters_circle_mean_anomaly_and_half_eccentricity() {
         let expected_eccentric_anomaly = Angle::new::<degree>(-115.79362093315422);
-        let eccentric_anomaly = eccentric_anomaly(three_quarter_circ(), 0.5);
+        let eccentric_anomaly = eccentric_anomaly(three_quarter_circ(), 0.5).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -356,7 +627,7 @@ mod tests {
     #[test]
     fn eccentric_anomaly_from_negative_quarter_circle_mean_anomaly_and_half_eccentricity() {
         let expected_eccentric_anomaly = Angle::new::<degree>(-115.79362093315422);
-        let eccentric_anomaly = eccentric_anomaly(-quarter_circ(), 0.5);
+        let eccentric_anomaly = eccentric_anomaly(-quarter_circ(), 0.5).unwrap();
         println!(
             "Expected eccentric anomaly: {}",
             expected_eccentric_anomaly.astro_display()
@@ -468,4 +739,75 @@ mod tests {
         println!("Calculated distance from focus: {}", actual.astro_display());
         assert!(eq(actual.value, expected.value));
     }
+
+    #[test]
+    fn eccentric_anomaly_converges_for_highly_eccentric_orbits() {
+        let eccentricity = 0.99;
+        for mean_anomaly in [1e-3, 0.1, 1., 3.] {
+            let eccentric_anomaly =
+                eccentric_anomaly(Angle::new::<radian>(mean_anomaly), eccentricity)
+                    .unwrap()
+                    .get::<radian>();
+            let recovered = eccentric_anomaly - eccentricity * eccentric_anomaly.sin();
+            assert!(eq_within(recovered, mean_anomaly, 1e-6));
+        }
+    }
+
+    #[test]
+    fn eccentric_anomaly_is_an_error_for_open_or_invalid_orbits() {
+        for eccentricity in [1., 1.5, -0.1, f64::NAN] {
+            assert!(eccentric_anomaly(quarter_circ(), eccentricity).is_err());
+        }
+    }
+
+    #[test]
+    fn hyperbolic_anomaly_solves_kepler_equation() {
+        let eccentricity = 1.8;
+        for mean_anomaly in [-50., -1., 0., 0.3, 2., 100.] {
+            let hyperbolic_anomaly =
+                hyperbolic_anomaly(Angle::new::<radian>(mean_anomaly), eccentricity).unwrap();
+            let recovered = eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly;
+            assert!(eq_within(recovered, mean_anomaly, 1e-5));
+        }
+    }
+
+    #[test]
+    fn hyperbolic_anomaly_is_an_error_for_closed_orbits() {
+        assert!(hyperbolic_anomaly(quarter_circ(), 0.5).is_err());
+        assert!(hyperbolic_anomaly(quarter_circ(), 1.).is_err());
+    }
+
+    #[test]
+    fn parabolic_anomaly_solves_barkers_equation() {
+        for mean_anomaly in [-20., -0.5, 0., 0.1, 3., 1000.] {
+            let d = parabolic_anomaly(Angle::new::<radian>(mean_anomaly));
+            assert!(eq_within(
+                d + d * d * d / 3.,
+                mean_anomaly,
+                1e-6 * (1. + mean_anomaly.abs())
+            ));
+        }
+    }
+
+    #[test]
+    fn true_anomaly_of_open_orbits_stays_below_asymptote() {
+        let eccentricity: f64 = 2.;
+        let asymptote = (-1. / eccentricity).acos();
+        let true_anomaly = true_anomaly_from_hyperbolic_anomaly(20., eccentricity);
+        assert!(true_anomaly.get::<radian>() < asymptote);
+        assert!(eq_within(true_anomaly.get::<radian>(), asymptote, 1e-3));
+
+        let true_anomaly = true_anomaly_from_parabolic_anomaly(1e6);
+        assert!(true_anomaly < half_circ());
+    }
+
+    #[test]
+    fn orbit_shape_from_eccentricity() {
+        assert_eq!(orbit_shape(0.).unwrap(), OrbitShape::Elliptic);
+        assert_eq!(orbit_shape(0.999).unwrap(), OrbitShape::Elliptic);
+        assert_eq!(orbit_shape(1.).unwrap(), OrbitShape::Parabolic);
+        assert_eq!(orbit_shape(1.001).unwrap(), OrbitShape::Hyperbolic);
+        assert!(orbit_shape(-1.).is_err());
+        assert!(orbit_shape(f64::INFINITY).is_err());
+    }
 }
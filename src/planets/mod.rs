@@ -4,6 +4,7 @@ pub mod orbit_parameters;
 pub mod physical_parameters;
 pub mod planet_brightness;
 pub mod planet_data;
+pub mod planetary_system;
 pub mod random_planets;
 pub mod real_data;
 pub mod surface_normal;
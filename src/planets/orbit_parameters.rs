@@ -1,12 +1,32 @@
 use astro_coords::{cartesian::Cartesian, direction::Direction, traits::*};
 use serde::{Deserialize, Serialize};
-use uom::si::f64::{Angle, Length, Mass, Time};
+use uom::si::{
+    angle::radian,
+    f64::{Angle, Length, Mass, Time},
+    length::meter,
+    velocity::meter_per_second,
+};
 
-use crate::planets::kepler_orbit::{
-    eccentric_anomaly, mean_anomaly, orbital_period, position_relative_to_central_body,
-    true_anomaly,
+use crate::{
+    error::AstroUtilError,
+    kinematics::{
+        state_vector::StateVector,
+        vector_math::{cross, dot, norm, normalized, scaled, signed_angle},
+        velocity_vector::VelocityVector,
+    },
+    planets::kepler_orbit::{
+        eccentric_anomaly, gravitational_parameter, hyperbolic_anomaly,
+        mean_anomaly_from_true_anomaly, mean_anomaly_since_epoch, open_orbit_mean_anomaly,
+        orbit_shape, orbital_period, parabolic_anomaly, position_relative_to_central_body,
+        true_anomaly, true_anomaly_from_hyperbolic_anomaly, true_anomaly_from_parabolic_anomaly,
+        velocity_relative_to_central_body, OrbitShape,
+    },
 };
 
+/*
+ * For hyperbolic orbits, the semi-major axis may be given with either sign.
+ * For parabolic orbits, where it is infinite, the periapsis distance is stored in its place.
+ */
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrbitParameters {
     pub(crate) semi_major_axis: Length,
@@ -14,6 +34,10 @@ pub struct OrbitParameters {
     pub(crate) inclination: Angle, // The angle between the orbital plane and the reference plane
     pub(crate) longitude_of_ascending_node: Angle, // The angle between the reference plane and the ascending node
     pub(crate) argument_of_periapsis: Angle, // The angle between the ascending node and the periapsis
+    #[serde(default)]
+    pub(crate) mean_anomaly_at_epoch: Angle, // The mean anomaly of the body at the epoch
+    #[serde(default)]
+    pub(crate) epoch: Time, // The time at which the elements are given, in the same time frame as the time passed to calculate_position
 }
 
 impl OrbitParameters {
@@ -23,6 +47,8 @@ impl OrbitParameters {
         inclination: Angle, // The angle between the orbital plane and the reference plane
         longitude_of_ascending_node: Angle, // The angle between the reference plane and the ascending node
         argument_of_periapsis: Angle, // The angle between the ascending node and the periapsis
+        mean_anomaly_at_epoch: Angle, // The mean anomaly of the body at the epoch
+        epoch: Time,                  // The time at which the elements are given
     ) -> Self {
         OrbitParameters {
             semi_major_axis,
@@ -30,6 +56,8 @@ impl OrbitParameters {
             inclination,
             longitude_of_ascending_node,
             argument_of_periapsis,
+            mean_anomaly_at_epoch,
+            epoch,
         }
     }
 
@@ -41,6 +69,19 @@ impl OrbitParameters {
         self.eccentricity
     }
 
+    pub fn get_shape(&self) -> Result<OrbitShape, AstroUtilError> {
+        orbit_shape(self.eccentricity)
+    }
+
+    pub fn get_periapsis_distance(&self) -> Result<Length, AstroUtilError> {
+        let distance = match self.get_shape()? {
+            OrbitShape::Elliptic => self.semi_major_axis * (1. - self.eccentricity),
+            OrbitShape::Parabolic => self.semi_major_axis,
+            OrbitShape::Hyperbolic => self.semi_major_axis.abs() * (self.eccentricity - 1.),
+        };
+        Ok(distance)
+    }
+
     pub fn get_inclination(&self) -> Angle {
         self.inclination
     }
@@ -53,21 +94,187 @@ impl OrbitParameters {
         self.argument_of_periapsis
     }
 
+    pub fn get_mean_anomaly_at_epoch(&self) -> Angle {
+        self.mean_anomaly_at_epoch
+    }
+
+    pub fn get_epoch(&self) -> Time {
+        self.epoch
+    }
+
     pub fn calculate_position(
         &self,
         body_mass: Mass,
         central_body_mass: Mass,
         time: Time,
-    ) -> Cartesian {
-        let orbital_period = orbital_period(self.semi_major_axis, body_mass, central_body_mass);
-        let mean_anomaly = mean_anomaly(orbital_period, time);
-        let eccentric_anomaly = eccentric_anomaly(mean_anomaly, self.eccentricity);
-        let true_anomaly = true_anomaly(eccentric_anomaly, self.eccentricity);
-        position_relative_to_central_body(
+    ) -> Result<Cartesian, AstroUtilError> {
+        let true_anomaly = self.true_anomaly_at(body_mass, central_body_mass, time)?;
+        Ok(position_relative_to_central_body(
             self.semi_major_axis,
             self.eccentricity,
             true_anomaly,
             self,
+        ))
+    }
+
+    pub fn calculate_velocity(
+        &self,
+        body_mass: Mass,
+        central_body_mass: Mass,
+        time: Time,
+    ) -> Result<VelocityVector, AstroUtilError> {
+        let true_anomaly = self.true_anomaly_at(body_mass, central_body_mass, time)?;
+        Ok(velocity_relative_to_central_body(
+            self.semi_major_axis,
+            self.eccentricity,
+            true_anomaly,
+            body_mass,
+            central_body_mass,
+            self,
+        ))
+    }
+
+    pub fn calculate_state_vector(
+        &self,
+        body_mass: Mass,
+        central_body_mass: Mass,
+        time: Time,
+    ) -> Result<StateVector, AstroUtilError> {
+        let position = self.calculate_position(body_mass, central_body_mass, time)?;
+        let velocity = self.calculate_velocity(body_mass, central_body_mass, time)?;
+        Ok(StateVector::new(position, velocity))
+    }
+
+    /*
+     * Calculates the orbital elements from the position and velocity relative to the central body.
+     * The resulting elements use the given time as epoch.
+     * https://en.wikipedia.org/wiki/Orbital_elements#Orbital_state_vectors
+     *
+     * For circular orbits the periapsis is placed at the ascending node,
+     * for orbits in the reference plane the ascending node is placed on the x-axis.
+     */
+    pub fn from_state_vector(
+        state: &StateVector,
+        body_mass: Mass,
+        central_body_mass: Mass,
+        time: Time,
+    ) -> Result<Self, AstroUtilError> {
+        const DEGENERACY_TOLERANCE: f64 = 1e-10;
+
+        let mu = gravitational_parameter(body_mass, central_body_mass);
+        let r = [
+            state.position.x.get::<meter>(),
+            state.position.y.get::<meter>(),
+            state.position.z.get::<meter>(),
+        ];
+        let v = [
+            state.velocity.x.get::<meter_per_second>(),
+            state.velocity.y.get::<meter_per_second>(),
+            state.velocity.z.get::<meter_per_second>(),
+        ];
+        let r_abs = norm(&r);
+        let v_squared = dot(&v, &v);
+
+        let angular_momentum = cross(&r, &v);
+        let h_abs = norm(&angular_momentum);
+        if h_abs <= 0. || !h_abs.is_finite() || mu <= 0. {
+            return Err(AstroUtilError::InvalidOrbit(
+                "State vector does not describe a Kepler orbit".to_string(),
+            ));
+        }
+        let h = scaled(&angular_momentum, 1. / h_abs);
+
+        let radial_velocity = dot(&r, &v);
+        let eccentricity_vector =
+            [0, 1, 2].map(|i| ((v_squared - mu / r_abs) * r[i] - radial_velocity * v[i]) / mu);
+        let eccentricity = norm(&eccentricity_vector);
+
+        let node = [-h[1], h[0], 0.];
+        let node = if norm(&node) > DEGENERACY_TOLERANCE {
+            normalized(&node)
+        } else {
+            [1., 0., 0.]
+        };
+        let periapsis = if eccentricity > DEGENERACY_TOLERANCE {
+            scaled(&eccentricity_vector, 1. / eccentricity)
+        } else {
+            node
+        };
+
+        let semi_latus_rectum = h_abs * h_abs / mu;
+        let semi_major_axis = match orbit_shape(eccentricity)? {
+            OrbitShape::Parabolic => semi_latus_rectum / 2.,
+            _ => semi_latus_rectum / (1. - eccentricity * eccentricity),
+        };
+        let inclination = h[2].clamp(-1., 1.).acos();
+        let longitude_of_ascending_node = node[1].atan2(node[0]);
+        let argument_of_periapsis = signed_angle(&node, &periapsis, &h);
+        let true_anomaly = signed_angle(&periapsis, &r, &h);
+        let mean_anomaly_at_epoch =
+            mean_anomaly_from_true_anomaly(Angle::new::<radian>(true_anomaly), eccentricity)?;
+
+        Ok(OrbitParameters {
+            semi_major_axis: Length::new::<meter>(semi_major_axis),
+            eccentricity,
+            inclination: Angle::new::<radian>(inclination),
+            longitude_of_ascending_node: Angle::new::<radian>(longitude_of_ascending_node),
+            argument_of_periapsis: Angle::new::<radian>(argument_of_periapsis),
+            mean_anomaly_at_epoch,
+            epoch: time,
+        })
+    }
+
+    fn true_anomaly_at(
+        &self,
+        body_mass: Mass,
+        central_body_mass: Mass,
+        time: Time,
+    ) -> Result<Angle, AstroUtilError> {
+        if !self.semi_major_axis.is_finite() || self.semi_major_axis.value == 0. {
+            return Err(AstroUtilError::InvalidOrbit(
+                "Semi-major axis must be finite and non-zero".to_string(),
+            ));
+        }
+        let true_anomaly = match self.get_shape()? {
+            OrbitShape::Elliptic => {
+                let orbital_period =
+                    orbital_period(self.semi_major_axis, body_mass, central_body_mass);
+                let mean_anomaly = mean_anomaly_since_epoch(
+                    orbital_period,
+                    self.mean_anomaly_at_epoch,
+                    time - self.epoch,
+                );
+                let eccentric_anomaly = eccentric_anomaly(mean_anomaly, self.eccentricity)?;
+                true_anomaly(eccentric_anomaly, self.eccentricity)
+            }
+            OrbitShape::Parabolic => {
+                let mean_anomaly =
+                    self.open_orbit_mean_anomaly(body_mass, central_body_mass, time)?;
+                true_anomaly_from_parabolic_anomaly(parabolic_anomaly(mean_anomaly))
+            }
+            OrbitShape::Hyperbolic => {
+                let mean_anomaly =
+                    self.open_orbit_mean_anomaly(body_mass, central_body_mass, time)?;
+                let hyperbolic_anomaly = hyperbolic_anomaly(mean_anomaly, self.eccentricity)?;
+                true_anomaly_from_hyperbolic_anomaly(hyperbolic_anomaly, self.eccentricity)
+            }
+        };
+        Ok(true_anomaly)
+    }
+
+    fn open_orbit_mean_anomaly(
+        &self,
+        body_mass: Mass,
+        central_body_mass: Mass,
+        time: Time,
+    ) -> Result<Angle, AstroUtilError> {
+        open_orbit_mean_anomaly(
+            self.semi_major_axis,
+            self.eccentricity,
+            body_mass,
+            central_body_mass,
+            self.mean_anomaly_at_epoch,
+            time - self.epoch,
         )
     }
 
@@ -87,15 +294,291 @@ impl OrbitParameters {
 #[cfg(test)]
 mod test {
 
-    use astro_units::angle::angle_eq_within;
-    use uom::si::{angle::radian, f64::Angle};
+    use astro_units::{angle::angle_eq_within, mass::solar_mass};
+    use uom::si::{
+        angle::{degree, radian},
+        f64::{Angle, Length, Mass, Time},
+        length::{astronomical_unit, meter},
+        mass::kilogram,
+        time::{day, second},
+    };
 
-    use crate::{astro_display::AstroDisplay, real_data::planets::*};
+    use crate::{
+        astro_display::AstroDisplay,
+        planets::{kepler_orbit::GRAVITATIONAL_CONSTANT, real_data::RealData},
+        real_data::planets::*,
+        tests::eq_within,
+    };
+
+    use super::*;
 
     fn tilt_accuracy() -> Angle {
         Angle::new::<radian>(2e-3)
     }
 
+    fn ecliptic_longitude_at_j2000(body: &RealData, central_body_mass: Mass) -> Angle {
+        let position = body
+            .orbit
+            .calculate_position(body.mass, central_body_mass, Time::new::<day>(0.))
+            .unwrap();
+        Angle::new::<radian>(position.y.get::<meter>().atan2(position.x.get::<meter>()))
+    }
+
+    fn open_orbit(semi_major_axis: Length, eccentricity: f64) -> OrbitParameters {
+        OrbitParameters::new(
+            semi_major_axis,
+            eccentricity,
+            Angle::new::<degree>(30.),
+            Angle::new::<degree>(40.),
+            Angle::new::<degree>(50.),
+            Angle::new::<radian>(0.),
+            Time::new::<day>(0.),
+        )
+    }
+
+    // Compares the numerically differentiated speed with the vis-viva equation v² = μ (2/r - 1/a).
+    fn assert_vis_viva(orbit: &OrbitParameters, inverse_semi_major_axis: f64, time: Time) {
+        let mass = Mass::new::<solar_mass>(1.);
+        let zero = Mass::new::<solar_mass>(0.);
+        let dt = Time::new::<second>(1.);
+        let before = orbit.calculate_position(zero, mass, time - dt).unwrap();
+        let after = orbit.calculate_position(zero, mass, time + dt).unwrap();
+        let position = orbit.calculate_position(zero, mass, time).unwrap();
+        let speed = before.distance(&after).get::<meter>() / (2. * dt.get::<second>());
+        let mu = GRAVITATIONAL_CONSTANT * mass.get::<kilogram>();
+        let r = position.length().get::<meter>();
+        let expected = (mu * (2. / r - inverse_semi_major_axis)).sqrt();
+        println!("speed: {}, expected: {}", speed, expected);
+        assert!(eq_within(speed, expected, 1e-4 * expected));
+    }
+
+    #[test]
+    fn orbits_without_epoch_start_at_periapsis_at_j2000() {
+        let mut json =
+            serde_json::to_value(open_orbit(Length::new::<astronomical_unit>(1.), 0.2)).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("mean_anomaly_at_epoch");
+        fields.remove("epoch");
+        let orbit: OrbitParameters = serde_json::from_value(json).unwrap();
+        assert_eq!(orbit.get_mean_anomaly_at_epoch(), Angle::new::<radian>(0.));
+        assert_eq!(orbit.get_epoch(), Time::new::<day>(0.));
+    }
+
+    #[test]
+    fn hyperbolic_orbit_starts_at_periapsis() {
+        let orbit = open_orbit(Length::new::<astronomical_unit>(-2.), 1.5);
+        let position = orbit
+            .calculate_position(
+                Mass::new::<solar_mass>(0.),
+                Mass::new::<solar_mass>(1.),
+                Time::new::<day>(0.),
+            )
+            .unwrap();
+        let expected = orbit.get_periapsis_distance().unwrap();
+        assert!(eq_within(
+            position.length().get::<astronomical_unit>(),
+            expected.get::<astronomical_unit>(),
+            1e-9
+        ));
+        assert!(eq_within(expected.get::<astronomical_unit>(), 1., 1e-9));
+    }
+
+    #[test]
+    fn hyperbolic_orbit_satisfies_vis_viva_equation() {
+        let semi_major_axis = Length::new::<astronomical_unit>(-2.);
+        let orbit = open_orbit(semi_major_axis, 1.5);
+        for days in [-300., 0., 20., 1000.] {
+            assert_vis_viva(
+                &orbit,
+                1. / semi_major_axis.get::<meter>(),
+                Time::new::<day>(days),
+            );
+        }
+    }
+
+    #[test]
+    fn parabolic_orbit_satisfies_vis_viva_equation() {
+        let periapsis_distance = Length::new::<astronomical_unit>(0.5);
+        let orbit = open_orbit(periapsis_distance, 1.);
+        assert!(eq_within(
+            orbit
+                .get_periapsis_distance()
+                .unwrap()
+                .get::<astronomical_unit>(),
+            0.5,
+            1e-9
+        ));
+        for days in [-300., 0., 20., 1000.] {
+            assert_vis_viva(&orbit, 0., Time::new::<day>(days));
+        }
+    }
+
+    #[test]
+    fn open_orbits_escape() {
+        let orbit = open_orbit(Length::new::<astronomical_unit>(-2.), 1.5);
+        let mass = Mass::new::<solar_mass>(1.);
+        let zero = Mass::new::<solar_mass>(0.);
+        let mut previous = Length::new::<astronomical_unit>(0.);
+        for years in 1..10 {
+            let time = Time::new::<day>(365.25 * years as f64);
+            let distance = orbit.calculate_position(zero, mass, time).unwrap().length();
+            assert!(distance > previous);
+            previous = distance;
+        }
+    }
+
+    #[test]
+    fn invalid_orbits_return_errors() {
+        let mass = Mass::new::<solar_mass>(1.);
+        let time = Time::new::<day>(10.);
+        for eccentricity in [-0.5, f64::NAN, f64::INFINITY] {
+            let orbit = open_orbit(Length::new::<astronomical_unit>(1.), eccentricity);
+            assert!(orbit.calculate_position(mass, mass, time).is_err());
+        }
+        let orbit = open_orbit(Length::new::<astronomical_unit>(0.), 0.5);
+        assert!(orbit.calculate_position(mass, mass, time).is_err());
+    }
+
+    fn assert_state_vectors_eq(actual: &StateVector, expected: &StateVector) {
+        let position_accuracy = 1e-6 * expected.position.length().get::<meter>();
+        let velocity_accuracy = 1e-6 * expected.velocity.length().get::<meter_per_second>();
+        println!("expected: {}", expected.astro_display());
+        println!("actual: {}", actual.astro_display());
+        assert!(actual.position.distance(&expected.position).get::<meter>() < position_accuracy);
+        let velocity_difference = &actual.velocity - &expected.velocity;
+        assert!(velocity_difference.length().get::<meter_per_second>() < velocity_accuracy);
+    }
+
+    fn assert_round_trip(orbit: &OrbitParameters, body_mass: Mass) {
+        let central_body_mass = Mass::new::<solar_mass>(1.);
+        let time = Time::new::<day>(100.);
+        let state = orbit
+            .calculate_state_vector(body_mass, central_body_mass, time)
+            .unwrap();
+        let recovered =
+            OrbitParameters::from_state_vector(&state, body_mass, central_body_mass, time).unwrap();
+        assert!(eq_within(
+            recovered.semi_major_axis.get::<meter>(),
+            orbit.semi_major_axis.get::<meter>(),
+            1e-6 * orbit.semi_major_axis.abs().get::<meter>()
+        ));
+        assert!(eq_within(recovered.eccentricity, orbit.eccentricity, 1e-6));
+        assert!(angle_eq_within(
+            recovered.inclination,
+            orbit.inclination,
+            Angle::new::<radian>(1e-6)
+        ));
+
+        for days in [100., 137., 1000.] {
+            let time = Time::new::<day>(days);
+            let expected = orbit
+                .calculate_state_vector(body_mass, central_body_mass, time)
+                .unwrap();
+            let actual = recovered
+                .calculate_state_vector(body_mass, central_body_mass, time)
+                .unwrap();
+            assert_state_vectors_eq(&actual, &expected);
+        }
+    }
+
+    #[test]
+    fn elliptical_orbits_survive_round_trip() {
+        for planet in [mercury(), mars(), jupiter()] {
+            println!("{}", planet.name);
+            assert_round_trip(&planet.orbit, planet.mass);
+        }
+    }
+
+    #[test]
+    fn circular_orbit_survives_round_trip() {
+        let mut orbit = venus().orbit;
+        orbit.eccentricity = 0.;
+        assert_round_trip(&orbit, venus().mass);
+    }
+
+    #[test]
+    fn orbit_in_reference_plane_survives_round_trip() {
+        assert_round_trip(&earth().orbit, earth().mass);
+
+        let mut orbit = earth().orbit;
+        orbit.inclination = Angle::new::<degree>(0.);
+        assert_round_trip(&orbit, earth().mass);
+    }
+
+    #[test]
+    fn highly_inclined_orbits_survive_round_trip() {
+        assert_round_trip(&pluto().orbit, pluto().mass);
+
+        for inclination in [90., 150.] {
+            let mut orbit = mars().orbit;
+            orbit.inclination = Angle::new::<degree>(inclination);
+            assert_round_trip(&orbit, mars().mass);
+        }
+    }
+
+    #[test]
+    fn hyperbolic_orbit_survives_round_trip() {
+        let orbit = open_orbit(Length::new::<astronomical_unit>(-2.), 1.5);
+        assert_round_trip(&orbit, Mass::new::<solar_mass>(0.));
+    }
+
+    #[test]
+    fn velocity_is_derivative_of_position() {
+        let orbit = mercury().orbit;
+        let mass = mercury().mass;
+        let sun_mass = Mass::new::<solar_mass>(1.);
+        let time = Time::new::<day>(42.);
+        let dt = Time::new::<second>(10.);
+        let before = orbit.calculate_position(mass, sun_mass, time - dt).unwrap();
+        let after = orbit.calculate_position(mass, sun_mass, time + dt).unwrap();
+        let expected = VelocityVector::from_displacement(&(&after - &before), 2. * dt);
+        let actual = orbit.calculate_velocity(mass, sun_mass, time).unwrap();
+        println!("expected: {}", expected.astro_display());
+        println!("actual: {}", actual.astro_display());
+        let difference = (&actual - &expected).length();
+        assert!(
+            difference.get::<meter_per_second>()
+                < 1e-4 * expected.length().get::<meter_per_second>()
+        );
+    }
+
+    #[test]
+    fn bodies_are_at_their_j2000_positions() {
+        // Heliocentric (and for the moon geocentric) ecliptic longitudes from the JPL ephemerides.
+        let expected_longitudes = [
+            (mercury(), 253.78),
+            (venus(), 182.60),
+            (earth(), 100.38),
+            (mars(), 359.45),
+            (jupiter(), 36.29),
+            (saturn(), 45.72),
+            (uranus(), 316.42),
+            (neptune(), 303.93),
+            (pluto(), 250.54),
+        ];
+        let sun_mass = Mass::new::<solar_mass>(1.);
+        for (planet, expected) in expected_longitudes {
+            let expected = Angle::new::<degree>(expected);
+            let actual = ecliptic_longitude_at_j2000(&planet, sun_mass);
+            println!(
+                "{}: expected {}, actual {}",
+                planet.name,
+                expected.astro_display(),
+                actual.astro_display()
+            );
+            assert!(angle_eq_within(actual, expected, Angle::new::<degree>(1.)));
+        }
+
+        let expected = Angle::new::<degree>(223.32);
+        let actual = ecliptic_longitude_at_j2000(&luna(), earth().mass);
+        println!(
+            "Moon: expected {}, actual {}",
+            expected.astro_display(),
+            actual.astro_display()
+        );
+        assert!(angle_eq_within(actual, expected, Angle::new::<degree>(1.)));
+    }
+
     #[test]
     fn axis_tilt_of_mercury() {
         let orbit_normal = mercury().orbit.normal();
@@ -0,0 +1,268 @@
+use astro_coords::cartesian::Cartesian;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{Mass, Time},
+    mass::kilogram,
+};
+
+use crate::{error::AstroUtilError, stars::data::StarData};
+
+use super::planet_data::PlanetData;
+
+/*
+ * A star system as a tree: the central star or stars, planets orbiting them, moons orbiting the planets and so on.
+ * Several central stars are treated as a single mass at the origin, which the planets orbit.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanetarySystem {
+    pub(super) stars: Vec<StarData>,
+    pub(super) planets: Vec<SatelliteNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SatelliteNode {
+    pub(super) body: PlanetData,
+    pub(super) satellites: Vec<SatelliteNode>,
+}
+
+impl SatelliteNode {
+    pub fn new(body: PlanetData) -> Self {
+        Self {
+            body,
+            satellites: Vec::new(),
+        }
+    }
+
+    pub fn with_satellite(mut self, satellite: SatelliteNode) -> Self {
+        self.satellites.push(satellite);
+        self
+    }
+
+    pub fn get_body(&self) -> &PlanetData {
+        &self.body
+    }
+
+    pub fn get_satellites(&self) -> &[SatelliteNode] {
+        &self.satellites
+    }
+
+    pub fn add_satellite(&mut self, satellite: SatelliteNode) {
+        self.satellites.push(satellite);
+    }
+
+    fn find_path<'a>(&'a self, name: &str, path: &mut Vec<&'a SatelliteNode>) -> bool {
+        path.push(self);
+        if self.body.get_name() == name {
+            return true;
+        }
+        for satellite in &self.satellites {
+            if satellite.find_path(name, path) {
+                return true;
+            }
+        }
+        path.pop();
+        false
+    }
+
+    fn collect_positions(
+        &self,
+        central_position: &Cartesian,
+        central_mass: Mass,
+        time: Time,
+        positions: &mut Vec<(String, Cartesian)>,
+    ) -> Result<(), AstroUtilError> {
+        let relative = self.body.get_orbital_parameters().calculate_position(
+            self.body.get_mass(),
+            central_mass,
+            time,
+        )?;
+        let position = central_position + &relative;
+        for satellite in &self.satellites {
+            satellite.collect_positions(&position, self.body.get_mass(), time, positions)?;
+        }
+        positions.push((self.body.get_name().clone(), position));
+        Ok(())
+    }
+}
+
+impl PlanetarySystem {
+    pub fn new(stars: Vec<StarData>) -> Self {
+        Self {
+            stars,
+            planets: Vec::new(),
+        }
+    }
+
+    pub fn with_planet(mut self, planet: SatelliteNode) -> Self {
+        self.planets.push(planet);
+        self
+    }
+
+    pub fn get_stars(&self) -> &[StarData] {
+        &self.stars
+    }
+
+    pub fn get_planets(&self) -> &[SatelliteNode] {
+        &self.planets
+    }
+
+    pub fn add_planet(&mut self, planet: SatelliteNode) {
+        self.planets.push(planet);
+    }
+
+    pub fn get_central_mass(&self, time: Time) -> Result<Mass, AstroUtilError> {
+        let mut mass = Mass::new::<kilogram>(0.);
+        for star in &self.stars {
+            mass += star.get_mass(time).ok_or_else(|| {
+                AstroUtilError::DataNotAvailable(format!("Mass of {}", star.get_name()))
+            })?;
+        }
+        Ok(mass)
+    }
+
+    pub fn find(&self, name: &str) -> Option<&SatelliteNode> {
+        self.find_path(name).and_then(|path| path.last().copied())
+    }
+
+    // The planet or moon that the named body orbits, or None if it orbits the central stars.
+    pub fn get_parent(&self, name: &str) -> Option<&PlanetData> {
+        let path = self.find_path(name)?;
+        let parent_index = path.len().checked_sub(2)?;
+        Some(&path[parent_index].body)
+    }
+
+    // Composes the orbits along the hierarchy, with the central stars at the origin.
+    pub fn get_position(&self, name: &str, time: Time) -> Result<Cartesian, AstroUtilError> {
+        let path = self
+            .find_path(name)
+            .ok_or_else(|| AstroUtilError::DataNotAvailable(format!("Body {}", name)))?;
+        let mut position = Cartesian::origin();
+        let mut central_mass = self.get_central_mass(time)?;
+        for node in path {
+            let relative = node.body.get_orbital_parameters().calculate_position(
+                node.body.get_mass(),
+                central_mass,
+                time,
+            )?;
+            position = &position + &relative;
+            central_mass = node.body.get_mass();
+        }
+        Ok(position)
+    }
+
+    pub fn get_positions(&self, time: Time) -> Result<Vec<(String, Cartesian)>, AstroUtilError> {
+        let central_mass = self.get_central_mass(time)?;
+        let mut positions = Vec::new();
+        for planet in &self.planets {
+            planet.collect_positions(&Cartesian::origin(), central_mass, time, &mut positions)?;
+        }
+        Ok(positions)
+    }
+
+    fn find_path(&self, name: &str) -> Option<Vec<&SatelliteNode>> {
+        let mut path = Vec::new();
+        for planet in &self.planets {
+            if planet.find_path(name, &mut path) {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        f64::Length,
+        length::{astronomical_unit, kilometer},
+        time::day,
+    };
+
+    use crate::real_data::{planets::*, solar_system::solar_system};
+
+    use super::*;
+
+    #[test]
+    fn solar_system_contains_the_moon_as_satellite_of_earth() {
+        let system = solar_system();
+        assert_eq!(system.get_stars().len(), 1);
+        let parent = system.get_parent(luna().name).unwrap();
+        assert_eq!(parent.get_name(), earth().name);
+        assert!(system.get_parent(earth().name).is_none());
+        assert!(system.find("Vulcan").is_none());
+    }
+
+    #[test]
+    fn moon_is_close_to_earth() {
+        let system = solar_system();
+        for days in [0., 10., 100., 1000.] {
+            let time = Time::new::<day>(days);
+            let earth_position = system.get_position(earth().name, time).unwrap();
+            let moon_position = system.get_position(luna().name, time).unwrap();
+            let distance = earth_position.distance(&moon_position).get::<kilometer>();
+            assert!(distance > 356_000.);
+            assert!(distance < 407_000.);
+            let earth_distance = earth_position.length().get::<astronomical_unit>();
+            assert!(earth_distance > 0.98 && earth_distance < 1.02);
+        }
+    }
+
+    #[test]
+    fn planet_positions_match_their_orbits() {
+        let system = solar_system();
+        let time = Time::new::<day>(123.);
+        let mars = mars();
+        let expected = mars
+            .orbit
+            .calculate_position(mars.mass, system.get_central_mass(time).unwrap(), time)
+            .unwrap();
+        let actual = system.get_position(mars.name, time).unwrap();
+        assert!(actual.distance(&expected).get::<kilometer>() < 1e-3);
+    }
+
+    #[test]
+    fn all_positions_are_resolved() {
+        let system = solar_system();
+        let time = Time::new::<day>(0.);
+        let positions = system.get_positions(time).unwrap();
+        for (name, position) in &positions {
+            let expected = system.get_position(name, time).unwrap();
+            assert!(position.distance(&expected).get::<kilometer>() < 1e-3);
+        }
+        assert!(positions.iter().any(|(name, _)| name == luna().name));
+    }
+
+    #[test]
+    fn sub_moons_are_resolved() {
+        let mut moon = SatelliteNode::new(luna().to_planet_data());
+        let mut sub_moon = luna().to_planet_data();
+        sub_moon.set_name("Moonmoon".to_string());
+        sub_moon.set_semi_major_axis(Length::new::<kilometer>(10_000.));
+        sub_moon.set_mass(luna().mass * 1e-6);
+        moon.add_satellite(SatelliteNode::new(sub_moon));
+        let system = PlanetarySystem::new(solar_system().get_stars().to_vec())
+            .with_planet(SatelliteNode::new(earth().to_planet_data()).with_satellite(moon));
+        let time = Time::new::<day>(5.);
+        let moon_position = system.get_position(luna().name, time).unwrap();
+        let sub_moon_position = system.get_position("Moonmoon", time).unwrap();
+        let distance = moon_position
+            .distance(&sub_moon_position)
+            .get::<kilometer>();
+        assert!(distance > 9_000. && distance < 11_000.);
+        assert_eq!(
+            system.get_parent("Moonmoon").unwrap().get_name(),
+            luna().name
+        );
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let system = solar_system();
+        let serialized = serde_json::to_string(&system).unwrap();
+        let deserialized: PlanetarySystem = serde_json::from_str(&serialized).unwrap();
+        let time = Time::new::<day>(42.);
+        let expected = system.get_position(luna().name, time).unwrap();
+        let actual = deserialized.get_position(luna().name, time).unwrap();
+        assert!(actual.distance(&expected).get::<kilometer>() < 1e-3);
+    }
+}
@@ -20,8 +20,10 @@ use super::{
 };
 
 pub fn generate_random_planet() -> PlanetData {
-    let mut rng = rand::rng();
+    generate_random_planet_with_rng(&mut rand::rng())
+}
 
+pub fn generate_random_planet_with_rng<R: Rng + ?Sized>(rng: &mut R) -> PlanetData {
     let name = String::new();
 
     let min = mercury().radius.get::<earth_radius>() * 0.5;
@@ -62,7 +64,9 @@ pub fn generate_random_planet() -> PlanetData {
 
     let argument_of_periapsis = Angle::new::<degree>(rng.random_range(0.0..360.0));
 
-    let rotation_axis = random_direction(&mut rng);
+    let mean_anomaly_at_epoch = Angle::new::<degree>(rng.random_range(0.0..360.0));
+
+    let rotation_axis = random_direction(rng);
 
     PlanetData {
         name,
@@ -80,16 +84,35 @@ pub fn generate_random_planet() -> PlanetData {
             inclination,
             longitude_of_ascending_node,
             argument_of_periapsis,
+            mean_anomaly_at_epoch,
+            Time::new::<hour>(0.),
         ),
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
     use super::*;
 
     #[test]
     fn generate_random_planet_does_not_crash() {
         let _ = generate_random_planet();
     }
+
+    #[test]
+    fn same_seed_generates_same_planet() {
+        let first = generate_random_planet_with_rng(&mut StdRng::seed_from_u64(42));
+        let second = generate_random_planet_with_rng(&mut StdRng::seed_from_u64(42));
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+        let other = generate_random_planet_with_rng(&mut StdRng::seed_from_u64(43));
+        assert_ne!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&other).unwrap()
+        );
+    }
 }
@@ -1,2 +1,3 @@
 pub mod planets;
+pub mod solar_system;
 pub mod stars;
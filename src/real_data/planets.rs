@@ -8,12 +8,18 @@ use uom::si::f64::Mass;
 use uom::si::f64::Time;
 use uom::si::length::meter;
 use uom::si::mass::kilogram;
+use uom::si::time::day;
 use uom::si::time::hour;
 
 use crate::color::srgb::sRGBColor;
 use crate::planets::orbit_parameters::OrbitParameters;
 use crate::planets::real_data::RealData;
 
+// Times are measured relative to the J2000 epoch, for which the mean anomalies below are given.
+fn j2000() -> Time {
+    Time::new::<day>(0.)
+}
+
 #[inline(always)]
 pub fn mercury() -> RealData {
     RealData {
@@ -24,6 +30,8 @@ pub fn mercury() -> RealData {
             inclination: Angle::new::<degree>(7.00487),
             longitude_of_ascending_node: Angle::new::<degree>(48.33167),
             argument_of_periapsis: Angle::new::<degree>(29.124279),
+            mean_anomaly_at_epoch: Angle::new::<degree>(174.7944),
+            epoch: j2000(),
         },
         geometric_albedo: 0.142,
         bond_albedo: Some(0.088),
@@ -49,6 +57,8 @@ pub fn venus() -> RealData {
             inclination: Angle::new::<degree>(3.39471),
             longitude_of_ascending_node: Angle::new::<degree>(76.68069),
             argument_of_periapsis: Angle::new::<degree>(54.85229),
+            mean_anomaly_at_epoch: Angle::new::<degree>(50.4461),
+            epoch: j2000(),
         },
         geometric_albedo: 0.689,
         bond_albedo: Some(0.76),
@@ -74,6 +84,8 @@ pub fn earth() -> RealData {
             inclination: Angle::new::<degree>(0.00005),
             longitude_of_ascending_node: Angle::new::<degree>(-11.26064),
             argument_of_periapsis: Angle::new::<degree>(114.20783),
+            mean_anomaly_at_epoch: Angle::new::<degree>(357.5174),
+            epoch: j2000(),
         },
         geometric_albedo: 0.367,
         bond_albedo: Some(0.306),
@@ -96,6 +108,8 @@ pub fn mars() -> RealData {
             inclination: Angle::new::<degree>(1.85061),
             longitude_of_ascending_node: Angle::new::<degree>(49.57854),
             argument_of_periapsis: Angle::new::<degree>(286.4623),
+            mean_anomaly_at_epoch: Angle::new::<degree>(19.4057),
+            epoch: j2000(),
         },
         geometric_albedo: 0.17,
         bond_albedo: Some(0.25),
@@ -121,6 +135,8 @@ pub fn ceres() -> RealData {
             inclination: Angle::new::<degree>(10.593),
             longitude_of_ascending_node: Angle::new::<degree>(80.393),
             argument_of_periapsis: Angle::new::<degree>(73.597),
+            mean_anomaly_at_epoch: Angle::new::<degree>(6.07),
+            epoch: j2000(),
         },
         geometric_albedo: 0.09,
         bond_albedo: None,
@@ -146,6 +162,8 @@ pub fn jupiter() -> RealData {
             inclination: Angle::new::<degree>(1.30530),
             longitude_of_ascending_node: Angle::new::<degree>(100.55615),
             argument_of_periapsis: Angle::new::<degree>(273.865),
+            mean_anomaly_at_epoch: Angle::new::<degree>(19.9752),
+            epoch: j2000(),
         },
         geometric_albedo: 0.538,
         bond_albedo: Some(0.503),
@@ -171,6 +189,8 @@ pub fn saturn() -> RealData {
             inclination: Angle::new::<degree>(2.48446),
             longitude_of_ascending_node: Angle::new::<degree>(113.71504),
             argument_of_periapsis: Angle::new::<degree>(339.39153),
+            mean_anomaly_at_epoch: Angle::new::<degree>(316.8477),
+            epoch: j2000(),
         },
         geometric_albedo: 0.499,
         bond_albedo: Some(0.342),
@@ -196,6 +216,8 @@ pub fn uranus() -> RealData {
             inclination: Angle::new::<degree>(0.76986),
             longitude_of_ascending_node: Angle::new::<degree>(74.22988),
             argument_of_periapsis: Angle::new::<degree>(96.734),
+            mean_anomaly_at_epoch: Angle::new::<degree>(142.2742),
+            epoch: j2000(),
         },
         geometric_albedo: 0.488,
         bond_albedo: Some(0.300),
@@ -221,6 +243,8 @@ pub fn neptune() -> RealData {
             inclination: Angle::new::<degree>(1.76917),
             longitude_of_ascending_node: Angle::new::<degree>(131.72169),
             argument_of_periapsis: Angle::new::<degree>(265.64685),
+            mean_anomaly_at_epoch: Angle::new::<degree>(267.5114),
+            epoch: j2000(),
         },
         geometric_albedo: 0.442,
         bond_albedo: Some(0.290),
@@ -246,6 +270,8 @@ pub fn pluto() -> RealData {
             inclination: Angle::new::<degree>(17.14175),
             longitude_of_ascending_node: Angle::new::<degree>(110.30347),
             argument_of_periapsis: Angle::new::<degree>(113.76329),
+            mean_anomaly_at_epoch: Angle::new::<degree>(14.8623),
+            epoch: j2000(),
         },
         geometric_albedo: 0.52,
         bond_albedo: Some(0.72),
@@ -271,6 +297,8 @@ pub fn luna() -> RealData {
             inclination: Angle::new::<degree>(5.145),
             longitude_of_ascending_node: Angle::new::<degree>(125.08),
             argument_of_periapsis: Angle::new::<degree>(318.15),
+            mean_anomaly_at_epoch: Angle::new::<degree>(135.0864),
+            epoch: j2000(),
         },
         geometric_albedo: 0.120,
         bond_albedo: Some(0.110),
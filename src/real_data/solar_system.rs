@@ -0,0 +1,19 @@
+use crate::planets::planetary_system::{PlanetarySystem, SatelliteNode};
+
+use super::{planets::*, stars::sun};
+
+pub fn solar_system() -> PlanetarySystem {
+    let earth = SatelliteNode::new(earth().to_planet_data())
+        .with_satellite(SatelliteNode::new(luna().to_planet_data()));
+    PlanetarySystem::new(vec![sun().to_star_data()])
+        .with_planet(SatelliteNode::new(mercury().to_planet_data()))
+        .with_planet(SatelliteNode::new(venus().to_planet_data()))
+        .with_planet(earth)
+        .with_planet(SatelliteNode::new(mars().to_planet_data()))
+        .with_planet(SatelliteNode::new(ceres().to_planet_data()))
+        .with_planet(SatelliteNode::new(jupiter().to_planet_data()))
+        .with_planet(SatelliteNode::new(saturn().to_planet_data()))
+        .with_planet(SatelliteNode::new(uranus().to_planet_data()))
+        .with_planet(SatelliteNode::new(neptune().to_planet_data()))
+        .with_planet(SatelliteNode::new(pluto().to_planet_data()))
+}
@@ -19,10 +19,12 @@ fn alpheratz() -> RealData {
         apparent_magnitude: 2.07,
         temperature: ThermodynamicTemperature::new::<kelvin>(13_800.),
         age: Some(Time::new::<gigayear>(0.06)),
-        lifetime: Time::new::<gigayear>(0.220601963),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(0, 8, 23.),
         declination: Declination::new(Sgn::Pos, 29, 5, 26.),
         distance: Length::new::<light_year>(97.),
+        spectral_type: "B8IVpMnHg",
     }
 }
 
@@ -37,10 +39,12 @@ fn mirach() -> RealData {
         apparent_magnitude: 2.07,
         temperature: ThermodynamicTemperature::new::<kelvin>(3842.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(1, 9, 44.),
         declination: Declination::new(Sgn::Pos, 35, 37, 14.),
         distance: Length::new::<light_year>(199.),
+        spectral_type: "M0III",
     }
 }
 
@@ -55,10 +59,12 @@ fn almach() -> RealData {
         apparent_magnitude: 2.1,
         temperature: ThermodynamicTemperature::new::<kelvin>(4250.),
         age: Some(Time::new::<gigayear>(0.0065)),
-        lifetime: Time::new::<gigayear>(0.008063854),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(2, 3, 54.),
         declination: Declination::new(Sgn::Pos, 42, 19, 47.),
         distance: Length::new::<light_year>(355.),
+        spectral_type: "K3IIb",
     }
 }
 
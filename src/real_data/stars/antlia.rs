@@ -19,10 +19,12 @@ fn alpha_antliae() -> RealData {
         apparent_magnitude: 4.28,
         temperature: ThermodynamicTemperature::new::<kelvin>(4070.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(10, 27, 9.),
         declination: Declination::new(Sgn::Neg, 31, 4, 4.),
         distance: Length::new::<light_year>(366.3),
+        spectral_type: "K4III",
     }
 }
 
@@ -37,10 +39,12 @@ fn epsilon_antliae() -> RealData {
         apparent_magnitude: 4.51,
         temperature: ThermodynamicTemperature::new::<kelvin>(4237.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(9, 29, 15.),
         declination: Declination::new(Sgn::Neg, 35, 57, 5.),
         distance: Length::new::<light_year>(699.6),
+        spectral_type: "K3III",
     }
 }
 
@@ -55,10 +59,12 @@ fn iota_antliae() -> RealData {
         apparent_magnitude: 4.60,
         temperature: ThermodynamicTemperature::new::<kelvin>(4892.),
         age: Some(Time::new::<gigayear>(2.2)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(10, 56, 43.),
         declination: Declination::new(Sgn::Neg, 37, 8, 16.),
         distance: Length::new::<light_year>(198.8),
+        spectral_type: "K0III",
     }
 }
 
@@ -1,7 +1,7 @@
 use astro_coords::ra_and_dec::*;
-use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
+use astro_units::{length::solar_radius, mass::solar_mass};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature},
     length::light_year,
     thermodynamic_temperature::kelvin,
 };
@@ -19,10 +19,12 @@ fn alpha_apodis() -> RealData {
         apparent_magnitude: 3.825,
         temperature: ThermodynamicTemperature::new::<kelvin>(4312.),
         age: None,
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(14, 47, 52.),
         declination: Declination::new(Sgn::Neg, 79, 2, 41.),
         distance: Length::new::<light_year>(411.1),
+        spectral_type: "K3III",
     }
 }
 
@@ -37,10 +39,12 @@ fn gamma_apodis() -> RealData {
         apparent_magnitude: 3.86,
         temperature: ThermodynamicTemperature::new::<kelvin>(5040.),
         age: None,
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(16, 33, 27.),
         declination: Declination::new(Sgn::Neg, 78, 53, 50.),
         distance: Length::new::<light_year>(150.),
+        spectral_type: "G9III",
     }
 }
 
@@ -55,10 +59,12 @@ fn beta_apodis() -> RealData {
         apparent_magnitude: 4.24,
         temperature: ThermodynamicTemperature::new::<kelvin>(4900.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.65092742),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(16, 43, 5.),
         declination: Declination::new(Sgn::Neg, 77, 31, 3.),
         distance: Length::new::<light_year>(149.),
+        spectral_type: "K0III",
     }
 }
 
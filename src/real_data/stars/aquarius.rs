@@ -19,10 +19,12 @@ fn sadalsuud() -> RealData {
         apparent_magnitude: 2.87,
         temperature: ThermodynamicTemperature::new::<kelvin>(5608.),
         age: Some(Time::new::<gigayear>(0.110)),
-        lifetime: Time::new::<gigayear>(0.111),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(21, 31, 34.),
         declination: Declination::new(Sgn::Neg, 5, 34, 16.),
         distance: Length::new::<light_year>(540.),
+        spectral_type: "G0Ib",
     }
 }
 
@@ -40,7 +42,9 @@ fn sadalmelik() -> RealData {
         declination: Declination::new(Sgn::Neg, 0, 19, 11.),
         distance: Length::new::<light_year>(758.1),
         age: Some(Time::new::<gigayear>(0.053)),
-        lifetime: Time::new::<gigayear>(0.10143918),
+        motion: None,
+        variability: None,
+        spectral_type: "G2Ib",
     }
 }
 
@@ -58,7 +62,9 @@ fn skat() -> RealData {
         declination: Declination::new(Sgn::Neg, 15, 49, 15.),
         distance: Length::new::<light_year>(159.5),
         age: Some(Time::new::<gigayear>(0.3)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
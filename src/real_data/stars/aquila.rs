@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn altair() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn altair() -> RealData {
         declination: Declination::new(Sgn::Pos, 8, 52, 6.),
         distance: Length::new::<light_year>(17.),
         age: Some(Time::new::<gigayear>(0.100)),
-        lifetime: Time::new::<gigayear>(1.65092742),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(536.23),
+            angular_velocity_from_mas_per_year(385.29),
+            Velocity::new::<kilometer_per_second>(-26.1),
+        )),
+        variability: None,
+        spectral_type: "A7V",
     }
 }
 
@@ -40,7 +50,9 @@ fn tarazed() -> RealData {
         declination: Declination::new(Sgn::Pos, 10, 36, 48.),
         distance: Length::new::<light_year>(460.5),
         age: Some(Time::new::<gigayear>(0.250)),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "K3II",
     }
 }
 
@@ -58,7 +70,9 @@ fn okab() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.27)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9620.),
         age: Some(Time::new::<gigayear>(0.1)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A0V",
     }
 }
 
@@ -76,7 +90,9 @@ fn theta_aquilae() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.76)),
         temperature: ThermodynamicTemperature::new::<kelvin>(10_300.),
         age: Some(Time::new::<gigayear>(0.0209)),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "B9.5III",
     }
 }
 
@@ -94,7 +110,9 @@ fn delta_aquilae() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.04)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7016.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "F2IV",
     }
 }
 
@@ -112,7 +130,9 @@ fn lambda_aquilae() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_780.),
         age: Some(Time::new::<gigayear>(0.160)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "B9V",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_arae() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(18_044.),
         age: Some(Time::new::<gigayear>(0.026)),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
+        spectral_type: "B2Vne",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_arae() -> RealData {
         declination: Declination::new(Sgn::Neg, 55, 31, 48.),
         distance: Length::new::<light_year>(602.6),
         age: Some(Time::new::<gigayear>(0.035)),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "K3Ib",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_arae() -> RealData {
         radius: Some(Length::new::<solar_radius>(23.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(21_500.),
         age: Some(Time::new::<gigayear>(0.009)),
-        lifetime: Time::new::<gigayear>(0.009767659),
+        motion: None,
+        variability: None,
+        spectral_type: "B1Ib",
     }
 }
 
@@ -76,7 +82,9 @@ fn delta_arae() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.12)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_962.),
         age: Some(Time::new::<gigayear>(0.125)),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "B8V",
     }
 }
 
@@ -94,7 +102,9 @@ fn zeta_arae() -> RealData {
         radius: Some(Length::new::<solar_radius>(114.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4246.),
         age: Some(Time::new::<gigayear>(0.045)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -22,7 +22,9 @@ fn hamal() -> RealData {
         declination: Declination::new(Sgn::Pos, 23, 27, 45.),
         distance: Length::new::<light_year>(66.),
         age: Some(Time::new::<gigayear>(2.5)),
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_arietis() -> RealData {
         radius: Some(Length::new::<solar_radius>(23.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9000.),
         age: Some(Time::new::<gigayear>(0.3)),
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "A5V",
     }
 }
 
@@ -58,7 +62,9 @@ fn bharani() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(11_900.),
         age: Some(Time::new::<gigayear>(0.130)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "B8Vn",
     }
 }
 
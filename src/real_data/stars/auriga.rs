@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn capella() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn capella() -> RealData {
         right_ascension: RightAscension::new(5, 16, 41.),
         declination: Declination::new(Sgn::Pos, 45, 59, 53.),
         distance: Length::new::<light_year>(42.),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(75.25),
+            angular_velocity_from_mas_per_year(-426.89),
+            Velocity::new::<kilometer_per_second>(29.19),
+        )),
+        variability: None,
+        spectral_type: "G3III",
     }
 }
 
@@ -40,7 +50,9 @@ fn menkalinan() -> RealData {
         declination: Declination::new(Sgn::Pos, 44, 56, 51.),
         distance: Length::new::<light_year>(82.),
         age: Some(Time::new::<gigayear>(0.570)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A1IV",
     }
 }
 
@@ -58,7 +70,9 @@ fn hassaleh() -> RealData {
         declination: Declination::new(Sgn::Pos, 33, 9, 58.),
         distance: Length::new::<light_year>(490.),
         age: Some(Time::new::<gigayear>(0.04)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "K3II",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn arcturus() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn arcturus() -> RealData {
         declination: Declination::new(Sgn::Pos, 19, 10, 56.),
         distance: Length::new::<light_year>(37.),
         age: Some(Time::new::<gigayear>(6.9)),
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-1093.39),
+            angular_velocity_from_mas_per_year(-2000.06),
+            Velocity::new::<kilometer_per_second>(-5.19),
+        )),
+        variability: None,
+        spectral_type: "K1.5III",
     }
 }
 
@@ -40,7 +50,9 @@ fn izar() -> RealData {
         declination: Declination::new(Sgn::Pos, 27, 4, 27.),
         distance: Length::new::<light_year>(210.),
         age: Some(Time::new::<gigayear>(0.0374)),
-        lifetime: Time::new::<gigayear>(0.136126994),
+        motion: None,
+        variability: None,
+        spectral_type: "K0II-III",
     }
 }
 
@@ -58,7 +70,9 @@ fn gamma_bootis() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.16)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7800.),
         age: Some(Time::new::<gigayear>(0.9)),
-        lifetime: Time::new::<gigayear>(1.17901142),
+        motion: None,
+        variability: None,
+        spectral_type: "A7III",
     }
 }
 
@@ -76,7 +90,9 @@ fn delta_bootis() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4847.),
         age: None,
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -94,7 +110,9 @@ fn beta_bootis() -> RealData {
         radius: Some(Length::new::<solar_radius>(21.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4932.),
         age: Some(Time::new::<gigayear>(0.240)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -112,7 +130,9 @@ fn muphrid() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.672)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6100.),
         age: Some(Time::new::<gigayear>(1.6)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "G0IV",
     }
 }
 
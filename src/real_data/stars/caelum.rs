@@ -22,7 +22,9 @@ fn alpha_caeli() -> RealData {
         declination: Declination::new(Sgn::Neg, 41, 51, 50.),
         distance: Length::new::<light_year>(65.63),
         age: Some(Time::new::<gigayear>(0.9)),
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: None,
+        variability: None,
+        spectral_type: "F2V",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma1_caeli() -> RealData {
         declination: Declination::new(Sgn::Neg, 35, 28, 59.),
         distance: Length::new::<light_year>(185.),
         age: None,
-        lifetime: Time::new::<gigayear>(3.10253119),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -58,7 +62,9 @@ fn beta_caeli() -> RealData {
         declination: Declination::new(Sgn::Neg, 37, 8, 39.),
         distance: Length::new::<light_year>(94.),
         age: Some(Time::new::<gigayear>(1.753)),
-        lifetime: Time::new::<gigayear>(3.9126515),
+        motion: None,
+        variability: None,
+        spectral_type: "F1V",
     }
 }
 
@@ -22,7 +22,9 @@ fn beta_camelopardalis() -> RealData {
         declination: Declination::new(Sgn::Pos, 60, 26, 32.),
         distance: Length::new::<light_year>(870.),
         age: Some(Time::new::<gigayear>(0.053)),
-        lifetime: Time::new::<gigayear>(0.063411557),
+        motion: None,
+        variability: None,
+        spectral_type: "G1Ib-IIa",
     }
 }
 
@@ -40,7 +42,9 @@ fn cs_camelopardalis() -> RealData {
         declination: Declination::new(Sgn::Pos, 59, 56, 25.),
         distance: Length::new::<light_year>(4289.),
         age: Some(Time::new::<gigayear>(0.011)),
-        lifetime: Time::new::<gigayear>(0.011037517),
+        motion: None,
+        variability: None,
+        spectral_type: "B9Ia",
     }
 }
 
@@ -58,7 +62,9 @@ fn alpha_camelopardalis() -> RealData {
         declination: Declination::new(Sgn::Pos, 66, 20, 34.),
         distance: Length::new::<light_year>(6_000.),
         age: Some(Time::new::<gigayear>(0.002)),
-        lifetime: Time::new::<gigayear>(0.005279908),
+        motion: None,
+        variability: None,
+        spectral_type: "O9Ia",
     }
 }
 
@@ -22,7 +22,9 @@ fn tarf() -> RealData {
         declination: Declination::new(Sgn::Pos, 9, 11, 8.),
         distance: Length::new::<light_year>(290.),
         age: Some(Time::new::<gigayear>(1.7)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "K4III",
     }
 }
 
@@ -40,7 +42,9 @@ fn asellus_australis() -> RealData {
         declination: Declination::new(Sgn::Pos, 18, 9, 16.),
         distance: Length::new::<light_year>(131.),
         age: Some(Time::new::<gigayear>(1.5)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -58,7 +62,9 @@ fn iota_cancri() -> RealData {
         declination: Declination::new(Sgn::Pos, 28, 45, 36.),
         distance: Length::new::<light_year>(330.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
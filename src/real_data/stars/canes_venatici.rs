@@ -19,11 +19,13 @@ fn cor_caroli() -> RealData {
         apparent_magnitude: 2.89,
         temperature: ThermodynamicTemperature::new::<kelvin>(11_600.),
         age: Some(Time::new::<gigayear>(0.165)),
-        lifetime: Time::new::<gigayear>(0.42),
+        motion: None,
+        variability: None,
 
         right_ascension: RightAscension::new(12, 56, 2.),
         declination: Declination::new(Sgn::Pos, 38, 19, 6.),
         distance: Length::new::<light_year>(110.1),
+        spectral_type: "",
     }
 }
 
@@ -41,7 +43,9 @@ fn chara() -> RealData {
         declination: Declination::new(Sgn::Pos, 41, 21, 27.),
         distance: Length::new::<light_year>(27.63),
         age: Some(Time::new::<gigayear>(3.4)),
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
+        spectral_type: "G0V",
     }
 }
 
@@ -59,7 +63,9 @@ fn twentyfour_canum_venaticorum() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.90)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8285.),
         age: Some(Time::new::<gigayear>(0.360)),
-        lifetime: Time::new::<gigayear>(1.59501327),
+        motion: None,
+        variability: None,
+        spectral_type: "A4V",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn sirius() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn sirius() -> RealData {
         declination: Declination::new(Sgn::Neg, 16, 42, 58.),
         distance: Length::new::<light_year>(9.),
         age: Some(Time::new::<gigayear>(0.242)),
-        lifetime: Time::new::<gigayear>(1.25731981),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-546.01),
+            angular_velocity_from_mas_per_year(-1223.07),
+            Velocity::new::<kilometer_per_second>(-5.5),
+        )),
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -40,7 +50,9 @@ fn adhara() -> RealData {
         declination: Declination::new(Sgn::Neg, 28, 58, 19.),
         distance: Length::new::<light_year>(431.),
         age: Some(Time::new::<gigayear>(0.019)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B2II",
     }
 }
 
@@ -58,7 +70,9 @@ fn wezen() -> RealData {
         declination: Declination::new(Sgn::Neg, 26, 23, 36.),
         distance: Length::new::<light_year>(1791.),
         age: Some(Time::new::<gigayear>(0.012)),
-        lifetime: Time::new::<gigayear>(0.012799766),
+        motion: None,
+        variability: None,
+        spectral_type: "F8Ia",
     }
 }
 
@@ -76,7 +90,9 @@ fn mirzam() -> RealData {
         declination: Declination::new(Sgn::Neg, 17, 57, 21.),
         distance: Length::new::<light_year>(499.),
         age: Some(Time::new::<gigayear>(0.0124)),
-        lifetime: Time::new::<gigayear>(0.015362858),
+        motion: None,
+        variability: None,
+        spectral_type: "B1II-III",
     }
 }
 
@@ -94,7 +110,9 @@ fn aludra() -> RealData {
         declination: Declination::new(Sgn::Neg, 29, 18, 11.),
         distance: Length::new::<light_year>(3196.),
         age: Some(Time::new::<gigayear>(0.0083)),
-        lifetime: Time::new::<gigayear>(0.011037517),
+        motion: None,
+        variability: None,
+        spectral_type: "B5Ia",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn procyon() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn procyon() -> RealData {
         declination: Declination::new(Sgn::Pos, 5, 13, 30.),
         distance: Length::new::<light_year>(11.),
         age: Some(Time::new::<gigayear>(1.37)),
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-714.59),
+            angular_velocity_from_mas_per_year(-1036.8),
+            Velocity::new::<kilometer_per_second>(-3.2),
+        )),
+        variability: None,
+        spectral_type: "F5IV-V",
     }
 }
 
@@ -40,7 +50,9 @@ fn gomeisa() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_772.),
         age: Some(Time::new::<gigayear>(0.160)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "B8Ve",
     }
 }
 
@@ -58,7 +70,9 @@ fn gamma_canis_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(36.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4036.),
         age: Some(Time::new::<gigayear>(1.3)),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -22,7 +22,9 @@ fn dabih() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4900.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.220601963),
+        motion: None,
+        variability: None,
+        spectral_type: "K0II-III",
     }
 }
 
@@ -40,7 +42,9 @@ fn deneb_algedi() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.91)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7301.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "A7III",
     }
 }
 
@@ -58,7 +62,9 @@ fn omega_capricorni() -> RealData {
         radius: Some(Length::new::<solar_radius>(172.1)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3915.),
         age: Some(Time::new::<gigayear>(0.0481)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn canopus() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn canopus() -> RealData {
         declination: Declination::new(Sgn::Neg, 52, 41, 44.),
         distance: Length::new::<light_year>(313.),
         age: Some(Time::new::<gigayear>(0.0251)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(19.93),
+            angular_velocity_from_mas_per_year(23.24),
+            Velocity::new::<kilometer_per_second>(20.3),
+        )),
+        variability: None,
+        spectral_type: "A9II",
     }
 }
 
@@ -40,7 +50,9 @@ fn miaplacidus() -> RealData {
         declination: Declination::new(Sgn::Neg, 69, 43, 2.),
         distance: Length::new::<light_year>(111.),
         age: Some(Time::new::<gigayear>(0.260)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "A1III",
     }
 }
 
@@ -58,7 +70,9 @@ fn avior() -> RealData {
         declination: Declination::new(Sgn::Neg, 59, 30, 34.),
         distance: Length::new::<light_year>(632.),
         age: Some(Time::new::<gigayear>(0.026)),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -76,7 +90,9 @@ fn aspidiske() -> RealData {
         declination: Declination::new(Sgn::Neg, 59, 16, 30.),
         distance: Length::new::<light_year>(694.),
         age: Some(Time::new::<gigayear>(0.0374)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "A7Ib",
     }
 }
 
@@ -22,7 +22,9 @@ fn navi() -> RealData {
         declination: Declination::new(Sgn::Pos, 60, 43, 0.),
         distance: Length::new::<light_year>(613.),
         age: Some(Time::new::<gigayear>(0.008)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B0.5IVe",
     }
 }
 
@@ -40,7 +42,9 @@ fn schedar() -> RealData {
         declination: Declination::new(Sgn::Pos, 56, 32, 14.),
         distance: Length::new::<light_year>(228.),
         age: Some(Time::new::<gigayear>(0.19)),
-        lifetime: Time::new::<gigayear>(0.193156929),
+        motion: None,
+        variability: None,
+        spectral_type: "K0IIIa",
     }
 }
 
@@ -58,7 +62,9 @@ fn caph() -> RealData {
         declination: Declination::new(Sgn::Pos, 59, 8, 59.),
         distance: Length::new::<light_year>(54.),
         age: Some(Time::new::<gigayear>(1.1)),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "F2III",
     }
 }
 
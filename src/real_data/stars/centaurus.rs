@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn rigel_kentaurus() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn rigel_kentaurus() -> RealData {
         declination: Declination::new(Sgn::Neg, 60, 50, 2.),
         distance: Length::new::<light_year>(4.),
         age: Some(Time::new::<gigayear>(4.85)),
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-3679.25),
+            angular_velocity_from_mas_per_year(473.67),
+            Velocity::new::<kilometer_per_second>(-21.4),
+        )),
+        variability: None,
+        spectral_type: "G2V",
     }
 }
 
@@ -40,7 +50,9 @@ fn hadar() -> RealData {
         declination: Declination::new(Sgn::Neg, 60, 22, 23.),
         distance: Length::new::<light_year>(526.),
         age: Some(Time::new::<gigayear>(0.0141)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B1III",
     }
 }
 
@@ -58,7 +70,9 @@ fn menkent() -> RealData {
         declination: Declination::new(Sgn::Neg, 36, 22, 11.),
         distance: Length::new::<light_year>(61.),
         age: None,
-        lifetime: Time::new::<gigayear>(4.45521207),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -76,7 +90,9 @@ fn muhlifain() -> RealData {
         declination: Declination::new(Sgn::Neg, 48, 57, 35.),
         distance: Length::new::<light_year>(130.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "A1IV",
     }
 }
 
@@ -94,7 +110,9 @@ fn epsilon_centauri() -> RealData {
         declination: Declination::new(Sgn::Neg, 53, 27, 59.),
         distance: Length::new::<light_year>(376.),
         age: Some(Time::new::<gigayear>(0.0158)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B1III",
     }
 }
 
@@ -112,7 +130,9 @@ fn eta_centauri() -> RealData {
         declination: Declination::new(Sgn::Neg, 42, 9, 28.),
         distance: Length::new::<light_year>(308.),
         age: Some(Time::new::<gigayear>(0.0056)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B2Ve",
     }
 }
 
@@ -130,7 +150,9 @@ fn zeta_centauri() -> RealData {
         declination: Declination::new(Sgn::Neg, 47, 17, 18.),
         distance: Length::new::<light_year>(384.),
         age: Some(Time::new::<gigayear>(0.04)),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "B2.5IV",
     }
 }
 
@@ -148,7 +170,9 @@ fn ma_wei() -> RealData {
         declination: Declination::new(Sgn::Neg, 50, 43, 21.),
         distance: Length::new::<light_year>(395.),
         age: Some(Time::new::<gigayear>(0.02)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IVne",
     }
 }
 
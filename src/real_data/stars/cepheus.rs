@@ -4,9 +4,10 @@ use uom::si::{
     f64::{Length, Mass, ThermodynamicTemperature, Time},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    time::day,
 };
 
-use crate::stars::real_data::RealData;
+use crate::stars::{real_data::RealData, variability::Variability};
 
 fn alderamin() -> RealData {
     RealData {
@@ -22,7 +23,9 @@ fn alderamin() -> RealData {
         declination: Declination::new(Sgn::Pos, 62, 35, 8.),
         distance: Length::new::<light_year>(49.),
         age: Some(Time::new::<gigayear>(0.82)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "A8V",
     }
 }
 
@@ -40,7 +43,9 @@ fn alfirk() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(27_000.),
         age: Some(Time::new::<gigayear>(0.0087)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "B1III",
     }
 }
 
@@ -58,7 +63,9 @@ fn errai() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.93)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4792.),
         age: Some(Time::new::<gigayear>(3.25)),
-        lifetime: Time::new::<gigayear>(3.9126515),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III-IV",
     }
 }
 
@@ -76,7 +83,13 @@ fn delta_cepheri() -> RealData {
         radius: Some(Length::new::<solar_radius>(44.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6000.),
         age: Some(Time::new::<gigayear>(0.079)),
-        lifetime: Time::new::<gigayear>(0.151849866),
+        motion: None,
+        variability: Some(Variability::classical_cepheid(
+            Time::new::<day>(5.366_249),
+            0.89,
+            0.75,
+        )),
+        spectral_type: "F5Ib-G1Ib",
     }
 }
 
@@ -94,7 +107,9 @@ fn eta_cephei() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.12)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4950.),
         age: Some(Time::new::<gigayear>(1.9)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K0IV",
     }
 }
 
@@ -112,7 +127,9 @@ fn iota_cephei() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.08)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4768.),
         age: Some(Time::new::<gigayear>(1.0)),
-        lifetime: Time::new::<gigayear>(1.09929685),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -130,7 +147,9 @@ fn zeta_cephei() -> RealData {
         radius: Some(Length::new::<solar_radius>(94.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4072.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "K1.5Ib",
     }
 }
 
@@ -148,7 +167,9 @@ fn erakis() -> RealData {
         declination: Declination::new(Sgn::Pos, 58, 46, 48.),
         distance: Length::new::<light_year>(3066.),
         age: Some(Time::new::<gigayear>(0.0097)),
-        lifetime: Time::new::<gigayear>(0.009767659),
+        motion: None,
+        variability: None,
+        spectral_type: "M2Ia",
     }
 }
 
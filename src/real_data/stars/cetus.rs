@@ -4,9 +4,13 @@ use uom::si::{
     f64::{Length, Mass, ThermodynamicTemperature, Time},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    time::day,
 };
 
-use crate::stars::real_data::RealData;
+use crate::stars::{
+    real_data::RealData,
+    variability::{LightCurveShape, Variability, VariableKind},
+};
 
 fn diphda() -> RealData {
     RealData {
@@ -22,7 +26,9 @@ fn diphda() -> RealData {
         declination: Declination::new(Sgn::Neg, 17, 59, 12.),
         distance: Length::new::<light_year>(96.),
         age: Some(Time::new::<gigayear>(0.4)),
-        lifetime: Time::new::<gigayear>(0.513076303),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -40,7 +46,9 @@ fn menkar() -> RealData {
         declination: Declination::new(Sgn::Pos, 4, 5, 23.),
         distance: Length::new::<light_year>(220.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "M1.5IIIa",
     }
 }
 
@@ -58,7 +66,15 @@ fn mira() -> RealData {
         radius: Some(Length::new::<solar_radius>(350.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3000.),
         age: Some(Time::new::<gigayear>(4.5)),
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: Some(Variability::new(
+            VariableKind::Mira,
+            Time::new::<day>(332.),
+            6.,
+            0.,
+            LightCurveShape::Sawtooth { rise_fraction: 0.4 },
+        )),
+        spectral_type: "M7IIIe",
     }
 }
 
@@ -76,7 +92,9 @@ fn baten_kaitos() -> RealData {
         radius: Some(Length::new::<solar_radius>(25.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4581.),
         age: Some(Time::new::<gigayear>(0.9)),
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -94,7 +112,9 @@ fn kaffaljidhma() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8551.),
         age: Some(Time::new::<gigayear>(0.647)),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "A2V",
     }
 }
 
@@ -112,7 +132,9 @@ fn iota_ceti() -> RealData {
         radius: Some(Length::new::<solar_radius>(34.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4446.),
         age: Some(Time::new::<gigayear>(0.5)),
-        lifetime: Time::new::<gigayear>(0.513076303),
+        motion: None,
+        variability: None,
+        spectral_type: "K1.5III",
     }
 }
 
@@ -130,7 +152,9 @@ fn deneb_algenubi() -> RealData {
         radius: Some(Length::new::<solar_radius>(15.10)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4543.),
         age: Some(Time::new::<gigayear>(1.6)),
-        lifetime: Time::new::<gigayear>(1.65092742),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -148,7 +172,9 @@ fn tau_ceti() -> RealData {
         radius: Some(Length::new::<solar_radius>(0.793)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5320.),
         age: Some(Time::new::<gigayear>(9.)),
-        lifetime: Time::new::<gigayear>(21.4199307),
+        motion: None,
+        variability: None,
+        spectral_type: "G8V",
     }
 }
 
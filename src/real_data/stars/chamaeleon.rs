@@ -22,7 +22,9 @@ fn alpha_chamaeleontis() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.11)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6580.),
         age: Some(Time::new::<gigayear>(1.8)),
-        lifetime: Time::new::<gigayear>(3.10253119),
+        motion: None,
+        variability: None,
+        spectral_type: "F5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma_chamaeleontis() -> RealData {
         radius: Some(Length::new::<solar_radius>(67.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4035.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
@@ -58,7 +62,9 @@ fn beta_chamaeleonis() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.84)),
         temperature: ThermodynamicTemperature::new::<kelvin>(14_495.),
         age: Some(Time::new::<gigayear>(0.0227)),
-        lifetime: Time::new::<gigayear>(0.078916095),
+        motion: None,
+        variability: None,
+        spectral_type: "B4V",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_circini() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.967)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7500.),
         age: Some(Time::new::<gigayear>(0.012)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "A7VpSrCrEu",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_circini() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8676.),
         age: Some(Time::new::<gigayear>(0.4)),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_circini() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(15_135.),
         age: Some(Time::new::<gigayear>(0.0631)),
-        lifetime: Time::new::<gigayear>(0.073299383),
+        motion: None,
+        variability: None,
+        spectral_type: "B5IV",
     }
 }
 
@@ -22,7 +22,9 @@ fn phact() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(12_963.),
         age: Some(Time::new::<gigayear>(0.093)),
-        lifetime: Time::new::<gigayear>(0.151849866),
+        motion: None,
+        variability: None,
+        spectral_type: "B9Ve",
     }
 }
 
@@ -40,7 +42,9 @@ fn wazn() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4545.),
         age: Some(Time::new::<gigayear>(2.)),
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "K1.5III",
     }
 }
 
@@ -58,7 +62,9 @@ fn delta_columbae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(5136.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "G7II",
     }
 }
 
@@ -76,7 +82,9 @@ fn epsilon_columbae() -> RealData {
         radius: Some(Length::new::<solar_radius>(25.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4575.),
         age: Some(Time::new::<gigayear>(0.8)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -22,7 +22,9 @@ fn diadem() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(6365.),
         age: None,
-        lifetime: Time::new::<gigayear>(4.45521207),
+        motion: None,
+        variability: None,
+        spectral_type: "F5V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_coma_berenices() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.106)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5936.),
         age: Some(Time::new::<gigayear>(2.)),
-        lifetime: Time::new::<gigayear>(5.9461393),
+        motion: None,
+        variability: None,
+        spectral_type: "F9.5V",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_coma_berenices() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.76)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4652.),
         age: Some(Time::new::<gigayear>(1.8)),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
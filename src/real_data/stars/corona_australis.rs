@@ -22,7 +22,9 @@ fn meridiana() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.21)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9916.),
         age: Some(Time::new::<gigayear>(0.254)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A2V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_coronae_australis() -> RealData {
         radius: Some(Length::new::<solar_radius>(38.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4575.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.10143918),
+        motion: None,
+        variability: None,
+        spectral_type: "K0II",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_coronae_australis() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.47)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6090.),
         age: Some(Time::new::<gigayear>(5.)),
-        lifetime: Time::new::<gigayear>(5.9461393),
+        motion: None,
+        variability: None,
+        spectral_type: "F8V",
     }
 }
 
@@ -19,10 +19,12 @@ fn alphecca() -> RealData {
         apparent_magnitude: 2.22,
         temperature: ThermodynamicTemperature::new::<kelvin>(9700.),
         age: Some(Time::new::<gigayear>(0.314)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(15, 34, 41.),
         declination: Declination::new(Sgn::Pos, 26, 42, 53.),
         distance: Length::new::<light_year>(75.),
+        spectral_type: "A1IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn nausakan() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.63)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7980.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.17901142),
+        motion: None,
+        variability: None,
+        spectral_type: "A5Vp",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_coronae_borealis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(7649.),
         age: Some(Time::new::<gigayear>(0.4)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A0IV",
     }
 }
 
@@ -76,7 +82,9 @@ fn delta_coronae_borealis() -> RealData {
         radius: Some(Length::new::<solar_radius>(7.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5180.),
         age: Some(Time::new::<gigayear>(0.8)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "G5III-IV",
     }
 }
 
@@ -94,7 +102,9 @@ fn epsilon_coronae_borealis() -> RealData {
         radius: Some(Length::new::<solar_radius>(21.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4365.),
         age: Some(Time::new::<gigayear>(2.8)),
-        lifetime: Time::new::<gigayear>(2.82957282),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -112,7 +122,9 @@ fn iota_coronae_borealis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(10_727.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "",
     }
 }
 
@@ -130,7 +142,9 @@ fn theta_coronae_borealis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(14_000.),
         age: Some(Time::new::<gigayear>(0.085)),
-        lifetime: Time::new::<gigayear>(0.170765802),
+        motion: None,
+        variability: None,
+        spectral_type: "B6Vnne",
     }
 }
 
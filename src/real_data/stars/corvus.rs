@@ -22,7 +22,9 @@ fn ghurab() -> RealData {
         right_ascension: RightAscension::new(12, 15, 48.),
         declination: Declination::new(Sgn::Neg, 17, 32, 31.),
         distance: Length::new::<light_year>(165.),
-        lifetime: Time::new::<gigayear>(0.170765802),
+        motion: None,
+        variability: None,
+        spectral_type: "B8IIIpHgMn",
     }
 }
 
@@ -40,7 +42,9 @@ fn kraz() -> RealData {
         right_ascension: RightAscension::new(12, 34, 23.),
         declination: Declination::new(Sgn::Neg, 23, 23, 48.),
         distance: Length::new::<light_year>(146.),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "G5II",
     }
 }
 
@@ -58,7 +62,9 @@ fn algorab() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(10_400.),
         age: Some(Time::new::<gigayear>(0.260)),
-        lifetime: Time::new::<gigayear>(0.513076303),
+        motion: None,
+        variability: None,
+        spectral_type: "B9.5V",
     }
 }
 
@@ -76,7 +82,9 @@ fn epsilon_corvi() -> RealData {
         radius: Some(Length::new::<solar_radius>(52.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4320.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -22,7 +22,9 @@ fn alkes() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.32)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4691.),
         age: Some(Time::new::<gigayear>(1.4)),
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_crateris() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(8830.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A1III",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_crateris() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8020.),
         age: Some(Time::new::<gigayear>(0.757)),
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
+        spectral_type: "A7V",
     }
 }
 
@@ -76,7 +82,9 @@ fn delta_crateris() -> RealData {
         radius: Some(Length::new::<solar_radius>(22.44)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4510.),
         age: Some(Time::new::<gigayear>(2.2)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
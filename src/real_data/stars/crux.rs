@@ -19,10 +19,12 @@ fn acrux() -> RealData {
         apparent_magnitude: 0.77,
         temperature: ThermodynamicTemperature::new::<kelvin>(24_000.),
         age: Some(Time::new::<gigayear>(0.0108)),
-        lifetime: Time::new::<gigayear>(0.011037517),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(12, 26, 36.),
         declination: Declination::new(Sgn::Neg, 63, 5, 57.),
         distance: Length::new::<light_year>(321.),
+        spectral_type: "B0.5IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn mimosa() -> RealData {
         right_ascension: RightAscension::new(12, 47, 43.),
         declination: Declination::new(Sgn::Neg, 59, 41, 20.),
         distance: Length::new::<light_year>(352.),
-        lifetime: Time::new::<gigayear>(0.012799766),
+        motion: None,
+        variability: None,
+        spectral_type: "B0.5III",
     }
 }
 
@@ -58,7 +62,9 @@ fn gacrux() -> RealData {
         right_ascension: RightAscension::new(12, 31, 10.),
         declination: Declination::new(Sgn::Neg, 57, 6, 48.),
         distance: Length::new::<light_year>(88.),
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: None,
+        variability: None,
+        spectral_type: "M3.5III",
     }
 }
 
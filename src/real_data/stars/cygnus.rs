@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn deneb() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn deneb() -> RealData {
         declination: Declination::new(Sgn::Pos, 45, 16, 49.),
         distance: Length::new::<light_year>(1548.),
         age: Some(Time::new::<gigayear>(0.011)),
-        lifetime: Time::new::<gigayear>(0.011037517),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(2.01),
+            angular_velocity_from_mas_per_year(1.85),
+            Velocity::new::<kilometer_per_second>(-4.5),
+        )),
+        variability: None,
+        spectral_type: "A2Ia",
     }
 }
 
@@ -37,10 +47,12 @@ fn sadir() -> RealData {
         apparent_magnitude: 2.23,
         temperature: ThermodynamicTemperature::new::<kelvin>(5790.),
         age: Some(Time::new::<gigayear>(0.012)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(20, 22, 14.),
         declination: Declination::new(Sgn::Pos, 40, 15, 24.),
         distance: Length::new::<light_year>(1522.),
+        spectral_type: "F8Ib",
     }
 }
 
@@ -55,10 +67,12 @@ fn aljanah() -> RealData {
         apparent_magnitude: 2.48,
         temperature: ThermodynamicTemperature::new::<kelvin>(4710.),
         age: Some(Time::new::<gigayear>(1.3)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(20, 46, 13.),
         declination: Declination::new(Sgn::Pos, 33, 58, 13.),
         distance: Length::new::<light_year>(72.),
+        spectral_type: "K0III",
     }
 }
 
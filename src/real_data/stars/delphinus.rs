@@ -22,7 +22,9 @@ fn sualocin() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.92)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_643.),
         age: Some(Time::new::<gigayear>(0.22)),
-        lifetime: Time::new::<gigayear>(0.220601963),
+        motion: None,
+        variability: None,
+        spectral_type: "B9IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn rotanev() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(6587.),
         age: Some(Time::new::<gigayear>(1.5)),
-        lifetime: Time::new::<gigayear>(1.59501327),
+        motion: None,
+        variability: None,
+        spectral_type: "F5IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_delphini() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6295.),
         age: Some(Time::new::<gigayear>(1.85)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K1IV",
     }
 }
 
@@ -76,7 +82,9 @@ fn delta_delphini() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.43)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7440.),
         age: Some(Time::new::<gigayear>(0.945)),
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
+        spectral_type: "A7III",
     }
 }
 
@@ -94,7 +102,9 @@ fn aldulfin() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_614.),
         age: Some(Time::new::<gigayear>(0.06)),
-        lifetime: Time::new::<gigayear>(0.063411557),
+        motion: None,
+        variability: None,
+        spectral_type: "B6III",
     }
 }
 
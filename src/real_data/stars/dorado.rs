@@ -22,7 +22,9 @@ fn alpha_doradus() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_588.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "A0IIIp",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_doradus() -> RealData {
         radius: Some(Length::new::<solar_radius>(67.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5445.),
         age: Some(Time::new::<gigayear>(0.04)),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "F4Ia-II",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_doradus() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.85)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6906.),
         age: Some(Time::new::<gigayear>(0.535)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "F1V",
     }
 }
 
@@ -73,10 +79,12 @@ fn r_doradus() -> RealData {
         apparent_magnitude: 5.59,
         temperature: ThermodynamicTemperature::new::<kelvin>(2710.),
         age: Some(Time::new::<gigayear>(4.)),
-        lifetime: Time::new::<gigayear>(21.4199307),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(4, 36, 46.),
         declination: Declination::new(Sgn::Neg, 62, 4, 38.),
         distance: Length::new::<light_year>(203.5),
+        spectral_type: "M8IIIe",
     }
 }
 
@@ -19,10 +19,12 @@ fn eltanin() -> RealData {
         apparent_magnitude: 2.24,
         temperature: ThermodynamicTemperature::new::<kelvin>(3930.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(17, 56, 36.),
         declination: Declination::new(Sgn::Pos, 51, 29, 20.),
         distance: Length::new::<light_year>(148.),
+        spectral_type: "K5III",
     }
 }
 
@@ -37,10 +39,12 @@ fn athebyne() -> RealData {
         apparent_magnitude: 2.73,
         temperature: ThermodynamicTemperature::new::<kelvin>(5055.),
         age: Some(Time::new::<gigayear>(0.55)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(16, 23, 59.),
         declination: Declination::new(Sgn::Pos, 61, 30, 51.),
         distance: Length::new::<light_year>(87.68),
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn theta_draconis() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.83)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6105.),
         age: Some(Time::new::<gigayear>(2.03)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "F8IV",
     }
 }
 
@@ -76,7 +82,9 @@ fn kappa_draconis() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.85)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_982.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "B6IIIpe",
     }
 }
 
@@ -94,7 +102,9 @@ fn tyl() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.15)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4993.),
         age: Some(Time::new::<gigayear>(0.5)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "G7III",
     }
 }
 
@@ -112,7 +122,9 @@ fn giausar() -> RealData {
         radius: Some(Length::new::<solar_radius>(71.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3958.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
@@ -130,7 +142,9 @@ fn grumium() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4445.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.82957282),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -148,7 +162,9 @@ fn thuban() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.932)),
         temperature: ThermodynamicTemperature::new::<kelvin>(10_225.),
         age: Some(Time::new::<gigayear>(0.280)),
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "A0III",
     }
 }
 
@@ -166,7 +182,9 @@ fn chi_draconis() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6150.),
         age: Some(Time::new::<gigayear>(5.3)),
-        lifetime: Time::new::<gigayear>(8.24015833),
+        motion: None,
+        variability: None,
+        spectral_type: "F7V",
     }
 }
 
@@ -184,7 +202,9 @@ fn edasich() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.99)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4504.),
         age: Some(Time::new::<gigayear>(2.2)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -202,7 +222,9 @@ fn aldhibah() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_397.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "B6III",
     }
 }
 
@@ -220,7 +242,9 @@ fn altais() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4820.),
         age: Some(Time::new::<gigayear>(0.8)),
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -238,7 +262,9 @@ fn rastaban() -> RealData {
         radius: Some(Length::new::<solar_radius>(40.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5160.),
         age: Some(Time::new::<gigayear>(0.062)),
-        lifetime: Time::new::<gigayear>(0.073299383),
+        motion: None,
+        variability: None,
+        spectral_type: "G2Ib-IIa",
     }
 }
 
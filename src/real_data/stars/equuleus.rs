@@ -22,7 +22,9 @@ fn kitalpha() -> RealData {
         radius: Some(Length::new::<solar_radius>(9.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5100.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "G2II-III",
     }
 }
 
@@ -41,7 +43,9 @@ fn delta_equulei() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.30)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6200.),
         age: Some(Time::new::<gigayear>(3.)),
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "F8V",
     }
 }
 
@@ -59,7 +63,9 @@ fn gamma_equulei() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.11)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7550.),
         age: Some(Time::new::<gigayear>(1.)),
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
+        spectral_type: "A9Vp",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn achernar() -> RealData {
     RealData {
@@ -19,10 +23,16 @@ fn achernar() -> RealData {
         apparent_magnitude: 0.45,
         temperature: ThermodynamicTemperature::new::<kelvin>(14_000.),
         age: Some(Time::new::<gigayear>(0.063)),
-        lifetime: Time::new::<gigayear>(0.073299383),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(87.),
+            angular_velocity_from_mas_per_year(-38.24),
+            Velocity::new::<kilometer_per_second>(16.),
+        )),
+        variability: None,
         right_ascension: RightAscension::new(1, 37, 43.),
         declination: Declination::new(Sgn::Neg, 57, 14, 12.),
         distance: Length::new::<light_year>(144.),
+        spectral_type: "B6Vep",
     }
 }
 
@@ -40,7 +50,9 @@ fn zaurak() -> RealData {
         declination: Declination::new(Sgn::Neg, 13, 30, 31.),
         distance: Length::new::<light_year>(221.),
         age: None,
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "M1IIIb",
     }
 }
 
@@ -58,7 +70,9 @@ fn cursa() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8360.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "A3III",
     }
 }
 
@@ -76,7 +90,9 @@ fn acamar() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.85)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8200.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A4III",
     }
 }
 
@@ -22,7 +22,9 @@ fn dalim() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.04)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6240.),
         age: Some(Time::new::<gigayear>(2.9)),
-        lifetime: Time::new::<gigayear>(3.46068223),
+        motion: None,
+        variability: None,
+        spectral_type: "F8IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_fornacis() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.02)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4820.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn nu_fornacis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.44)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_400.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "B9.5IIIpSi",
     }
 }
 
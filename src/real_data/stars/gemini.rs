@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn pollux() -> RealData {
     RealData {
@@ -19,10 +23,16 @@ fn pollux() -> RealData {
         apparent_magnitude: 1.16,
         temperature: ThermodynamicTemperature::new::<kelvin>(4586.),
         age: Some(Time::new::<gigayear>(0.724)),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-626.55),
+            angular_velocity_from_mas_per_year(-45.8),
+            Velocity::new::<kilometer_per_second>(3.23),
+        )),
+        variability: None,
         right_ascension: RightAscension::new(7, 45, 19.),
         declination: Declination::new(Sgn::Pos, 28, 1, 34.),
         distance: Length::new::<light_year>(34.),
+        spectral_type: "K0III",
     }
 }
 
@@ -37,10 +47,12 @@ fn castor() -> RealData {
         apparent_magnitude: 1.58,
         temperature: ThermodynamicTemperature::new::<kelvin>(10_286.),
         age: Some(Time::new::<gigayear>(0.290)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(7, 34, 36.),
         declination: Declination::new(Sgn::Pos, 31, 53, 18.),
         distance: Length::new::<light_year>(52.),
+        spectral_type: "A1V",
     }
 }
 
@@ -55,10 +67,12 @@ fn alhena() -> RealData {
         apparent_magnitude: 1.93,
         temperature: ThermodynamicTemperature::new::<kelvin>(9260.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.513076303),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(6, 37, 43.),
         declination: Declination::new(Sgn::Pos, 16, 23, 57.),
         distance: Length::new::<light_year>(105.),
+        spectral_type: "A1IV",
     }
 }
 
@@ -73,10 +87,12 @@ fn tejat() -> RealData {
         apparent_magnitude: 2.75,
         temperature: ThermodynamicTemperature::new::<kelvin>(3460.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.17901142),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(6, 22, 58.),
         declination: Declination::new(Sgn::Pos, 22, 30, 49.),
         distance: Length::new::<light_year>(230.),
+        spectral_type: "M3III",
     }
 }
 
@@ -91,10 +107,12 @@ fn propus() -> RealData {
         apparent_magnitude: 3.31,
         temperature: ThermodynamicTemperature::new::<kelvin>(3502.),
         age: Some(Time::new::<gigayear>(0.8)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(6, 14, 53.),
         declination: Declination::new(Sgn::Pos, 22, 30, 24.),
         distance: Length::new::<light_year>(349.),
+        spectral_type: "M3III",
     }
 }
 
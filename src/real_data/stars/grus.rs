@@ -19,10 +19,12 @@ fn alnair() -> RealData {
         apparent_magnitude: 1.73,
         temperature: ThermodynamicTemperature::new::<kelvin>(13_920.),
         age: Some(Time::new::<gigayear>(0.1)),
-        lifetime: Time::new::<gigayear>(0.193156929),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(22, 8, 14.),
         declination: Declination::new(Sgn::Neg, 46, 57, 40.),
         distance: Length::new::<light_year>(101.),
+        spectral_type: "B7V",
     }
 }
 
@@ -37,10 +39,12 @@ fn tiaki() -> RealData {
         apparent_magnitude: 2.07,
         temperature: ThermodynamicTemperature::new::<kelvin>(3480.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(22, 42, 40.),
         declination: Declination::new(Sgn::Neg, 46, 53, 4.),
         distance: Length::new::<light_year>(170.),
+        spectral_type: "M5III",
     }
 }
 
@@ -58,7 +62,9 @@ fn aldhanab() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(12_520.),
         age: Some(Time::new::<gigayear>(0.075)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "B8III",
     }
 }
 
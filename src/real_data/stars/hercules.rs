@@ -22,7 +22,9 @@ fn rasalgethi() -> RealData {
         radius: Some(Length::new::<solar_radius>(284.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3155.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "M5Ib-II",
     }
 }
 
@@ -40,7 +42,9 @@ fn kornephoros() -> RealData {
         radius: Some(Length::new::<solar_radius>(17.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4887.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.513076303),
+        motion: None,
+        variability: None,
+        spectral_type: "G7IIIa",
     }
 }
 
@@ -58,7 +62,9 @@ fn sarin() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9620.),
         age: Some(Time::new::<gigayear>(0.370)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A3IV",
     }
 }
 
@@ -76,7 +82,9 @@ fn eta_herculis() -> RealData {
         radius: Some(Length::new::<solar_radius>(8.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4900.),
         age: Some(Time::new::<gigayear>(1.)),
-        lifetime: Time::new::<gigayear>(1.09929685),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -94,7 +102,9 @@ fn mu_herculis() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.73)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5560.),
         age: Some(Time::new::<gigayear>(6.8)),
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "G5IV",
     }
 }
 
@@ -112,7 +122,9 @@ fn zeta_herculis() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.56)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5820.),
         age: Some(Time::new::<gigayear>(2.7)),
-        lifetime: Time::new::<gigayear>(2.82957282),
+        motion: None,
+        variability: None,
+        spectral_type: "F9IV",
     }
 }
 
@@ -130,7 +142,9 @@ fn pi_herculis() -> RealData {
         radius: Some(Length::new::<solar_radius>(72.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4170.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.193156929),
+        motion: None,
+        variability: None,
+        spectral_type: "K3II",
     }
 }
 
@@ -1,7 +1,7 @@
 use astro_coords::ra_and_dec::*;
-use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
+use astro_units::{length::solar_radius, mass::solar_mass};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature},
     length::light_year,
     thermodynamic_temperature::kelvin,
 };
@@ -22,7 +22,9 @@ fn alpha_horologii() -> RealData {
         radius: Some(Length::new::<solar_radius>(8.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5028.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -40,7 +42,9 @@ fn r_horologii() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(2200.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "M7IIIe",
     }
 }
 
@@ -58,7 +62,9 @@ fn beta_horologii() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8303.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "A5III",
     }
 }
 
@@ -19,10 +19,12 @@ fn alphard() -> RealData {
         apparent_magnitude: 1.99,
         temperature: ThermodynamicTemperature::new::<kelvin>(4120.),
         age: Some(Time::new::<gigayear>(0.42)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(9, 27, 35.),
         declination: Declination::new(Sgn::Neg, 8, 39, 30.),
         distance: Length::new::<light_year>(177.),
+        spectral_type: "K3II-III",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma_hydrae() -> RealData {
         radius: Some(Length::new::<solar_radius>(16.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5087.),
         age: Some(Time::new::<gigayear>(0.372)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn zeta_hydrae() -> RealData {
         radius: Some(Length::new::<solar_radius>(17.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4925.),
         age: Some(Time::new::<gigayear>(0.17)),
-        lifetime: Time::new::<gigayear>(0.170765802),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -76,7 +82,9 @@ fn nu_hydrae() -> RealData {
         radius: Some(Length::new::<solar_radius>(21.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4335.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_hydri() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.040)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7087.),
         age: Some(Time::new::<gigayear>(0.810)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "F0IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_hydri() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.809)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5872.),
         age: Some(Time::new::<gigayear>(6.4)),
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "G2IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_hydri() -> RealData {
         declination: Declination::new(Sgn::Neg, 74, 14, 20.),
         distance: Length::new::<light_year>(214.),
         age: None,
-        lifetime: Time::new::<gigayear>(9.81519157),
+        motion: None,
+        variability: None,
+        spectral_type: "M2III",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_indi() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4893.),
         age: Some(Time::new::<gigayear>(1.)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_indi() -> RealData {
         radius: Some(Length::new::<solar_radius>(55.58)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4541.),
         age: Some(Time::new::<gigayear>(0.0532)),
-        lifetime: Time::new::<gigayear>(0.063411557),
+        motion: None,
+        variability: None,
+        spectral_type: "K1II",
     }
 }
 
@@ -58,7 +62,9 @@ fn eta_indi() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.27)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7694.),
         age: Some(Time::new::<gigayear>(0.1)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "A9IV",
     }
 }
 
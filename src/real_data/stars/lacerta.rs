@@ -22,7 +22,9 @@ fn alpha_lacertae() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.1432)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9050.),
         age: Some(Time::new::<gigayear>(0.4)),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_lacertae() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.96)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4803.),
         age: Some(Time::new::<gigayear>(6.76)),
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -58,7 +62,9 @@ fn five_lacertae() -> RealData {
         radius: Some(Length::new::<solar_radius>(319.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3713.),
         age: Some(Time::new::<gigayear>(0.1)),
-        lifetime: Time::new::<gigayear>(0.10143918),
+        motion: None,
+        variability: None,
+        spectral_type: "M0II",
     }
 }
 
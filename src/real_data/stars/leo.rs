@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn regulus() -> RealData {
     RealData {
@@ -19,10 +23,16 @@ fn regulus() -> RealData {
         apparent_magnitude: 1.36,
         temperature: ThermodynamicTemperature::new::<kelvin>(11_668.),
         age: Some(Time::new::<gigayear>(0.100)),
-        lifetime: Time::new::<gigayear>(0.220601963),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-248.73),
+            angular_velocity_from_mas_per_year(5.59),
+            Velocity::new::<kilometer_per_second>(5.9),
+        )),
+        variability: None,
         right_ascension: RightAscension::new(10, 8, 22.),
         declination: Declination::new(Sgn::Pos, 11, 58, 2.),
         distance: Length::new::<light_year>(77.),
+        spectral_type: "B8IVn",
     }
 }
 
@@ -40,7 +50,9 @@ fn algieba() -> RealData {
         declination: Declination::new(Sgn::Pos, 19, 50, 29.),
         distance: Length::new::<light_year>(126.),
         age: None,
-        lifetime: Time::new::<gigayear>(4.45521207),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -55,10 +67,12 @@ fn denebola() -> RealData {
         apparent_magnitude: 2.14,
         temperature: ThermodynamicTemperature::new::<kelvin>(8500.),
         age: Some(Time::new::<gigayear>(0.25)),
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(11, 49, 3.),
         declination: Declination::new(Sgn::Pos, 14, 34, 19.),
         distance: Length::new::<light_year>(36.),
+        spectral_type: "A3V",
     }
 }
 
@@ -76,7 +90,9 @@ fn zosma() -> RealData {
         right_ascension: RightAscension::new(11, 14, 7.),
         declination: Declination::new(Sgn::Pos, 20, 31, 25.),
         distance: Length::new::<light_year>(58.),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "A4V",
     }
 }
 
@@ -22,7 +22,9 @@ fn praecipua() -> RealData {
         radius: Some(Length::new::<solar_radius>(8.22)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4670.),
         age: Some(Time::new::<gigayear>(1.7)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_leonis_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(9.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4097.),
         age: Some(Time::new::<gigayear>(0.4)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -58,7 +62,9 @@ fn twentyfour_leonis_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.75)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7839.),
         age: Some(Time::new::<gigayear>(0.390)),
-        lifetime: Time::new::<gigayear>(1.59501327),
+        motion: None,
+        variability: None,
+        spectral_type: "A7V",
     }
 }
 
@@ -19,10 +19,12 @@ fn arneb() -> RealData {
         apparent_magnitude: 2.58,
         temperature: ThermodynamicTemperature::new::<kelvin>(6_850.),
         age: Some(Time::new::<gigayear>(0.013)),
-        lifetime: Time::new::<gigayear>(0.015362858),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(5, 32, 44.),
         declination: Declination::new(Sgn::Neg, 17, 49, 20.),
         distance: Length::new::<light_year>(1283.),
+        spectral_type: "F0Ib",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_leporis() -> RealData {
         radius: Some(Length::new::<solar_radius>(16.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5450.),
         age: Some(Time::new::<gigayear>(0.240)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "G5II",
     }
 }
 
@@ -58,7 +62,9 @@ fn epsilon_leporis() -> RealData {
         radius: Some(Length::new::<solar_radius>(40.1)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4131.),
         age: Some(Time::new::<gigayear>(1.72)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "K4III",
     }
 }
 
@@ -22,7 +22,9 @@ fn zubenelgenubi() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(8128.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.46316038),
+        motion: None,
+        variability: None,
+        spectral_type: "A3IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn zubeneschamali() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(12_300.),
         age: Some(Time::new::<gigayear>(0.08)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "B8V",
     }
 }
 
@@ -55,10 +59,12 @@ fn brachium() -> RealData {
         apparent_magnitude: 3.21,
         temperature: ThermodynamicTemperature::new::<kelvin>(3596.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(15, 4, 4.),
         declination: Declination::new(Sgn::Neg, 25, 16, 55.),
         distance: Length::new::<light_year>(288.),
+        spectral_type: "M3.5III",
     }
 }
 
@@ -19,10 +19,12 @@ fn alpha_lupi() -> RealData {
         apparent_magnitude: 2.30,
         temperature: ThermodynamicTemperature::new::<kelvin>(21_820.),
         age: Some(Time::new::<gigayear>(0.018)),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(14, 41, 56.),
         declination: Declination::new(Sgn::Neg, 47, 23, 18.),
         distance: Length::new::<light_year>(548.),
+        spectral_type: "B1.5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_lupi() -> RealData {
         radius: Some(Length::new::<solar_radius>(6.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(24_090.),
         age: Some(Time::new::<gigayear>(0.0246)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_lupi() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(20_900.),
         age: Some(Time::new::<gigayear>(0.0186)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IV",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_lyncis() -> RealData {
         declination: Declination::new(Sgn::Pos, 34, 23, 33.),
         distance: Length::new::<light_year>(221.9),
         age: Some(Time::new::<gigayear>(1.3)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "K7III",
     }
 }
 
@@ -40,7 +42,9 @@ fn thirtyeight_lyncis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.07)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8862.),
         age: Some(Time::new::<gigayear>(0.213)),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "A2IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn thirtyone_lyncis() -> RealData {
         radius: Some(Length::new::<solar_radius>(53.27)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3921.),
         age: Some(Time::new::<gigayear>(1.32)),
-        lifetime: Time::new::<gigayear>(1.46316038),
+        motion: None,
+        variability: None,
+        spectral_type: "K5III",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn vega() -> RealData {
     RealData {
@@ -19,10 +23,16 @@ fn vega() -> RealData {
         apparent_magnitude: 0.03,
         temperature: ThermodynamicTemperature::new::<kelvin>(9602.),
         age: Some(Time::new::<gigayear>(0.455)),
-        lifetime: Time::new::<gigayear>(1.09929685),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(200.94),
+            angular_velocity_from_mas_per_year(286.23),
+            Velocity::new::<kilometer_per_second>(-13.9),
+        )),
+        variability: None,
         right_ascension: RightAscension::new(18, 36, 56.),
         declination: Declination::new(Sgn::Pos, 38, 47, 1.),
         distance: Length::new::<light_year>(25.),
+        spectral_type: "A0V",
     }
 }
 
@@ -37,10 +47,12 @@ fn r_lyrae() -> RealData {
         apparent_magnitude: 4.08,
         temperature: ThermodynamicTemperature::new::<kelvin>(3313.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.46605285),
+        motion: None,
+        variability: None,
         right_ascension: RightAscension::new(18, 55, 20.),
         declination: Declination::new(Sgn::Pos, 43, 56, 46.),
         distance: Length::new::<light_year>(349.4),
+        spectral_type: "M5III",
     }
 }
 
@@ -59,7 +71,9 @@ fn gamma_lyrae() -> RealData {
         radius: Some(Length::new::<solar_radius>(15.40)),
         temperature: ThermodynamicTemperature::new::<kelvin>(10_000.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.078916095),
+        motion: None,
+        variability: None,
+        spectral_type: "B9III",
     }
 }
 
@@ -77,7 +91,9 @@ fn beta_lyrae() -> RealData {
         radius: Some(Length::new::<solar_radius>(15.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_300.),
         age: Some(Time::new::<gigayear>(0.023)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "B8II",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_mensae() -> RealData {
         radius: Some(Length::new::<solar_radius>(0.960)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5569.),
         age: Some(Time::new::<gigayear>(6.2)),
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
+        spectral_type: "G7V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_mensae() -> RealData {
         radius: Some(Length::new::<solar_radius>(25.85)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5088.),
         age: Some(Time::new::<gigayear>(0.250)),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_mensae() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.99)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4491.),
         age: Some(Time::new::<gigayear>(8.)),
-        lifetime: Time::new::<gigayear>(8.24015833),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
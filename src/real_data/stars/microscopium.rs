@@ -22,7 +22,9 @@ fn gamma_microscopii() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5050.),
         age: Some(Time::new::<gigayear>(0.620)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "G7III",
     }
 }
 
@@ -40,7 +42,9 @@ fn epsilon_microscopii() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9126.),
         age: Some(Time::new::<gigayear>(0.525)),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -58,7 +62,9 @@ fn theta1_microscopii() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.35)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9240.),
         age: Some(Time::new::<gigayear>(0.437)),
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "",
     }
 }
 
@@ -42,7 +42,9 @@ pub fn sun() -> RealData {
         right_ascension: RightAscension::new(0, 0, 0.),
         declination: Declination::new(Sgn::Pos, 0, 0, 0.),
         distance: Length::new::<light_year>(0.),
-        lifetime: Time::new::<gigayear>(10.0),
+        motion: None,
+        variability: None,
+        spectral_type: "G2V",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_monocerotis() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.1)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4879.),
         age: Some(Time::new::<gigayear>(1.18)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma_monocerotis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4375.),
         age: None,
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "K1.5III",
     }
 }
 
@@ -58,7 +62,9 @@ fn delta_monocerotis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(9462.),
         age: Some(Time::new::<gigayear>(0.405)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A0V",
     }
 }
 
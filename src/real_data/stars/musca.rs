@@ -22,7 +22,9 @@ fn alpha_muscae() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(21_400.),
         age: Some(Time::new::<gigayear>(0.0183)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IV-V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_muscae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(21_000.),
         age: Some(Time::new::<gigayear>(0.0151)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "B2V",
     }
 }
 
@@ -58,7 +62,9 @@ fn delta_muscae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4_400.),
         age: None,
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
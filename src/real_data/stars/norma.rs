@@ -22,7 +22,9 @@ fn gamma2_normae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4699.),
         age: None,
-        lifetime: Time::new::<gigayear>(1.09929685),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -40,7 +42,9 @@ fn epsilon_normae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(10_888.),
         age: Some(Time::new::<gigayear>(0.0501)),
-        lifetime: Time::new::<gigayear>(0.063411557),
+        motion: None,
+        variability: None,
+        spectral_type: "B4V",
     }
 }
 
@@ -58,7 +62,9 @@ fn iota1_normae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(7842.),
         age: Some(Time::new::<gigayear>(0.731)),
-        lifetime: Time::new::<gigayear>(1.46316038),
+        motion: None,
+        variability: None,
+        spectral_type: "A7V",
     }
 }
 
@@ -22,7 +22,9 @@ fn nu_octantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4860.),
         age: Some(Time::new::<gigayear>(2.5)),
-        lifetime: Time::new::<gigayear>(8.24015833),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_octantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8006.),
         age: Some(Time::new::<gigayear>(0.496)),
-        lifetime: Time::new::<gigayear>(0.964406929),
+        motion: None,
+        variability: None,
+        spectral_type: "A9IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn delta_octantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(24.61)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4311.),
         age: None,
-        lifetime: Time::new::<gigayear>(8.24015833),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
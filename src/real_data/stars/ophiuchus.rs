@@ -22,7 +22,9 @@ fn rasalhague() -> RealData {
         right_ascension: RightAscension::new(17, 34, 56.),
         declination: Declination::new(Sgn::Pos, 12, 33, 37.),
         distance: Length::new::<light_year>(47.),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn sabik() -> RealData {
         right_ascension: RightAscension::new(17, 10, 23.),
         declination: Declination::new(Sgn::Neg, 15, 43, 30.),
         distance: Length::new::<light_year>(84.),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "A2V",
     }
 }
 
@@ -58,7 +62,9 @@ fn han() -> RealData {
         declination: Declination::new(Sgn::Neg, 10, 34, 2.),
         distance: Length::new::<light_year>(458.),
         age: Some(Time::new::<gigayear>(0.003)),
-        lifetime: Time::new::<gigayear>(0.009767659),
+        motion: None,
+        variability: None,
+        spectral_type: "O9.5V",
     }
 }
 
@@ -76,7 +82,9 @@ fn yed_prior() -> RealData {
         right_ascension: RightAscension::new(16, 14, 21.),
         declination: Declination::new(Sgn::Neg, 3, 41, 40.),
         distance: Length::new::<light_year>(171.),
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: None,
+        variability: None,
+        spectral_type: "M0.5III",
     }
 }
 
@@ -94,7 +102,9 @@ fn cebalrai() -> RealData {
         declination: Declination::new(Sgn::Pos, 4, 34, 2.),
         distance: Length::new::<light_year>(81.8),
         age: Some(Time::new::<gigayear>(3.82)),
-        lifetime: Time::new::<gigayear>(5.9461393),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
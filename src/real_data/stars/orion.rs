@@ -1,13 +1,22 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
-    time::year,
+    time::{day, year},
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{
+        isochrone_fit::parsec_lifetime,
+        motion::StarMotion,
+        real_data::RealData,
+        variability::{LightCurveShape, Variability, VariableKind},
+    },
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn rigel() -> RealData {
     RealData {
@@ -23,26 +32,46 @@ fn rigel() -> RealData {
         right_ascension: RightAscension::new(5, 14, 32.),
         declination: Declination::new(Sgn::Neg, 8, 12, 6.),
         distance: Length::new::<light_year>(773.),
-        lifetime: Time::new::<gigayear>(0.009767659),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(1.31),
+            angular_velocity_from_mas_per_year(0.5),
+            Velocity::new::<kilometer_per_second>(17.8),
+        )),
+        variability: None,
+        spectral_type: "B8Ia",
     }
 }
 
+// Betelgeuse is about to explode, so it is given the lifetime of its PARSEC track, less a century.
 fn betelgeuse() -> RealData {
-    let lifetime = Time::new::<gigayear>(0.012799766);
+    let mass = Mass::new::<solar_mass>(16.5);
+    let age = parsec_lifetime(mass, None) - Time::new::<year>(100.);
     RealData {
         common_name: "Betelgeuse",
         astronomical_name: "α Orionis",
         constellation: "Orion",
         radius: Some(Length::new::<solar_radius>(887.)),
-        mass: Mass::new::<solar_mass>(16.5),
+        mass,
         absolute_magnitude: -5.14,
         apparent_magnitude: 0.9,
         temperature: ThermodynamicTemperature::new::<kelvin>(3600.),
         right_ascension: RightAscension::new(5, 55, 10.),
         declination: Declination::new(Sgn::Pos, 7, 24, 25.),
         distance: Length::new::<light_year>(522.),
-        age: Some(lifetime - Time::new::<year>(100.)),
-        lifetime,
+        age: Some(age),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(27.54),
+            angular_velocity_from_mas_per_year(11.3),
+            Velocity::new::<kilometer_per_second>(21.91),
+        )),
+        variability: Some(Variability::new(
+            VariableKind::SemiRegular,
+            Time::new::<day>(400.),
+            1.,
+            0.,
+            LightCurveShape::Sinusoidal,
+        )),
+        spectral_type: "M1-2Ia-ab",
     }
 }
 
@@ -60,7 +89,9 @@ fn bellatrix() -> RealData {
         right_ascension: RightAscension::new(5, 25, 8.),
         declination: Declination::new(Sgn::Pos, 6, 20, 59.),
         distance: Length::new::<light_year>(243.),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "B2III",
     }
 }
 
@@ -78,7 +109,9 @@ fn alnilam() -> RealData {
         declination: Declination::new(Sgn::Neg, 1, 12, 7.),
         distance: Length::new::<light_year>(1342.),
         age: Some(Time::new::<gigayear>(0.0057)),
-        lifetime: Time::new::<gigayear>(0.005807621),
+        motion: None,
+        variability: None,
+        spectral_type: "B0Ia",
     }
 }
 
@@ -96,7 +129,9 @@ fn alnitak() -> RealData {
         declination: Declination::new(Sgn::Neg, 1, 56, 34.),
         distance: Length::new::<light_year>(817.),
         age: Some(Time::new::<gigayear>(0.0064)),
-        lifetime: Time::new::<gigayear>(0.006573099),
+        motion: None,
+        variability: None,
+        spectral_type: "O9.5Ib",
     }
 }
 
@@ -114,7 +149,9 @@ fn saiph() -> RealData {
         right_ascension: RightAscension::new(5, 47, 45.),
         declination: Declination::new(Sgn::Neg, 9, 40, 11.),
         distance: Length::new::<light_year>(721.2),
-        lifetime: Time::new::<gigayear>(0.012799766),
+        motion: None,
+        variability: None,
+        spectral_type: "B0.5Ia",
     }
 }
 
@@ -132,7 +169,9 @@ fn mintaka() -> RealData {
         declination: Declination::new(Sgn::Neg, 0, 17, 57.),
         distance: Length::new::<light_year>(916.),
         age: Some(Time::new::<gigayear>(0.008)),
-        lifetime: Time::new::<gigayear>(0.008063854),
+        motion: None,
+        variability: None,
+        spectral_type: "O9.5II",
     }
 }
 
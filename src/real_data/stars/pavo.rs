@@ -22,7 +22,9 @@ fn peacock() -> RealData {
         right_ascension: RightAscension::new(20, 25, 39.),
         declination: Declination::new(Sgn::Neg, 56, 44, 6.),
         distance: Length::new::<light_year>(183.),
-        lifetime: Time::new::<gigayear>(0.073299383),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_pavonis() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8184.),
         age: Some(Time::new::<gigayear>(0.305)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A7III",
     }
 }
 
@@ -58,7 +62,9 @@ fn delta_pavonis() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.197)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5571.),
         age: Some(Time::new::<gigayear>(6.7)),
-        lifetime: Time::new::<gigayear>(8.24015833),
+        motion: None,
+        variability: None,
+        spectral_type: "G8IV",
     }
 }
 
@@ -22,7 +22,9 @@ fn enif() -> RealData {
         right_ascension: RightAscension::new(21, 44, 11.),
         declination: Declination::new(Sgn::Pos, 9, 52, 30.),
         distance: Length::new::<light_year>(672.),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "K2Ib",
     }
 }
 
@@ -40,7 +42,9 @@ fn scheat() -> RealData {
         right_ascension: RightAscension::new(23, 3, 46.),
         declination: Declination::new(Sgn::Pos, 28, 4, 58.),
         distance: Length::new::<light_year>(199.),
-        lifetime: Time::new::<gigayear>(1.17901142),
+        motion: None,
+        variability: None,
+        spectral_type: "M2.5II-III",
     }
 }
 
@@ -58,7 +62,9 @@ fn markab() -> RealData {
         right_ascension: RightAscension::new(23, 4, 46.),
         declination: Declination::new(Sgn::Pos, 15, 12, 19.),
         distance: Length::new::<light_year>(140.),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "B9III",
     }
 }
 
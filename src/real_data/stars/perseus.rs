@@ -4,9 +4,13 @@ use uom::si::{
     f64::{Length, Mass, ThermodynamicTemperature, Time},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    time::day,
 };
 
-use crate::stars::real_data::RealData;
+use crate::stars::{
+    real_data::RealData,
+    variability::{LightCurveShape, Variability, VariableKind},
+};
 
 fn mirphak() -> RealData {
     RealData {
@@ -22,7 +26,9 @@ fn mirphak() -> RealData {
         declination: Declination::new(Sgn::Pos, 49, 51, 40.),
         distance: Length::new::<light_year>(592.),
         age: Some(Time::new::<gigayear>(0.040)),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "F5Ib",
     }
 }
 
@@ -40,7 +46,18 @@ fn algol() -> RealData {
         declination: Declination::new(Sgn::Pos, 40, 57, 20.),
         distance: Length::new::<light_year>(93.),
         age: Some(Time::new::<gigayear>(0.3)),
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: Some(Variability::new(
+            VariableKind::EclipsingBinary,
+            Time::new::<day>(2.867_328),
+            1.3,
+            0.88,
+            LightCurveShape::Eclipse {
+                width: 0.145,
+                secondary_depth: 0.05,
+            },
+        )),
+        spectral_type: "B8V",
     }
 }
 
@@ -58,7 +75,9 @@ fn gorgonea_tertia() -> RealData {
         right_ascension: RightAscension::new(3, 5, 11.),
         declination: Declination::new(Sgn::Pos, 38, 50, 25.),
         distance: Length::new::<light_year>(325.),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "M4II",
     }
 }
 
@@ -1,7 +1,7 @@
 use astro_coords::ra_and_dec::*;
-use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
+use astro_units::{length::solar_radius, mass::solar_mass};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature},
     length::light_year,
     thermodynamic_temperature::kelvin,
 };
@@ -22,7 +22,9 @@ fn ankaa() -> RealData {
         right_ascension: RightAscension::new(0, 26, 17.),
         declination: Declination::new(Sgn::Neg, 42, 18, 21.),
         distance: Length::new::<light_year>(77.),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "K0.5IIIb",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_phoenicis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(5090.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_phoenicis() -> RealData {
         radius: Some(Length::new::<solar_radius>(52.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3802.),
         age: None,
-        lifetime: Time::new::<gigayear>(3.9126515),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
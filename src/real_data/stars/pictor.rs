@@ -22,7 +22,9 @@ fn alpha_pictoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7530.),
         age: Some(Time::new::<gigayear>(0.660)),
-        lifetime: Time::new::<gigayear>(1.25731981),
+        motion: None,
+        variability: None,
+        spectral_type: "A7IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_pictoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8052.),
         age: Some(Time::new::<gigayear>(0.023)),
-        lifetime: Time::new::<gigayear>(1.59501327),
+        motion: None,
+        variability: None,
+        spectral_type: "A6V",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_pictoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4600.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
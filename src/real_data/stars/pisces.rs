@@ -22,7 +22,9 @@ fn alpha_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.45)),
         temperature: ThermodynamicTemperature::new::<kelvin>(10_233.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "",
     }
 }
 
@@ -40,7 +42,9 @@ fn delta_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(44.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3963.),
         age: Some(Time::new::<gigayear>(0.00298)),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "K5III",
     }
 }
 
@@ -58,7 +62,9 @@ fn nu_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(34.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4154.),
         age: Some(Time::new::<gigayear>(1.8)),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -76,7 +82,9 @@ fn iota_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.595)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6288.),
         age: Some(Time::new::<gigayear>(3.8)),
-        lifetime: Time::new::<gigayear>(3.9126515),
+        motion: None,
+        variability: None,
+        spectral_type: "F7V",
     }
 }
 
@@ -94,7 +102,9 @@ fn omicron_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(14.57)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5004.),
         age: Some(Time::new::<gigayear>(0.390)),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -112,7 +122,9 @@ fn epsilon_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.9)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4814.),
         age: Some(Time::new::<gigayear>(0.9)),
-        lifetime: Time::new::<gigayear>(0.964406929),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -130,7 +142,9 @@ fn theta_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4684.),
         age: Some(Time::new::<gigayear>(0.00245)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -148,7 +162,9 @@ fn eta_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(26.48)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4937.),
         age: Some(Time::new::<gigayear>(0.220)),
-        lifetime: Time::new::<gigayear>(0.220601963),
+        motion: None,
+        variability: None,
+        spectral_type: "G7IIIa",
     }
 }
 
@@ -166,7 +182,9 @@ fn gamma_piscium() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.28)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4833.),
         age: Some(Time::new::<gigayear>(4.58)),
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
+        spectral_type: "G9III",
     }
 }
 
@@ -184,7 +202,9 @@ fn omega_piscium() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(6641.),
         age: Some(Time::new::<gigayear>(1.337)),
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "F4IV",
     }
 }
 
@@ -22,7 +22,9 @@ fn formalhaut() -> RealData {
         right_ascension: RightAscension::new(22, 57, 39.),
         declination: Declination::new(Sgn::Neg, 29, 37, 20.),
         distance: Length::new::<light_year>(25.),
-        lifetime: Time::new::<gigayear>(1.54706939),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
@@ -40,7 +42,9 @@ fn delta_piscis_austrini() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4828.),
         age: Some(Time::new::<gigayear>(3.1)),
-        lifetime: Time::new::<gigayear>(3.10253119),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -58,7 +62,9 @@ fn epsilon_piscis_austrini() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(11_066.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.193156929),
+        motion: None,
+        variability: None,
+        spectral_type: "B8V",
     }
 }
 
@@ -76,7 +82,9 @@ fn iota_piscis_austrini() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(9330.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "A0V",
     }
 }
 
@@ -22,7 +22,9 @@ fn naos() -> RealData {
         right_ascension: RightAscension::new(8, 3, 35.),
         declination: Declination::new(Sgn::Neg, 40, 0, 12.),
         distance: Length::new::<light_year>(1399.),
-        lifetime: Time::new::<gigayear>(0.00435),
+        motion: None,
+        variability: None,
+        spectral_type: "O4I",
     }
 }
 
@@ -40,7 +42,9 @@ fn ahadi() -> RealData {
         declination: Declination::new(Sgn::Neg, 37, 5, 51.),
         distance: Length::new::<light_year>(1094.),
         age: Some(Time::new::<gigayear>(0.019)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "K3Ib",
     }
 }
 
@@ -58,7 +62,9 @@ fn rho_puppis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.41)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6920.),
         age: Some(Time::new::<gigayear>(1.5)),
-        lifetime: Time::new::<gigayear>(1.65092742),
+        motion: None,
+        variability: None,
+        spectral_type: "F5II",
     }
 }
 
@@ -76,7 +82,9 @@ fn tau_puppis() -> RealData {
         radius: Some(Length::new::<solar_radius>(27.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4489.),
         age: Some(Time::new::<gigayear>(0.3)),
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -94,7 +102,9 @@ fn nu_puppis() -> RealData {
         radius: Some(Length::new::<solar_radius>(4.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(12_120.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.10143918),
+        motion: None,
+        variability: None,
+        spectral_type: "B8III",
     }
 }
 
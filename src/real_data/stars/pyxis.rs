@@ -22,7 +22,9 @@ fn alpha_pyxidis() -> RealData {
         radius: Some(Length::new::<solar_radius>(6.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(24_300.),
         age: Some(Time::new::<gigayear>(0.026)),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
+        spectral_type: "B1.5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_pyxidis() -> RealData {
         radius: Some(Length::new::<solar_radius>(24.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5124.),
         age: None,
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "G7Ib-II",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_pyxidis() -> RealData {
         radius: Some(Length::new::<solar_radius>(21.87)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4270.),
         age: Some(Time::new::<gigayear>(1.8)),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
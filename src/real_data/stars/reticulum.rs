@@ -22,7 +22,9 @@ fn alpha_reticuli() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5196.),
         age: Some(Time::new::<gigayear>(0.33)),
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "G8II-III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_reticuli() -> RealData {
         radius: Some(Length::new::<solar_radius>(9.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4580.),
         age: Some(Time::new::<gigayear>(5.)),
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -58,7 +62,9 @@ fn epsilon_reticuli() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.18)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4961.),
         age: Some(Time::new::<gigayear>(2.8)),
-        lifetime: Time::new::<gigayear>(2.82957282),
+        motion: None,
+        variability: None,
+        spectral_type: "K2IV",
     }
 }
 
@@ -22,7 +22,9 @@ fn gamma_sagittae() -> RealData {
         radius: Some(Length::new::<solar_radius>(55.13)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3862.),
         age: Some(Time::new::<gigayear>(2.35)),
-        lifetime: Time::new::<gigayear>(14.2493142),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
@@ -40,7 +42,9 @@ fn delta_sagittae() -> RealData {
         radius: Some(Length::new::<solar_radius>(108.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3660.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "M2II",
     }
 }
 
@@ -58,7 +62,9 @@ fn alpha_sagittae() -> RealData {
         radius: Some(Length::new::<solar_radius>(21.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5333.),
         age: Some(Time::new::<gigayear>(0.151)),
-        lifetime: Time::new::<gigayear>(0.170765802),
+        motion: None,
+        variability: None,
+        spectral_type: "G1II",
     }
 }
 
@@ -22,7 +22,9 @@ fn kaus_australis() -> RealData {
         right_ascension: RightAscension::new(18, 24, 10.),
         declination: Declination::new(Sgn::Neg, 34, 23, 5.),
         distance: Length::new::<light_year>(145.),
-        lifetime: Time::new::<gigayear>(0.254814649),
+        motion: None,
+        variability: None,
+        spectral_type: "B9.5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn nunki() -> RealData {
         right_ascension: RightAscension::new(18, 55, 16.),
         declination: Declination::new(Sgn::Neg, 26, 17, 49.),
         distance: Length::new::<light_year>(224.),
-        lifetime: Time::new::<gigayear>(0.040555762),
+        motion: None,
+        variability: None,
+        spectral_type: "B2.5V",
     }
 }
 
@@ -58,7 +62,9 @@ fn namalwarid() -> RealData {
         right_ascension: RightAscension::new(18, 17, 38.),
         declination: Declination::new(Sgn::Neg, 36, 45, 42.),
         distance: Length::new::<light_year>(149.1),
-        lifetime: Time::new::<gigayear>(5.06543331),
+        motion: None,
+        variability: None,
+        spectral_type: "M3.5IIIab",
     }
 }
 
@@ -76,7 +82,9 @@ fn kaus_media() -> RealData {
         right_ascension: RightAscension::new(18, 20, 60.),
         declination: Declination::new(Sgn::Neg, 29, 49, 41.),
         distance: Length::new::<light_year>(305.5),
-        lifetime: Time::new::<gigayear>(0.351318702),
+        motion: None,
+        variability: None,
+        spectral_type: "K3IIIa",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn antares() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn antares() -> RealData {
         right_ascension: RightAscension::new(16, 29, 24.),
         declination: Declination::new(Sgn::Neg, 26, 25, 55.),
         distance: Length::new::<light_year>(604.),
-        lifetime: Time::new::<gigayear>(0.015362858),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-12.11),
+            angular_velocity_from_mas_per_year(-23.3),
+            Velocity::new::<kilometer_per_second>(-3.4),
+        )),
+        variability: None,
+        spectral_type: "M1.5Iab-b",
     }
 }
 
@@ -40,7 +50,9 @@ fn shaula() -> RealData {
         declination: Declination::new(Sgn::Neg, 37, 6, 14.),
         distance: Length::new::<light_year>(600.),
         age: Some(Time::new::<gigayear>(0.026)),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
+        spectral_type: "B1.5IV",
     }
 }
 
@@ -58,7 +70,9 @@ fn sargas() -> RealData {
         right_ascension: RightAscension::new(17, 37, 19.),
         declination: Declination::new(Sgn::Neg, 42, 59, 52.),
         distance: Length::new::<light_year>(272.),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "F1II",
     }
 }
 
@@ -76,7 +90,9 @@ fn dschubba() -> RealData {
         right_ascension: RightAscension::new(16, 0, 20.),
         declination: Declination::new(Sgn::Neg, 22, 37, 18.),
         distance: Length::new::<light_year>(401.5),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B0.3IV",
     }
 }
 
@@ -94,7 +110,9 @@ fn larawag() -> RealData {
         right_ascension: RightAscension::new(16, 50, 10.),
         declination: Declination::new(Sgn::Neg, 34, 17, 36.),
         distance: Length::new::<light_year>(65.),
-        lifetime: Time::new::<gigayear>(4.45521207),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -112,7 +130,9 @@ fn girtab() -> RealData {
         declination: Declination::new(Sgn::Neg, 39, 1, 48.),
         distance: Length::new::<light_year>(464.),
         age: Some(Time::new::<gigayear>(0.012)),
-        lifetime: Time::new::<gigayear>(0.012799766),
+        motion: None,
+        variability: None,
+        spectral_type: "B1.5III",
     }
 }
 
@@ -130,7 +150,9 @@ fn acrab() -> RealData {
         declination: Declination::new(Sgn::Neg, 19, 48, 20.),
         distance: Length::new::<light_year>(530.),
         age: Some(Time::new::<gigayear>(0.015)),
-        lifetime: Time::new::<gigayear>(0.015362858),
+        motion: None,
+        variability: None,
+        spectral_type: "B1V",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_sculptoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(7.52)),
         temperature: ThermodynamicTemperature::new::<kelvin>(13_600.),
         age: Some(Time::new::<gigayear>(0.093)),
-        lifetime: Time::new::<gigayear>(0.111319448),
+        motion: None,
+        variability: None,
+        spectral_type: "B7IIIp",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_sculptoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.0)),
         temperature: ThermodynamicTemperature::new::<kelvin>(12_110.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "B9.5IVpHgMn",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_sulptoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4578.),
         age: Some(Time::new::<gigayear>(2.)),
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
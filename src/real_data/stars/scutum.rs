@@ -1,7 +1,7 @@
 use astro_coords::ra_and_dec::*;
-use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
+use astro_units::{length::solar_radius, mass::solar_mass};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature},
     length::light_year,
     thermodynamic_temperature::kelvin,
 };
@@ -22,7 +22,9 @@ fn alpha_scuti() -> RealData {
         radius: Some(Length::new::<solar_radius>(20.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4315.),
         age: None,
-        lifetime: Time::new::<gigayear>(3.46068223),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_scuti() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4622.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "G4IIa",
     }
 }
 
@@ -58,7 +62,9 @@ fn zeta_scuti() -> RealData {
         radius: Some(Length::new::<solar_radius>(9.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4750.),
         age: None,
-        lifetime: Time::new::<gigayear>(3.9126515),
+        motion: None,
+        variability: None,
+        spectral_type: "G9IIIb",
     }
 }
 
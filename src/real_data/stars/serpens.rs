@@ -22,7 +22,9 @@ fn unukalhai() -> RealData {
         right_ascension: RightAscension::new(15, 44, 16.),
         declination: Declination::new(Sgn::Pos, 6, 25, 32.),
         distance: Length::new::<light_year>(74.),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "K2IIIb",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_serpentis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(8928.),
         age: Some(Time::new::<gigayear>(0.267)),
-        lifetime: Time::new::<gigayear>(1.46316038),
+        motion: None,
+        variability: None,
+        spectral_type: "A2IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn mu_serpentis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(9487.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "A0V",
     }
 }
 
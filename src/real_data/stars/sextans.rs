@@ -22,7 +22,9 @@ fn alpha_sextantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.07)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9984.),
         age: Some(Time::new::<gigayear>(0.385)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A0III",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma_sextantis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(9825.),
         age: Some(Time::new::<gigayear>(0.401)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -58,7 +62,9 @@ fn beta_sextantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(14_570.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.111319448),
+        motion: None,
+        variability: None,
+        spectral_type: "B6V",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn aldebaran() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn aldebaran() -> RealData {
         right_ascension: RightAscension::new(4, 35, 55.),
         declination: Declination::new(Sgn::Pos, 16, 30, 33.),
         distance: Length::new::<light_year>(65.),
-        lifetime: Time::new::<gigayear>(5.9461393),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(63.45),
+            angular_velocity_from_mas_per_year(-188.94),
+            Velocity::new::<kilometer_per_second>(54.26),
+        )),
+        variability: None,
+        spectral_type: "K5III",
     }
 }
 
@@ -40,7 +50,9 @@ fn alnath() -> RealData {
         right_ascension: RightAscension::new(5, 26, 18.),
         declination: Declination::new(Sgn::Pos, 28, 36, 27.),
         distance: Length::new::<light_year>(131.),
-        lifetime: Time::new::<gigayear>(0.111319448),
+        motion: None,
+        variability: None,
+        spectral_type: "B7III",
     }
 }
 
@@ -58,7 +70,9 @@ fn gamma_tauri() -> RealData {
         radius: Some(Length::new::<solar_radius>(13.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4844.),
         age: Some(Time::new::<gigayear>(0.5)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "G9.5III",
     }
 }
 
@@ -76,7 +90,9 @@ fn epsilon_tauri() -> RealData {
         radius: Some(Length::new::<solar_radius>(12.35)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4950.),
         age: Some(Time::new::<gigayear>(0.625)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -94,7 +110,9 @@ fn lambda_tauri() -> RealData {
         radius: Some(Length::new::<solar_radius>(6.4)),
         temperature: ThermodynamicTemperature::new::<kelvin>(18_700.),
         age: Some(Time::new::<gigayear>(0.0332)),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "B3V",
     }
 }
 
@@ -112,7 +130,9 @@ fn zeta_tauri() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(15_500.),
         age: Some(Time::new::<gigayear>(0.019)),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: None,
+        variability: None,
+        spectral_type: "B4IIIpe",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_telescopii() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.3)),
         temperature: ThermodynamicTemperature::new::<kelvin>(16_700.),
         age: Some(Time::new::<gigayear>(0.0241)),
-        lifetime: Time::new::<gigayear>(0.10143918),
+        motion: None,
+        variability: None,
+        spectral_type: "B3IV",
     }
 }
 
@@ -40,7 +42,9 @@ fn zeta_telescopii() -> RealData {
         radius: Some(Length::new::<solar_radius>(9.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4801.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "K1III",
     }
 }
 
@@ -58,7 +62,9 @@ fn epsilon_telescopii() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4996.),
         age: None,
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "K0III",
     }
 }
 
@@ -22,7 +22,9 @@ fn beta_trianguli() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(8186.),
         age: Some(Time::new::<gigayear>(0.29)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "A5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn alpha_trianguli() -> RealData {
         radius: Some(Length::new::<solar_radius>(3.22)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6288.),
         age: Some(Time::new::<gigayear>(1.6)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "F6IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_trianguli() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.96)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9440.),
         age: Some(Time::new::<gigayear>(0.3)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
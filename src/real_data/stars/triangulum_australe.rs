@@ -22,7 +22,9 @@ fn atria() -> RealData {
         right_ascension: RightAscension::new(16, 48, 40.),
         declination: Declination::new(Sgn::Neg, 69, 1, 40.),
         distance: Length::new::<light_year>(415.),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "K2IIb-IIIa",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_trianguli_australis() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.976)),
         temperature: ThermodynamicTemperature::new::<kelvin>(7171.),
         age: Some(Time::new::<gigayear>(0.674)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "F1V",
     }
 }
 
@@ -58,7 +62,9 @@ fn gamma_trianguli_australis() -> RealData {
         radius: Some(Length::new::<solar_radius>(5.86)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9306.),
         age: Some(Time::new::<gigayear>(0.260)),
-        lifetime: Time::new::<gigayear>(1.36020165),
+        motion: None,
+        variability: None,
+        spectral_type: "A1III",
     }
 }
 
@@ -22,7 +22,9 @@ fn alpha_tucanae() -> RealData {
         right_ascension: RightAscension::new(22, 18, 30.),
         declination: Declination::new(Sgn::Neg, 60, 15, 35.),
         distance: Length::new::<light_year>(198.5),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -40,7 +42,9 @@ fn gamma_tucanae() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.2)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6679.),
         age: Some(Time::new::<gigayear>(1.414)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "F3IV",
     }
 }
 
@@ -58,7 +62,9 @@ fn zeta_tucanae() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.08)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5970.),
         age: Some(Time::new::<gigayear>(2.5)),
-        lifetime: Time::new::<gigayear>(9.81519157),
+        motion: None,
+        variability: None,
+        spectral_type: "F9.5V",
     }
 }
 
@@ -22,7 +22,9 @@ fn alioth() -> RealData {
         right_ascension: RightAscension::new(12, 54, 2.),
         declination: Declination::new(Sgn::Pos, 55, 57, 36.),
         distance: Length::new::<light_year>(81.),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "A1III-IVp",
     }
 }
 
@@ -40,7 +42,9 @@ fn dubhe() -> RealData {
         right_ascension: RightAscension::new(11, 3, 44.),
         declination: Declination::new(Sgn::Pos, 61, 45, 4.),
         distance: Length::new::<light_year>(124.),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "K0IIIa",
     }
 }
 
@@ -58,7 +62,9 @@ fn alkaid() -> RealData {
         right_ascension: RightAscension::new(13, 47, 32.),
         declination: Declination::new(Sgn::Pos, 49, 18, 48.),
         distance: Length::new::<light_year>(101.),
-        lifetime: Time::new::<gigayear>(0.073299383),
+        motion: None,
+        variability: None,
+        spectral_type: "B3V",
     }
 }
 
@@ -76,7 +82,9 @@ fn mizar() -> RealData {
         right_ascension: RightAscension::new(13, 23, 56.),
         declination: Declination::new(Sgn::Pos, 54, 55, 31.),
         distance: Length::new::<light_year>(78.),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -94,7 +102,9 @@ fn merak() -> RealData {
         right_ascension: RightAscension::new(11, 1, 50.),
         declination: Declination::new(Sgn::Pos, 56, 22, 57.),
         distance: Length::new::<light_year>(79.),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "A1IV",
     }
 }
 
@@ -112,7 +122,9 @@ fn phecda() -> RealData {
         right_ascension: RightAscension::new(11, 53, 50.),
         declination: Declination::new(Sgn::Pos, 53, 41, 41.),
         distance: Length::new::<light_year>(84.),
-        lifetime: Time::new::<gigayear>(0.420724107),
+        motion: None,
+        variability: None,
+        spectral_type: "A0Ve",
     }
 }
 
@@ -130,7 +142,9 @@ fn tania_australis() -> RealData {
         right_ascension: RightAscension::new(10, 22, 20.),
         declination: Declination::new(Sgn::Pos, 41, 29, 58.),
         distance: Length::new::<light_year>(230.0),
-        lifetime: Time::new::<gigayear>(0.067960505),
+        motion: None,
+        variability: None,
+        spectral_type: "M0III",
     }
 }
 
@@ -148,7 +162,9 @@ fn megrez() -> RealData {
         right_ascension: RightAscension::new(12, 15, 26.),
         declination: Declination::new(Sgn::Pos, 57, 1, 57.),
         distance: Length::new::<light_year>(80.5),
-        lifetime: Time::new::<gigayear>(1.89665739),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
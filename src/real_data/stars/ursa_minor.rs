@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn polaris() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn polaris() -> RealData {
         right_ascension: RightAscension::new(2, 31, 49.),
         declination: Declination::new(Sgn::Pos, 89, 15, 51.),
         distance: Length::new::<light_year>(431.),
-        lifetime: Time::new::<gigayear>(0.093024309),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(44.48),
+            angular_velocity_from_mas_per_year(-11.85),
+            Velocity::new::<kilometer_per_second>(-17.4),
+        )),
+        variability: None,
+        spectral_type: "F7Ib",
     }
 }
 
@@ -40,7 +50,9 @@ fn kochab() -> RealData {
         right_ascension: RightAscension::new(14, 50, 42.),
         declination: Declination::new(Sgn::Pos, 74, 9, 20.),
         distance: Length::new::<light_year>(126.),
-        lifetime: Time::new::<gigayear>(1.03650581),
+        motion: None,
+        variability: None,
+        spectral_type: "K4III",
     }
 }
 
@@ -58,7 +70,9 @@ fn zeta_ursae_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(6.15)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8720.),
         age: Some(Time::new::<gigayear>(0.180)),
-        lifetime: Time::new::<gigayear>(0.297402042),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
@@ -76,7 +90,9 @@ fn delta_ursae_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.8)),
         temperature: ThermodynamicTemperature::new::<kelvin>(9911.),
         age: Some(Time::new::<gigayear>(0.327)),
-        lifetime: Time::new::<gigayear>(0.916355612),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -94,7 +110,9 @@ fn eta_ursae_minoris() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.0)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6858.),
         age: Some(Time::new::<gigayear>(1.061)),
-        lifetime: Time::new::<gigayear>(3.46068223),
+        motion: None,
+        variability: None,
+        spectral_type: "F5V",
     }
 }
 
@@ -112,7 +130,9 @@ fn pherkad() -> RealData {
         radius: Some(Length::new::<solar_radius>(15.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8280.),
         age: Some(Time::new::<gigayear>(0.032)),
-        lifetime: Time::new::<gigayear>(0.03224554),
+        motion: None,
+        variability: None,
+        spectral_type: "A3III",
     }
 }
 
@@ -130,7 +150,9 @@ fn epsilon_ursae_minoris() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(5215.),
         age: None,
-        lifetime: Time::new::<gigayear>(6.97272616),
+        motion: None,
+        variability: None,
+        spectral_type: "G5III",
     }
 }
 
@@ -22,7 +22,9 @@ fn regor() -> RealData {
         right_ascension: RightAscension::new(8, 9, 32.),
         declination: Declination::new(Sgn::Neg, 47, 20, 12.),
         distance: Length::new::<light_year>(840.),
-        lifetime: Time::new::<gigayear>(0.006972406),
+        motion: None,
+        variability: None,
+        spectral_type: "WC8+O7.5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn alsephina() -> RealData {
         right_ascension: RightAscension::new(8, 44, 42.),
         declination: Declination::new(Sgn::Neg, 54, 42, 32.),
         distance: Length::new::<light_year>(80.),
-        lifetime: Time::new::<gigayear>(0.964406929),
+        motion: None,
+        variability: None,
+        spectral_type: "A1V",
     }
 }
 
@@ -58,7 +62,9 @@ fn suhail() -> RealData {
         right_ascension: RightAscension::new(9, 7, 60.),
         declination: Declination::new(Sgn::Neg, 43, 25, 57.),
         distance: Length::new::<light_year>(573.),
-        lifetime: Time::new::<gigayear>(0.052267043),
+        motion: None,
+        variability: None,
+        spectral_type: "K4Ib-II",
     }
 }
 
@@ -76,7 +82,9 @@ fn markeb() -> RealData {
         right_ascension: RightAscension::new(9, 22, 7.),
         declination: Declination::new(Sgn::Neg, 55, 0, 38.),
         distance: Length::new::<light_year>(539.),
-        lifetime: Time::new::<gigayear>(0.026540021),
+        motion: None,
+        variability: None,
+        spectral_type: "B2IV",
     }
 }
 
@@ -1,12 +1,16 @@
 use astro_coords::ra_and_dec::*;
 use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
 use uom::si::{
-    f64::{Length, Mass, ThermodynamicTemperature, Time},
+    f64::{Length, Mass, ThermodynamicTemperature, Time, Velocity},
     length::light_year,
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
-use crate::stars::real_data::RealData;
+use crate::{
+    stars::{motion::StarMotion, real_data::RealData},
+    units::angular_velocity::angular_velocity_from_mas_per_year,
+};
 
 fn spica() -> RealData {
     RealData {
@@ -22,7 +26,13 @@ fn spica() -> RealData {
         right_ascension: RightAscension::new(13, 25, 12.),
         declination: Declination::new(Sgn::Neg, 11, 9, 41.),
         distance: Length::new::<light_year>(262.),
-        lifetime: Time::new::<gigayear>(0.019450199),
+        motion: Some(StarMotion::new(
+            angular_velocity_from_mas_per_year(-42.35),
+            angular_velocity_from_mas_per_year(-30.67),
+            Velocity::new::<kilometer_per_second>(1.),
+        )),
+        variability: None,
+        spectral_type: "B1III-IV",
     }
 }
 
@@ -40,7 +50,9 @@ fn minelauva() -> RealData {
         right_ascension: RightAscension::new(12, 55, 36.),
         declination: Declination::new(Sgn::Pos, 3, 23, 51.),
         distance: Length::new::<light_year>(202.4),
-        lifetime: Time::new::<gigayear>(3.10253119),
+        motion: None,
+        variability: None,
+        spectral_type: "M3III",
     }
 }
 
@@ -58,7 +70,9 @@ fn zavijava() -> RealData {
         radius: Some(Length::new::<solar_radius>(1.681)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6132.),
         age: Some(Time::new::<gigayear>(2.9)),
-        lifetime: Time::new::<gigayear>(3.10253119),
+        motion: None,
+        variability: None,
+        spectral_type: "F9V",
     }
 }
 
@@ -76,7 +90,9 @@ fn syrma() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.5)),
         temperature: ThermodynamicTemperature::new::<kelvin>(6282.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.54186931),
+        motion: None,
+        variability: None,
+        spectral_type: "F7III",
     }
 }
 
@@ -94,7 +110,9 @@ fn heze() -> RealData {
         radius: Some(Length::new::<solar_radius>(2.079)),
         temperature: ThermodynamicTemperature::new::<kelvin>(8247.),
         age: Some(Time::new::<gigayear>(0.51)),
-        lifetime: Time::new::<gigayear>(1.25731981),
+        motion: None,
+        variability: None,
+        spectral_type: "A3V",
     }
 }
 
@@ -112,7 +130,9 @@ fn vindemiatrix() -> RealData {
         radius: Some(Length::new::<solar_radius>(10.6)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5086.),
         age: Some(Time::new::<gigayear>(0.560)),
-        lifetime: Time::new::<gigayear>(0.63513384),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
@@ -130,7 +150,9 @@ fn porrima() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(6757.),
         age: Some(Time::new::<gigayear>(1.14)),
-        lifetime: Time::new::<gigayear>(2.29668629),
+        motion: None,
+        variability: None,
+        spectral_type: "F1V",
     }
 }
 
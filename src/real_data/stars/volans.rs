@@ -22,7 +22,9 @@ fn gamma1_volantis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(6541.),
         age: Some(Time::new::<gigayear>(1.4)),
-        lifetime: Time::new::<gigayear>(1.73766023),
+        motion: None,
+        variability: None,
+        spectral_type: "F2V",
     }
 }
 
@@ -40,7 +42,9 @@ fn beta_volantis() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4546.),
         age: None,
-        lifetime: Time::new::<gigayear>(2.08398753),
+        motion: None,
+        variability: None,
+        spectral_type: "K2III",
     }
 }
 
@@ -58,7 +62,9 @@ fn zeta_volantis() -> RealData {
         radius: Some(Length::new::<solar_radius>(11.)),
         temperature: ThermodynamicTemperature::new::<kelvin>(4721.),
         age: Some(Time::new::<gigayear>(1.5)),
-        lifetime: Time::new::<gigayear>(1.59501327),
+        motion: None,
+        variability: None,
+        spectral_type: "G8III",
     }
 }
 
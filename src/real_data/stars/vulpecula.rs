@@ -22,7 +22,9 @@ fn alpha_vulpeculae() -> RealData {
         radius: Some(Length::new::<solar_radius>(43.14)),
         temperature: ThermodynamicTemperature::new::<kelvin>(3690.),
         age: Some(Time::new::<gigayear>(11.3)),
-        lifetime: Time::new::<gigayear>(11.7800188),
+        motion: None,
+        variability: None,
+        spectral_type: "M0.5III",
     }
 }
 
@@ -40,7 +42,9 @@ fn twentythree_vulpeculae() -> RealData {
         radius: None,
         temperature: ThermodynamicTemperature::new::<kelvin>(4429.),
         age: None,
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "K3III",
     }
 }
 
@@ -58,7 +62,9 @@ fn thirtyone_vulpeculae() -> RealData {
         radius: Some(Length::new::<solar_radius>(8.01)),
         temperature: ThermodynamicTemperature::new::<kelvin>(5261.),
         age: Some(Time::new::<gigayear>(0.7)),
-        lifetime: Time::new::<gigayear>(0.800458342),
+        motion: None,
+        variability: None,
+        spectral_type: "G7III",
     }
 }
 
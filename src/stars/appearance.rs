@@ -8,6 +8,8 @@ use uom::si::{
 
 use crate::{astro_display::AstroDisplay, color::srgb::sRGBColor};
 
+use super::extinction::{redden, transmission};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StarAppearance {
     pub(crate) name: String,
@@ -58,6 +60,13 @@ impl StarAppearance {
         self.pos = direction;
     }
 
+    // Dims and reddens the star by the visual extinction along the line of sight.
+    pub fn with_extinction(mut self, visual_extinction: f64) -> Self {
+        self.illuminance *= transmission(visual_extinction);
+        self.color = redden(&self.color, visual_extinction);
+        self
+    }
+
     pub fn apparently_the_same(&self, other: &Self) -> bool {
         let angle_accuracy = Angle::new::<degree>(0.03); //Rather high due to accos inaccuracy
 
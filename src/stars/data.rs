@@ -1,22 +1,71 @@
 use super::{
-    appearance::StarAppearance, evolution::StarDataEvolution, fate::StarFate,
+    appearance::StarAppearance,
+    evolution::{StarDataEvolution, StoredStarDataEvolution},
+    extinction::DustMap,
+    fate::StarFate,
+    photometry::{absolute_magnitude_in_band, bolometric_luminosity, PhotometricBand},
     physical_parameters::StarPhysicalParameters,
+    remnant::Remnant,
+    spectral_type::SpectralType,
+    variability::Variability,
 };
-use crate::color::srgb::sRGBColor;
+use crate::{color::srgb::sRGBColor, kinematics::velocity_vector::VelocityVector};
 use astro_coords::{cartesian::Cartesian, ecliptic::Ecliptic};
 use astro_units::luminous_intensity::calc_illuminance;
 use serde::{Deserialize, Serialize};
-use uom::si::f64::{Length, LuminousIntensity, Mass, ThermodynamicTemperature, Time};
+use uom::si::f64::{Length, LuminousIntensity, Mass, Power, ThermodynamicTemperature, Time};
+use uom::si::length::parsec;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredStarData")]
 pub struct StarData {
     pub(super) name: String,
     pub(super) constellation: Option<String>,
     pub(super) params: StarPhysicalParameters,
     pub(super) pos: Cartesian,
+    #[serde(default)]
+    pub(super) velocity: VelocityVector,
     pub(super) evolution: StarDataEvolution,
+    #[serde(default)]
+    pub(super) metallicity: Option<f64>, // Mass fraction of elements heavier than helium
+    #[serde(default)]
+    pub(super) variability: Option<Variability>,
 }
 
+#[derive(Deserialize)]
+struct StoredStarData {
+    name: String,
+    constellation: Option<String>,
+    params: StarPhysicalParameters,
+    pos: Cartesian,
+    #[serde(default)]
+    velocity: VelocityVector,
+    evolution: StoredStarDataEvolution,
+    #[serde(default)]
+    metallicity: Option<f64>,
+    #[serde(default)]
+    variability: Option<Variability>,
+}
+
+impl From<StoredStarData> for StarData {
+    fn from(stored: StoredStarData) -> Self {
+        let evolution = stored.evolution.resolve(stored.params.mass);
+        Self {
+            name: stored.name,
+            constellation: stored.constellation,
+            params: stored.params,
+            pos: stored.pos,
+            velocity: stored.velocity,
+            evolution,
+            metallicity: stored.metallicity,
+            variability: stored.variability,
+        }
+    }
+}
+
+// Below this angle, the motion of a star across the sky is not considered a change.
+const NOTICEABLE_MOTION_IN_RADIANS: f64 = 1e-6;
+
 impl StarData {
     pub fn new(
         name: String,
@@ -29,8 +78,11 @@ impl StarData {
             name,
             params,
             pos,
+            velocity: VelocityVector::zero(),
             constellation,
             evolution,
+            metallicity: None,
+            variability: None,
         }
     }
 
@@ -55,7 +107,10 @@ impl StarData {
     }
 
     pub fn get_radius(&self, time: Time) -> Option<Length> {
-        Some(self.evolution.apply_to_radius(self.params.radius?, time))
+        Some(
+            self.evolution
+                .apply_to_radius(self.params.radius?, self.params.mass, time),
+        )
     }
 
     pub const fn get_luminous_intensity_at_epoch(&self) -> LuminousIntensity {
@@ -63,8 +118,15 @@ impl StarData {
     }
 
     pub fn get_luminous_intensity(&self, time: Time) -> LuminousIntensity {
-        self.evolution
-            .apply_to_luminous_intensity(self.params.luminous_intensity, time)
+        let luminous_intensity = self.evolution.apply_to_luminous_intensity(
+            self.params.luminous_intensity,
+            self.params.mass,
+            time,
+        );
+        match self.variability_while_alive(time) {
+            Some(variability) => variability.apply_to_luminous_intensity(luminous_intensity, time),
+            None => luminous_intensity,
+        }
     }
 
     pub const fn get_temperature_at_epoch(&self) -> ThermodynamicTemperature {
@@ -72,8 +134,66 @@ impl StarData {
     }
 
     pub fn get_temperature(&self, time: Time) -> ThermodynamicTemperature {
-        self.evolution
-            .apply_to_temperature(self.params.temperature, time)
+        let temperature = self.evolution.apply_to_temperature(
+            self.params.temperature,
+            self.params.luminous_intensity,
+            self.params.mass,
+            time,
+        );
+        match self.variability_while_alive(time) {
+            Some(variability) => variability.apply_to_temperature(temperature, time),
+            None => temperature,
+        }
+    }
+
+    pub fn get_absolute_magnitude(&self, band: PhotometricBand, time: Time) -> f64 {
+        absolute_magnitude_in_band(
+            band,
+            self.get_luminous_intensity(time),
+            self.get_temperature(time),
+        )
+    }
+
+    // https://en.wikipedia.org/wiki/Distance_modulus
+    pub fn get_apparent_magnitude(&self, band: PhotometricBand, time: Time) -> f64 {
+        let distance_in_parsec = self.get_distance(time).get::<parsec>();
+        self.get_absolute_magnitude(band, time) + 5. * distance_in_parsec.log10() - 5.
+    }
+
+    pub fn get_bolometric_luminosity(&self, time: Time) -> Power {
+        bolometric_luminosity(
+            self.get_radius(time),
+            self.get_temperature(time),
+            self.get_luminous_intensity(time),
+        )
+    }
+
+    // Neutron stars and black holes, as well as the explosion of a star, have no spectral type.
+    pub fn get_spectral_type(&self, time: Time) -> Option<SpectralType> {
+        let has_died = matches!(
+            self.evolution.time_until_death(time),
+            Some(time_until_death) if time_until_death <= Time::default()
+        );
+        if has_died && self.get_fate().get_remnant() != Some(Remnant::WhiteDwarf) {
+            return None;
+        }
+        Some(SpectralType::from_temperature_and_luminous_intensity(
+            self.get_temperature(time),
+            self.get_luminous_intensity(time),
+        ))
+    }
+
+    pub const fn get_variability(&self) -> &Option<Variability> {
+        &self.variability
+    }
+
+    // Remnants no longer pulsate, and a companion that used to eclipse them does not hide much light.
+    fn variability_while_alive(&self, time: Time) -> Option<&Variability> {
+        let variability = self.variability.as_ref()?;
+        match self.evolution.time_until_death(time) {
+            Some(time_until_death) if time_until_death <= Time::default() => None,
+            _ => Some(variability),
+        }
     }
 
     pub const fn get_age_at_epoch(&self) -> Option<Time> {
@@ -96,8 +216,12 @@ impl StarData {
         &self.pos
     }
 
-    pub fn get_pos(&self, _time: Time) -> Cartesian {
-        self.pos.clone()
+    pub fn get_pos(&self, time: Time) -> Cartesian {
+        &self.pos + &self.velocity.displacement(time)
+    }
+
+    pub const fn get_velocity(&self) -> &VelocityVector {
+        &self.velocity
     }
 
     pub fn get_time_until_death(&self, time_since_epoch: Time) -> Option<Time> {
@@ -112,6 +236,10 @@ impl StarData {
         &self.evolution.fate
     }
 
+    pub const fn get_metallicity(&self) -> Option<f64> {
+        self.metallicity
+    }
+
     pub fn set_name(&mut self, name: String) {
         self.name = name;
     }
@@ -140,6 +268,14 @@ impl StarData {
         self.evolution.age = age;
     }
 
+    pub fn set_metallicity(&mut self, metallicity: Option<f64>) {
+        self.metallicity = metallicity;
+    }
+
+    pub fn set_variability(&mut self, variability: Option<Variability>) {
+        self.variability = variability;
+    }
+
     pub fn set_distance_at_epoch(&mut self, distance: Length) {
         let direction = match self.pos.to_direction() {
             Ok(direction) => direction,
@@ -152,24 +288,60 @@ impl StarData {
         self.pos = pos;
     }
 
+    pub fn set_velocity(&mut self, velocity: VelocityVector) {
+        self.velocity = velocity;
+    }
+
     pub fn get_evolution(&self) -> &StarDataEvolution {
         &self.evolution
     }
 
     pub fn has_changed(&self, then: Time, now: Time) -> bool {
         self.evolution.has_changed(then, now)
+            || self.has_moved(then, now)
+            || self
+                .variability_while_alive(now)
+                .is_some_and(|variability| variability.has_changed(then, now))
+    }
+
+    fn has_moved(&self, then: Time, now: Time) -> bool {
+        if self.velocity.is_zero() {
+            return false;
+        }
+        let displacement = self.velocity.displacement(now - then).length();
+        let distance = self.get_distance(then).min(self.get_distance(now));
+        (displacement / distance).value > NOTICEABLE_MOTION_IN_RADIANS
     }
 
     pub fn to_star_appearance(&self, time_since_epoch: Time) -> StarAppearance {
+        self.to_star_appearance_at(&self.get_pos(time_since_epoch), time_since_epoch)
+    }
+
+    // As seen from the given observer, dimmed and reddened by the dust in between.
+    pub fn to_star_appearance_through_dust(
+        &self,
+        dust_map: &dyn DustMap,
+        observer_position: &Cartesian,
+        time_since_epoch: Time,
+    ) -> StarAppearance {
+        let pos = self.get_pos(time_since_epoch);
+        let visual_extinction = dust_map.visual_extinction(observer_position, &pos);
+        self.to_star_appearance_at(&(&pos - observer_position), time_since_epoch)
+            .with_extinction(visual_extinction)
+    }
+
+    // For components of a multiple star, whose positions relative to the observer are set by their orbits.
+    pub(super) fn to_star_appearance_at(
+        &self,
+        pos: &Cartesian,
+        time_since_epoch: Time,
+    ) -> StarAppearance {
         let luminous_intensity = self.get_luminous_intensity(time_since_epoch);
-        let illuminance = calc_illuminance(luminous_intensity, self.get_distance(time_since_epoch));
+        let illuminance = calc_illuminance(luminous_intensity, pos.length());
 
         let color = sRGBColor::from_temperature(self.get_temperature(time_since_epoch));
 
-        let pos = self
-            .get_pos(time_since_epoch)
-            .to_ecliptic()
-            .unwrap_or(Ecliptic::x_direction());
+        let pos = pos.to_ecliptic().unwrap_or(Ecliptic::x_direction());
 
         StarAppearance {
             name: self.name.clone(),
@@ -183,8 +355,13 @@ impl StarData {
 
 #[cfg(test)]
 mod tests {
-    use astro_units::mass::solar_mass;
+    use astro_units::{
+        illuminance::illuminance_to_apparent_magnitude,
+        luminous_intensity::{luminous_intensity_to_absolute_magnitude, solar_luminous_intensity},
+        mass::solar_mass,
+    };
     use uom::si::{
+        angle::second,
         length::{light_year, meter},
         luminous_intensity::candela,
         mass::kilogram,
@@ -193,7 +370,60 @@ mod tests {
     };
 
     use super::*;
-    use crate::real_data::stars::all::get_many_stars;
+    use crate::{
+        astro_display::AstroDisplay,
+        real_data::stars::{all::get_many_stars, sun},
+        stars::{
+            extinction::ExponentialDustDisk, photometry::PhotometricBand,
+            random::galactic_structure::GalacticStructure, spectral_type::LuminosityClass,
+            variability::cepheid_absolute_magnitude,
+        },
+        tests::eq_within,
+    };
+
+    #[test]
+    fn star_data_without_velocity_deserializes_as_resting() {
+        let mut json = serde_json::to_value(sun().to_star_data()).unwrap();
+        json.as_object_mut().unwrap().remove("velocity");
+        let star: StarData = serde_json::from_value(json).unwrap();
+        assert!(star.get_velocity().is_zero());
+    }
+
+    #[test]
+    fn fates_stored_by_earlier_versions_are_read() {
+        let mut star = sun().to_star_data();
+        let mut with_fate = |fate: &str, mass: f64| {
+            star.params.mass = Some(Mass::new::<solar_mass>(mass));
+            let mut json = serde_json::to_value(&star).unwrap();
+            json["evolution"]["fate"] = serde_json::Value::from(fate);
+            let star: StarData = serde_json::from_value(json).unwrap();
+            *star.get_fate()
+        };
+        assert_eq!(with_fate("WhiteDwarf", 1.), StarFate::PlanetaryNebula);
+        assert_eq!(
+            with_fate("TypeIISupernova", 12.),
+            StarFate::TypeIISupernova(Remnant::NeutronStar)
+        );
+        assert_eq!(
+            with_fate("TypeIISupernova", 40.),
+            StarFate::TypeIISupernova(Remnant::BlackHole)
+        );
+    }
+
+    #[test]
+    fn fates_survive_a_round_trip() {
+        for fate in [
+            StarFate::PlanetaryNebula,
+            StarFate::TypeIISupernova(Remnant::BlackHole),
+            StarFate::TypeIaSupernova,
+        ] {
+            let mut star = sun().to_star_data();
+            star.evolution.fate = fate;
+            let json = serde_json::to_string(&star).unwrap();
+            let deserialized: StarData = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized.get_fate(), &fate);
+        }
+    }
 
     #[test]
     fn real_stars_have_a_non_vanishing_lifetime() {
@@ -218,7 +448,7 @@ mod tests {
         let star_data: Vec<StarData> = get_many_stars().iter().map(|s| s.to_star_data()).collect();
         for star in star_data {
             if star.params.mass.unwrap() < Mass::new::<solar_mass>(8.0) {
-                assert_eq!(star.get_fate(), &StarFate::WhiteDwarf);
+                assert_eq!(star.get_fate(), &StarFate::PlanetaryNebula);
             }
         }
     }
@@ -228,11 +458,34 @@ mod tests {
         let star_data: Vec<StarData> = get_many_stars().iter().map(|s| s.to_star_data()).collect();
         for star in star_data {
             if star.params.mass.unwrap() > Mass::new::<solar_mass>(8.0) {
-                assert_eq!(star.get_fate(), &StarFate::TypeIISupernova);
+                assert!(star.get_fate().is_supernova());
             }
         }
     }
 
+    #[test]
+    fn sirius_moves_across_the_sky() {
+        let sirius = get_many_stars()
+            .into_iter()
+            .find(|s| s.common_name == "Sirius")
+            .unwrap()
+            .to_star_data();
+        let then = Time::new::<year>(0.);
+        let now = Time::new::<year>(1_000.);
+        let angle = sirius.get_pos(then).angle_to(&sirius.get_pos(now)).unwrap();
+        assert!(eq_within(angle.get::<second>(), 1339., 20.));
+        assert!(sirius.has_changed(then, now));
+    }
+
+    #[test]
+    fn stars_without_velocity_do_not_move() {
+        let mut star = get_many_stars()[0].to_star_data();
+        star.set_velocity(VelocityVector::zero());
+        let then = star.get_pos(Time::new::<year>(0.));
+        let now = star.get_pos(Time::new::<year>(1e6));
+        assert!(then.distance(&now).get::<meter>() < 1.);
+    }
+
     fn kinda_equal(a: Option<f64>, b: Option<f64>) -> bool {
         match (a, b) {
             (Some(a), Some(b)) => (a - b).abs() < 1e-6,
@@ -244,7 +497,9 @@ mod tests {
     #[test]
     fn comparing_getting_stuff_at_epoch() {
         let star_data: Vec<StarData> = get_many_stars().iter().map(|s| s.to_star_data()).collect();
-        for star in star_data {
+        for mut star in star_data {
+            // The catalogued values of variable stars are their means over the light curve.
+            star.set_variability(None);
             assert!(
                 kinda_equal(
                     star.get_mass_at_epoch().map(|m| m.get::<kilogram>()),
@@ -324,4 +579,167 @@ mod tests {
             ));
         }
     }
+
+    #[test]
+    fn old_white_dwarfs_fade_from_view() {
+        let params = StarPhysicalParameters::new(
+            Some(Mass::new::<solar_mass>(2.)),
+            None,
+            100. * solar_luminous_intensity(),
+            ThermodynamicTemperature::new::<kelvin>(4_000.),
+        );
+        let lifetime = Time::new::<year>(1e9);
+        let evolution =
+            StarDataEvolution::new(None, Some(lifetime), lifetime, StarFate::PlanetaryNebula);
+        let pos = Cartesian::new(
+            Length::new::<light_year>(10.),
+            Length::new::<light_year>(0.),
+            Length::new::<light_year>(0.),
+        );
+        let star = StarData::new("".to_string(), None, params, pos, evolution);
+        let young = star.to_star_appearance(Time::new::<year>(1e6));
+        let old = star.to_star_appearance(Time::new::<year>(1e9));
+        assert!(old.illuminance < young.illuminance);
+        assert!(
+            star.get_temperature(Time::new::<year>(1e9))
+                < star.get_temperature(Time::new::<year>(1e6))
+        );
+    }
+
+    fn real_star(astronomical_name: &str) -> StarData {
+        get_many_stars()
+            .into_iter()
+            .find(|s| s.astronomical_name == astronomical_name)
+            .unwrap()
+            .to_star_data()
+    }
+
+    #[test]
+    fn distant_stars_in_the_plane_appear_dimmer_and_redder() {
+        let towards_center = GalacticStructure::milky_way()
+            .get_galactic_center_direction()
+            .to_cartesian(Length::new::<light_year>(5_000.));
+        let mut star = sun().to_star_data();
+        star.pos = towards_center.clone();
+        let now = Time::new::<year>(0.);
+        let dust = ExponentialDustDisk::milky_way();
+        let through_dust = star.to_star_appearance_through_dust(&dust, &Cartesian::origin(), now);
+        let without_dust = star.to_star_appearance(now);
+        assert!(through_dust.illuminance < without_dust.illuminance * 0.5);
+        let (red, _, blue) = through_dust.color.maximized_sRGB_tuple();
+        let (clear_red, _, clear_blue) = without_dust.color.maximized_sRGB_tuple();
+        assert!(blue / red < clear_blue / clear_red);
+
+        let observer = towards_center * 0.999;
+        let nearby = star.to_star_appearance_through_dust(&dust, &observer, now);
+        assert!(nearby.illuminance > without_dust.illuminance * 1e5);
+    }
+
+    #[test]
+    fn spectral_types_follow_the_evolution() {
+        let sun = sun().to_star_data();
+        let now = Time::new::<year>(0.);
+        assert_eq!(sun.get_spectral_type(now).unwrap().astro_display(), "G2V");
+        let as_white_dwarf = sun.get_time_until_death(now).unwrap() + Time::new::<year>(1e9);
+        let white_dwarf = sun.get_spectral_type(as_white_dwarf).unwrap();
+        assert_eq!(
+            white_dwarf.get_luminosity_class(),
+            Some(LuminosityClass::VII)
+        );
+
+        let betelgeuse = real_star("α Orionis");
+        assert!(betelgeuse
+            .get_spectral_type(now)
+            .unwrap()
+            .get_luminosity_class()
+            .unwrap()
+            .is_supergiant());
+        let after_death = betelgeuse.get_time_until_death(now).unwrap() + Time::new::<year>(1.);
+        assert_eq!(betelgeuse.get_spectral_type(after_death), None);
+    }
+
+    #[test]
+    fn delta_cephei_pulsates_in_brightness_and_color() {
+        let delta_cephei = real_star("δ Cephei");
+        let period = delta_cephei
+            .get_variability()
+            .as_ref()
+            .unwrap()
+            .get_period();
+        let appearances: Vec<StarAppearance> = (0..20)
+            .map(|i| delta_cephei.to_star_appearance(period * (i as f64 / 20.)))
+            .collect();
+        let magnitudes: Vec<f64> = appearances
+            .iter()
+            .map(|a| illuminance_to_apparent_magnitude(a.illuminance))
+            .collect();
+        let brightest = magnitudes.iter().cloned().fold(f64::INFINITY, f64::min);
+        let faintest = magnitudes.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        assert!(eq_within(faintest - brightest, 0.89, 0.1));
+        assert!(delta_cephei.has_changed(Time::new::<year>(0.), period * 0.5));
+        let temperatures: Vec<f64> = (0..20)
+            .map(|i| {
+                delta_cephei
+                    .get_temperature(period * (i as f64 / 20.))
+                    .get::<kelvin>()
+            })
+            .collect();
+        let hottest = temperatures
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let coolest = temperatures.iter().cloned().fold(f64::INFINITY, f64::min);
+        assert!(hottest - coolest > 500.);
+    }
+
+    #[test]
+    fn delta_cephei_follows_the_period_luminosity_relation() {
+        let delta_cephei = real_star("δ Cephei");
+        let period = delta_cephei
+            .get_variability()
+            .as_ref()
+            .unwrap()
+            .get_period();
+        let absolute_magnitude = luminous_intensity_to_absolute_magnitude(
+            delta_cephei.get_luminous_intensity_at_epoch(),
+        );
+        assert!(eq_within(
+            absolute_magnitude,
+            cepheid_absolute_magnitude(period),
+            0.3
+        ));
+    }
+
+    #[test]
+    fn algol_dims_only_during_its_eclipses() {
+        let algol = real_star("β Persei");
+        let variability = algol.get_variability().as_ref().unwrap().clone();
+        let at_phase = |phase: f64| {
+            let phase_since_epoch = (phase - variability.get_phase_at_epoch()).rem_euclid(1.);
+            variability.get_period() * phase_since_epoch
+        };
+        let in_eclipse = algol.get_luminous_intensity(at_phase(0.));
+        let out_of_eclipse = algol.get_luminous_intensity(at_phase(0.25));
+        assert!(in_eclipse < out_of_eclipse * 0.4);
+        assert_eq!(
+            algol.get_temperature(at_phase(0.)),
+            algol.get_temperature(at_phase(0.25))
+        );
+    }
+
+    #[test]
+    fn betelgeuse_is_brightest_in_the_infrared() {
+        let mut betelgeuse = real_star("α Orionis");
+        betelgeuse.set_variability(None);
+        let time = Time::new::<year>(0.);
+        let v = betelgeuse.get_apparent_magnitude(PhotometricBand::V, time);
+        // The catalogue lists an apparent magnitude of 0.9.
+        assert!(eq_within(v, 0.9, 0.1));
+        let g = betelgeuse.get_apparent_magnitude(PhotometricBand::G, time);
+        let k = betelgeuse.get_apparent_magnitude(PhotometricBand::K, time);
+        assert!(g < v - 0.5);
+        assert!(k < v - 3.);
+        let bolometric_in_solar = betelgeuse.get_bolometric_luminosity(time).value / 3.828e26;
+        assert!((5e4..5e5).contains(&bolometric_in_solar));
+    }
 }
@@ -1,4 +1,6 @@
-use astro_units::{length::solar_radius, mass::solar_mass, time::gigayear};
+use astro_units::{
+    length::solar_radius, luminous_intensity::solar_luminous_intensity, mass::solar_mass,
+};
 use serde::{Deserialize, Serialize};
 use uom::si::{
     f64::{Length, LuminousIntensity, Mass, TemperatureInterval, ThermodynamicTemperature, Time},
@@ -8,14 +10,67 @@ use uom::si::{
     time::year,
 };
 
-use super::{data::StarData, fate::StarFate};
+use super::{
+    data::StarData,
+    fate::{StarFate, StoredStarFate},
+    isochrone_fit::{evaluate_age, fit_age, parsec_lifetime, IsochroneFit},
+    random::parsec::interpolation::{InterpolatedLine, ParsecTrack},
+};
+
+/*
+ * How a generated star evolves away from its epoch.
+ * The linear lifestage rates are cheap, but after a few thousand years they drift off,
+ * down to negative luminosities. Following the PARSEC track instead reproduces the red giant branch,
+ * the helium flash and the asymptotic giant branch with their proper timing.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EvolutionMode {
+    #[default]
+    LinearLifestage,
+    ParsecTrack,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StarDataEvolution {
     lifestage_evolution: Option<StarDataLifestageEvolution>,
+    #[serde(default)]
+    track: Option<ParsecTrack>,
     pub(super) age: Option<Time>,
     pub(super) lifetime: Time,
     pub(super) fate: StarFate,
+    #[serde(default)]
+    isochrone_fit: Option<IsochroneFit>,
+    #[serde(default)]
+    initial_mass: Option<Mass>, // Only known for stars that were generated on a track
+}
+
+// As stored, with a fate that may still depend on the mass of the star.
+#[derive(Deserialize)]
+pub(super) struct StoredStarDataEvolution {
+    lifestage_evolution: Option<StarDataLifestageEvolution>,
+    #[serde(default)]
+    track: Option<ParsecTrack>,
+    age: Option<Time>,
+    lifetime: Time,
+    fate: StoredStarFate,
+    #[serde(default)]
+    isochrone_fit: Option<IsochroneFit>,
+    #[serde(default)]
+    initial_mass: Option<Mass>,
+}
+
+impl StoredStarDataEvolution {
+    pub(super) fn resolve(self, mass: Option<Mass>) -> StarDataEvolution {
+        StarDataEvolution {
+            lifestage_evolution: self.lifestage_evolution,
+            track: self.track,
+            age: self.age,
+            lifetime: self.lifetime,
+            fate: self.fate.resolve(self.initial_mass.or(mass)),
+            isochrone_fit: self.isochrone_fit,
+            initial_mass: self.initial_mass,
+        }
+    }
 }
 
 impl StarDataEvolution {
@@ -23,9 +78,12 @@ impl StarDataEvolution {
     pub fn none() -> StarDataEvolution {
         Self {
             lifestage_evolution: None,
+            track: None,
             age: None,
             lifetime: Time::new::<year>(0.),
-            fate: StarFate::WhiteDwarf,
+            fate: StarFate::PlanetaryNebula,
+            isochrone_fit: None,
+            initial_mass: None,
         }
     }
 
@@ -37,20 +95,57 @@ impl StarDataEvolution {
     ) -> Self {
         Self {
             lifestage_evolution,
+            track: None,
             age,
             lifetime,
             fate,
+            isochrone_fit: None,
+            initial_mass: None,
         }
     }
 
-    pub(crate) fn from_age_and_mass(age: Time, mass: Mass) -> Self {
-        let lifetime = Time::new::<gigayear>(10.) * mass.get::<solar_mass>().powf(-2.5); //TODO: find a better formula
-        let fate = StarFate::new(mass);
+    pub(crate) fn with_track(mut self, track: ParsecTrack) -> Self {
+        self.track = Some(track);
+        self
+    }
+
+    pub(crate) fn with_initial_mass(mut self, initial_mass: Mass) -> Self {
+        self.initial_mass = Some(initial_mass);
+        self
+    }
+
+    /*
+     * Catalogued stars live as long as the PARSEC track of their mass.
+     * A catalogued age is checked against that track, while a missing one is fitted
+     * to the observed temperature and luminosity. So is a catalogued age beyond the lifetime,
+     * because a star that is observed today cannot have died already. The fit keeps that age
+     * as rejected and is then implausible.
+     */
+    pub(crate) fn from_catalogue(
+        mass: Mass,
+        metallicity: Option<f64>,
+        age: Option<Time>,
+        luminous_intensity: LuminousIntensity,
+        temperature: ThermodynamicTemperature,
+    ) -> Self {
+        let lifetime = parsec_lifetime(mass, metallicity);
+        let isochrone_fit = match age {
+            Some(age) if age < lifetime => {
+                evaluate_age(mass, metallicity, age, luminous_intensity, temperature)
+            }
+            Some(age) => {
+                fit_age(mass, metallicity, luminous_intensity, temperature).with_rejected_age(age)
+            }
+            None => fit_age(mass, metallicity, luminous_intensity, temperature),
+        };
         Self {
             lifestage_evolution: None,
-            age: Some(age),
+            track: None,
+            age: Some(isochrone_fit.age),
             lifetime,
-            fate,
+            fate: StarFate::new(mass, metallicity),
+            isochrone_fit: Some(isochrone_fit),
+            initial_mass: None,
         }
     }
 
@@ -72,6 +167,17 @@ impl StarDataEvolution {
             }
         }
 
+        if let (Some(line_then), Some(line_now)) =
+            (self.line_on_track(then), self.line_on_track(now))
+        {
+            let luminosity_change =
+                relative_change(line_then.luminosity_in_solar, line_now.luminosity_in_solar);
+            let temperature_change =
+                relative_change(line_then.temperature.value, line_now.temperature.value);
+            return luminosity_change > NOTICEABLE_RELATIVE_CHANGE
+                || temperature_change > NOTICEABLE_RELATIVE_CHANGE;
+        }
+
         let diff = (then - now).abs();
         let evolution_timescale = Time::new::<year>(1_000.);
 
@@ -85,24 +191,53 @@ impl StarDataEvolution {
         self.age.map(|age| self.lifetime - age - time_since_epoch)
     }
 
+    pub fn get_isochrone_fit(&self) -> Option<&IsochroneFit> {
+        self.isochrone_fit.as_ref()
+    }
+
+    pub fn get_initial_mass(&self) -> Option<Mass> {
+        self.initial_mass
+    }
+
+    pub fn follows_parsec_track(&self) -> bool {
+        self.track.is_some()
+    }
+
+    fn line_on_track(&self, time_since_epoch: Time) -> Option<InterpolatedLine> {
+        let age = self.age?;
+        let track = self.track.as_ref()?;
+        Some(track.interpolated().at_age(age + time_since_epoch))
+    }
+
     pub(crate) fn apply_to_mass(&self, mass: Mass, time_since_epoch: Time) -> Mass {
         if let Some(time_until_death) = self.time_until_death(time_since_epoch) {
             if time_until_death.value < 0. {
                 return self.fate.apply_to_mass(mass);
             }
         }
+        if let Some(line) = self.line_on_track(time_since_epoch) {
+            return line.mass;
+        }
         if let Some(lifestage_evolution) = &self.lifestage_evolution {
             return mass + lifestage_evolution.mass_per_year * time_since_epoch.get::<year>();
         }
         mass
     }
 
-    pub(crate) fn apply_to_radius(&self, radius: Length, time_since_epoch: Time) -> Length {
+    pub(crate) fn apply_to_radius(
+        &self,
+        radius: Length,
+        mass: Option<Mass>,
+        time_since_epoch: Time,
+    ) -> Length {
         if let Some(time_until_death) = self.time_until_death(time_since_epoch) {
             if time_until_death.value < 0. {
-                return self.fate.apply_to_radius();
+                return self.fate.apply_to_radius(mass);
             }
         }
+        if let Some(line) = self.line_on_track(time_since_epoch) {
+            return line.radius;
+        }
         if let Some(lifestage_evolution) = &self.lifestage_evolution {
             return radius + lifestage_evolution.radius_per_year * time_since_epoch.get::<year>();
         }
@@ -112,15 +247,21 @@ impl StarDataEvolution {
     pub(crate) fn apply_to_luminous_intensity(
         &self,
         luminous_intensity: LuminousIntensity,
+        mass: Option<Mass>,
         time_since_epoch: Time,
     ) -> LuminousIntensity {
         if let Some(time_until_death) = self.time_until_death(time_since_epoch) {
             if time_until_death.value < 0. {
-                return self
-                    .fate
-                    .apply_to_luminous_intensity(luminous_intensity, -time_until_death);
+                return self.fate.apply_to_luminous_intensity(
+                    luminous_intensity,
+                    mass,
+                    -time_until_death,
+                );
             }
         }
+        if let Some(line) = self.line_on_track(time_since_epoch) {
+            return line.luminosity_in_solar * solar_luminous_intensity();
+        }
         if let Some(lifestage_evolution) = &self.lifestage_evolution {
             return luminous_intensity
                 + lifestage_evolution.luminous_intensity_per_year * time_since_epoch.get::<year>();
@@ -131,15 +272,23 @@ impl StarDataEvolution {
     pub(crate) fn apply_to_temperature(
         &self,
         temperature: ThermodynamicTemperature,
+        luminous_intensity: LuminousIntensity,
+        mass: Option<Mass>,
         time_since_epoch: Time,
     ) -> ThermodynamicTemperature {
         if let Some(time_until_death) = self.time_until_death(time_since_epoch) {
             if time_until_death.value < 0. {
-                return self
-                    .fate
-                    .apply_to_temperature(temperature, -time_until_death);
+                return self.fate.apply_to_temperature(
+                    temperature,
+                    luminous_intensity,
+                    mass,
+                    -time_until_death,
+                );
             }
         }
+        if let Some(line) = self.line_on_track(time_since_epoch) {
+            return line.temperature;
+        }
         if let Some(lifestage_evolution) = &self.lifestage_evolution {
             let temp_diff =
                 lifestage_evolution.temperature_per_year * time_since_epoch.get::<year>();
@@ -177,6 +326,18 @@ impl StarDataEvolution {
     }
 }
 
+// About 0.01 magnitudes, or a barely noticeable shift in colour.
+const NOTICEABLE_RELATIVE_CHANGE: f64 = 0.01;
+
+fn relative_change(then: f64, now: f64) -> f64 {
+    let larger = then.abs().max(now.abs());
+    if larger > 0. {
+        (then - now).abs() / larger
+    } else {
+        0.
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct StarDataLifestageEvolution {
     mass_per_year: Mass,
@@ -218,6 +379,7 @@ impl StarDataLifestageEvolution {
 
 #[cfg(test)]
 mod tests {
+    use astro_units::time::gigayear;
     use uom::si::time::{day, hour, minute, second};
 
     use super::*;
@@ -237,8 +399,12 @@ mod tests {
             Time::new::<year>(-1_000.),
             Time::new::<year>(-10_000.),
         ];
-        let evolution =
-            StarDataEvolution::new(None, None, Time::new::<year>(10_000.), StarFate::WhiteDwarf);
+        let evolution = StarDataEvolution::new(
+            None,
+            None,
+            Time::new::<year>(10_000.),
+            StarFate::PlanetaryNebula,
+        );
         for now in times.clone().into_iter() {
             for then in times.clone().into_iter() {
                 assert_eq!(
@@ -263,7 +429,7 @@ mod tests {
             Some(lifestage_evolution),
             None,
             Time::new::<year>(10_000.),
-            StarFate::WhiteDwarf,
+            StarFate::PlanetaryNebula,
         );
         assert!(evolution.has_changed(then, now));
         assert!(evolution.has_changed(now, then));
@@ -275,7 +441,7 @@ mod tests {
         let now = Time::new::<year>(10_000.);
         let age = Some(Time::new::<year>(1_000.));
         let lifetime = Time::new::<year>(5_000.);
-        let evolution = StarDataEvolution::new(None, age, lifetime, StarFate::WhiteDwarf);
+        let evolution = StarDataEvolution::new(None, age, lifetime, StarFate::PlanetaryNebula);
         assert!(evolution.has_changed(then, now));
         assert!(evolution.has_changed(now, then));
     }
@@ -291,7 +457,7 @@ mod tests {
             Time::new::<year>(1.),
         ];
         let age = Some(lifetime);
-        let evolution = StarDataEvolution::new(None, age, lifetime, StarFate::WhiteDwarf);
+        let evolution = StarDataEvolution::new(None, age, lifetime, StarFate::PlanetaryNebula);
         for step1 in small_steps.clone().into_iter() {
             for step2 in small_steps.clone().into_iter() {
                 let now = step1;
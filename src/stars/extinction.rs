@@ -0,0 +1,286 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use astro_coords::cartesian::Cartesian;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::Length,
+    length::{light_year, micrometer, parsec},
+};
+
+use crate::color::srgb::sRGBColor;
+
+use super::{photometry::PhotometricBand, random::galactic_structure::GalacticStructure};
+
+/*
+ * Interstellar dust dims the light of distant stars and, because it scatters blue light more than red light,
+ * also reddens them. The visual extinction A_V is the dimming in the V band in magnitudes,
+ * the color excess E(B-V) is by how much the star appears redder than it is.
+ * https://en.wikipedia.org/wiki/Extinction_(astronomy)
+ */
+pub trait DustMap: Debug + Send + Sync {
+    fn visual_extinction(&self, from: &Cartesian, to: &Cartesian) -> f64;
+
+    fn color_excess(&self, from: &Cartesian, to: &Cartesian) -> f64 {
+        color_excess(self.visual_extinction(from, to))
+    }
+}
+
+// The ratio of total to selective extinction in the diffuse interstellar medium.
+pub const TOTAL_TO_SELECTIVE_EXTINCTION: f64 = 3.1;
+
+/*
+ * Dust that is concentrated in a thin exponential disk, much flatter than that of the stars.
+ * The local extinction is given in magnitudes of A_V per kiloparsec, in the galactic plane at the solar circle.
+ * Only the position of the Sun and the orientation are taken from the galactic structure.
+ * https://ui.adsabs.harvard.edu/abs/2001ApJ...556..181D/abstract
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExponentialDustDisk {
+    pub(super) local_extinction: f64,
+    pub(super) scale_length: Length,
+    pub(super) scale_height: Length,
+    pub(super) galactic_structure: GalacticStructure,
+}
+
+// Steps of the integration along the line of sight, per scale height and at most in total.
+const STEPS_PER_SCALE_HEIGHT: f64 = 4.;
+const MAX_INTEGRATION_STEPS: usize = 2_000;
+
+impl ExponentialDustDisk {
+    pub fn new(local_extinction: f64, scale_length: Length, scale_height: Length) -> Self {
+        Self {
+            local_extinction,
+            scale_length,
+            scale_height,
+            galactic_structure: GalacticStructure::milky_way(),
+        }
+    }
+
+    pub fn milky_way() -> Self {
+        Self::new(
+            1.,
+            Length::new::<light_year>(7_300.),
+            Length::new::<light_year>(440.),
+        )
+    }
+
+    pub fn with_galactic_structure(mut self, galactic_structure: GalacticStructure) -> Self {
+        self.galactic_structure = galactic_structure;
+        self
+    }
+
+    pub fn get_local_extinction(&self) -> f64 {
+        self.local_extinction
+    }
+
+    pub fn get_scale_length(&self) -> Length {
+        self.scale_length
+    }
+
+    pub fn get_scale_height(&self) -> Length {
+        self.scale_height
+    }
+
+    // The dust density at the position, relative to that in the galactic plane at the solar circle.
+    pub fn relative_density(&self, pos: &Cartesian) -> f64 {
+        let structure = &self.galactic_structure;
+        let radius = structure.distance_to_axis(pos) - structure.get_sun_distance_to_center();
+        let height = structure.height_above_plane(pos).abs();
+        (-(radius / self.scale_length).value - (height / self.scale_height).value).exp()
+    }
+}
+
+impl Default for ExponentialDustDisk {
+    fn default() -> Self {
+        Self::milky_way()
+    }
+}
+
+// Simpson's rule along the line of sight.
+impl DustMap for ExponentialDustDisk {
+    fn visual_extinction(&self, from: &Cartesian, to: &Cartesian) -> f64 {
+        let path = to - from;
+        let length = path.length();
+        let steps = ((length / self.scale_height).value * STEPS_PER_SCALE_HEIGHT).ceil() as usize;
+        let steps = steps.clamp(1, MAX_INTEGRATION_STEPS / 2) * 2;
+        let density =
+            |i: usize| self.relative_density(&(from + &(&path * (i as f64 / steps as f64))));
+        let mut sum = density(0) + density(steps);
+        for i in 1..steps {
+            let weight = if i % 2 == 1 { 4. } else { 2. };
+            sum += weight * density(i);
+        }
+        let integral_in_kiloparsec = sum / steps as f64 / 3. * length.get::<parsec>() / 1_000.;
+        self.local_extinction * integral_in_kiloparsec
+    }
+}
+
+/*
+ * Dust maps cannot be serialized in general, but are shared between the threads that generate stars.
+ * Two of them are only considered equal if they are the same instance.
+ */
+#[derive(Clone)]
+pub struct SharedDustMap(Arc<dyn DustMap>);
+
+impl SharedDustMap {
+    pub fn new<T: DustMap + 'static>(dust_map: T) -> Self {
+        Self(Arc::new(dust_map))
+    }
+
+    pub fn get(&self) -> &dyn DustMap {
+        self.0.as_ref()
+    }
+}
+
+impl Debug for SharedDustMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq for SharedDustMap {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+pub fn color_excess(visual_extinction: f64) -> f64 {
+    visual_extinction / TOTAL_TO_SELECTIVE_EXTINCTION
+}
+
+// The fraction of the light that passes through the dust.
+pub fn transmission(extinction: f64) -> f64 {
+    10f64.powf(-0.4 * extinction)
+}
+
+/*
+ * The extinction at a wavelength relative to that in the V band, from the infrared to the near ultraviolet.
+ * https://ui.adsabs.harvard.edu/abs/1989ApJ...345..245C/abstract
+ */
+pub fn relative_extinction(wavelength: Length) -> f64 {
+    let x = 1. / wavelength.get::<micrometer>();
+    let (a, b) = if x < 1.1 {
+        (0.574 * x.powf(1.61), -0.527 * x.powf(1.61))
+    } else {
+        let y = x - 1.82;
+        let a = 1. + 0.17699 * y - 0.50447 * y.powi(2) - 0.02427 * y.powi(3)
+            + 0.72085 * y.powi(4)
+            + 0.01979 * y.powi(5)
+            - 0.77530 * y.powi(6)
+            + 0.32999 * y.powi(7);
+        let b = 1.41338 * y + 2.28305 * y.powi(2) + 1.07233 * y.powi(3)
+            - 5.38434 * y.powi(4)
+            - 0.62251 * y.powi(5)
+            + 5.30260 * y.powi(6)
+            - 2.09002 * y.powi(7);
+        (a, b)
+    };
+    a + b / TOTAL_TO_SELECTIVE_EXTINCTION
+}
+
+pub fn extinction_in_band(band: PhotometricBand, visual_extinction: f64) -> f64 {
+    relative_extinction(effective_wavelength(band)) * visual_extinction
+}
+
+/*
+ * Each channel of a linear sRGB color is dimmed at the dominant wavelength of its primary.
+ * https://en.wikipedia.org/wiki/SRGB
+ */
+pub fn redden(color: &sRGBColor, visual_extinction: f64) -> sRGBColor {
+    let factors = [612., 549., 465.].map(|nanometers| {
+        let wavelength = Length::new::<micrometer>(nanometers / 1_000.);
+        transmission(relative_extinction(wavelength) * visual_extinction)
+    });
+    color.scaled_per_channel(factors)
+}
+
+// https://en.wikipedia.org/wiki/Photometric_system
+fn effective_wavelength(band: PhotometricBand) -> Length {
+    let micrometers = match band {
+        PhotometricBand::U => 0.365,
+        PhotometricBand::B => 0.445,
+        PhotometricBand::V => 0.551,
+        PhotometricBand::G => 0.622,
+        PhotometricBand::BP => 0.511,
+        PhotometricBand::RP => 0.777,
+        PhotometricBand::J => 1.22,
+        PhotometricBand::H => 1.63,
+        PhotometricBand::K => 2.19,
+    };
+    Length::new::<micrometer>(micrometers)
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_coords::direction::Direction;
+
+    use super::*;
+    use crate::tests::eq_within;
+
+    fn towards(direction: &Direction, distance: f64) -> Cartesian {
+        direction.to_cartesian(Length::new::<light_year>(distance))
+    }
+
+    #[test]
+    fn extinction_grows_along_the_plane_but_not_out_of_it() {
+        let dust = ExponentialDustDisk::milky_way();
+        let structure = GalacticStructure::milky_way();
+        let sun = Cartesian::origin();
+        let center = structure.get_galactic_center_direction();
+        let pole = structure.get_north_galactic_pole();
+
+        let one_kiloparsec = dust.visual_extinction(&sun, &towards(center, 3_262.));
+        assert!((0.8..1.5).contains(&one_kiloparsec));
+        let ten_kiloparsec = dust.visual_extinction(&sun, &towards(center, 32_616.));
+        assert!(ten_kiloparsec > 10. * one_kiloparsec);
+
+        let above = dust.visual_extinction(&sun, &towards(pole, 3_000.));
+        let far_above = dust.visual_extinction(&sun, &towards(pole, 30_000.));
+        assert!(above < 0.2);
+        assert!(eq_within(above, far_above, 0.01));
+        assert_eq!(dust.visual_extinction(&sun, &sun), 0.);
+    }
+
+    #[test]
+    fn extinction_is_symmetric_along_the_line_of_sight() {
+        let dust = ExponentialDustDisk::milky_way();
+        let a = towards(&Direction::X, 1_000.);
+        let b = towards(&Direction::Z, -2_000.);
+        assert!(eq_within(
+            dust.visual_extinction(&a, &b),
+            dust.visual_extinction(&b, &a),
+            1e-9
+        ));
+        assert!(eq_within(
+            dust.color_excess(&a, &b),
+            dust.visual_extinction(&a, &b) / 3.1,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn extinction_is_strongest_in_the_blue() {
+        assert!(eq_within(
+            extinction_in_band(PhotometricBand::V, 1.),
+            1.,
+            0.01
+        ));
+        let b_minus_v = extinction_in_band(PhotometricBand::B, 1.) - 1.;
+        assert!(eq_within(b_minus_v, color_excess(1.), 0.03));
+        assert!(extinction_in_band(PhotometricBand::U, 1.) > 1.4);
+        assert!(extinction_in_band(PhotometricBand::K, 1.) < 0.15);
+    }
+
+    #[test]
+    fn dust_reddens_white_light() {
+        let white = sRGBColor::from_sRGB(1., 1., 1.);
+        let (r, g, b) = redden(&white, 2.).maximized_sRGB_tuple();
+        assert_eq!(r, 1.);
+        assert!(g < r);
+        assert!(b < g);
+        assert_eq!(redden(&white, 0.), white);
+    }
+}
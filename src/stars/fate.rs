@@ -1,7 +1,6 @@
 use std::ops::Range;
 
 use astro_units::{
-    length::solar_radius,
     luminous_intensity::{
         absolute_magnitude_to_luminous_intensity, luminous_intensity_to_absolute_magnitude,
     },
@@ -11,57 +10,157 @@ use astro_units::{
 use serde::{Deserialize, Serialize};
 use uom::si::{
     f64::{Length, LuminousIntensity, Mass, ThermodynamicTemperature, Time},
-    length::kilometer,
+    length::meter,
     thermodynamic_temperature::kelvin,
     time::day,
 };
 
 use crate::astro_display::AstroDisplay;
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+use super::{random::metallicity::SOLAR_METALLICITY, remnant::Remnant};
+
+/*
+ * How a star ends its life, and what it leaves behind.
+ * Stars below 8 solar masses shed their envelope as a planetary nebula and leave a white dwarf.
+ * Heavier stars collapse, leaving a neutron star or, above about 25 solar masses, a black hole.
+ * Metal poor stars of 140 to 260 solar masses are disrupted entirely by pair instability,
+ * and white dwarfs that accrete from a companion explode as Type Ia supernovae without a remnant.
+ * https://en.wikipedia.org/wiki/Stellar_evolution#Stellar_remnants
+ * https://ui.adsabs.harvard.edu/abs/2003ApJ...591..288H/abstract
+ */
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum StarFate {
-    WhiteDwarf,
+    #[serde(alias = "WhiteDwarf")]
+    PlanetaryNebula,
+    TypeIISupernova(Remnant),
+    PairInstabilitySupernova,
+    TypeIaSupernova,
+}
+
+/*
+ * Earlier versions stored a Type II supernova without its remnant,
+ * which then followed from the mass of the star when it was needed.
+ */
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(super) enum StoredStarFate {
+    Fate(StarFate),
+    Legacy(LegacyStarFate),
+}
+
+#[derive(Deserialize)]
+pub(super) enum LegacyStarFate {
     TypeIISupernova,
 }
 
+const MAX_WHITE_DWARF_PROGENITOR_MASS: f64 = 8.;
+const MAX_NEUTRON_STAR_PROGENITOR_MASS: f64 = 25.;
+const PAIR_INSTABILITY_PROGENITOR_MASSES: Range<f64> = 140.0..260.0;
+// Above this mass fraction, winds strip the star before its core becomes unstable.
+// https://ui.adsabs.harvard.edu/abs/2007A%26A...475L..19L/abstract
+const MAX_PAIR_INSTABILITY_METALLICITY: f64 = SOLAR_METALLICITY / 3.;
+
 impl StarFate {
-    pub(crate) fn new(initial_mass: Mass) -> Self {
-        if initial_mass < Mass::new::<solar_mass>(8.) {
-            StarFate::WhiteDwarf
+    // Without a known metallicity, stars are assumed to be solar.
+    pub(crate) fn new(initial_mass: Mass, metallicity: Option<f64>) -> Self {
+        let mass = initial_mass.get::<solar_mass>();
+        let metallicity = metallicity.unwrap_or(SOLAR_METALLICITY);
+        if mass < MAX_WHITE_DWARF_PROGENITOR_MASS {
+            StarFate::PlanetaryNebula
+        } else if PAIR_INSTABILITY_PROGENITOR_MASSES.contains(&mass)
+            && metallicity < MAX_PAIR_INSTABILITY_METALLICITY
+        {
+            StarFate::PairInstabilitySupernova
         } else {
-            StarFate::TypeIISupernova
+            StarFate::TypeIISupernova(Self::remnant_of_core_collapse(Some(initial_mass)))
         }
     }
 
-    pub(crate) fn apply_to_mass(&self, mass: Mass) -> Mass {
-        match self {
-            StarFate::WhiteDwarf => 0.3 * mass, // rough estimate after shedding outer layers
-            StarFate::TypeIISupernova => {
-                if mass < Mass::new::<solar_mass>(25.) {
-                    Mass::new::<solar_mass>(1.4) // Typical Neutron Star
-                } else {
-                    Mass::new::<solar_mass>(7.0) // Typical Black Hole
-                }
+    pub(super) fn remnant_of_core_collapse(mass: Option<Mass>) -> Remnant {
+        match mass {
+            Some(mass) if mass.get::<solar_mass>() >= MAX_NEUTRON_STAR_PROGENITOR_MASS => {
+                Remnant::BlackHole
             }
+            _ => Remnant::NeutronStar,
         }
     }
 
-    pub(crate) fn apply_to_radius(&self) -> Length {
+    // A white dwarf that accretes from a close companion eventually exceeds the Chandrasekhar mass.
+    pub fn in_accreting_binary(&self) -> Self {
         match self {
-            StarFate::WhiteDwarf => Length::new::<solar_radius>(0.0084), // Sirius B
-            StarFate::TypeIISupernova => Length::new::<kilometer>(20.), // Neutron star or black hole
+            StarFate::PlanetaryNebula => StarFate::TypeIaSupernova,
+            other => *other,
         }
     }
 
+    pub fn get_remnant(&self) -> Option<Remnant> {
+        match self {
+            StarFate::PlanetaryNebula => Some(Remnant::WhiteDwarf),
+            StarFate::TypeIISupernova(remnant) => Some(*remnant),
+            StarFate::PairInstabilitySupernova | StarFate::TypeIaSupernova => None,
+        }
+    }
+
+    pub fn is_supernova(&self) -> bool {
+        !matches!(self, StarFate::PlanetaryNebula)
+    }
+
+    // The brightest a star of this mass can become when it dies, if it explodes at all.
+    pub(crate) fn most_luminous_death(initial_mass: Mass) -> Option<LuminousIntensity> {
+        let mass = initial_mass.get::<solar_mass>();
+        if mass >= PAIR_INSTABILITY_PROGENITOR_MASSES.start {
+            Some(absolute_magnitude_to_luminous_intensity(
+                PAIR_INSTABILITY_SUPERNOVA_PEAK_MAGNITUDE,
+            ))
+        } else if mass >= MAX_WHITE_DWARF_PROGENITOR_MASS {
+            Some(absolute_magnitude_to_luminous_intensity(
+                TYPE_II_SUPERNOVA_PEAK_MAGNITUDE,
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn apply_to_mass(&self, mass: Mass) -> Mass {
+        match self.get_remnant() {
+            Some(remnant) => remnant.mass(Some(mass)),
+            None => Mass::new::<solar_mass>(0.),
+        }
+    }
+
+    pub(crate) fn apply_to_radius(&self, mass: Option<Mass>) -> Length {
+        match self.get_remnant() {
+            Some(remnant) => remnant.radius(mass),
+            None => Length::new::<meter>(0.),
+        }
+    }
+
+    /*
+     * The mass of the star before its death determines the mass of its remnant, and thus how it cools.
+     * A remnant that outshines the fading supernova takes over its appearance.
+     */
     pub(crate) fn apply_to_luminous_intensity(
         &self,
         luminous_intensity: LuminousIntensity,
+        mass: Option<Mass>,
         time_since_death: Time,
     ) -> LuminousIntensity {
         match self {
-            StarFate::WhiteDwarf => absolute_magnitude_to_luminous_intensity(11.18), // Sirius B
-            StarFate::TypeIISupernova => {
-                type_2_supernova_luminous_intensity(luminous_intensity, time_since_death)
+            StarFate::PlanetaryNebula => {
+                planetary_nebula_luminous_intensity(luminous_intensity, mass, time_since_death)
+            }
+            StarFate::TypeIISupernova(remnant) => {
+                let supernova =
+                    type_2_supernova_luminous_intensity(luminous_intensity, time_since_death);
+                match remnant.luminous_intensity(mass, time_since_death) {
+                    Some(remnant) if time_since_death.value > 0. => supernova.max(remnant),
+                    _ => supernova,
+                }
+            }
+            StarFate::PairInstabilitySupernova => PAIR_INSTABILITY_LIGHT_CURVE
+                .luminous_intensity(luminous_intensity, time_since_death),
+            StarFate::TypeIaSupernova => {
+                TYPE_IA_LIGHT_CURVE.luminous_intensity(luminous_intensity, time_since_death)
             }
         }
     }
@@ -69,21 +168,191 @@ impl StarFate {
     pub(crate) fn apply_to_temperature(
         &self,
         temperature: ThermodynamicTemperature,
+        luminous_intensity: LuminousIntensity,
+        mass: Option<Mass>,
         time_since_death: Time,
     ) -> ThermodynamicTemperature {
         match self {
-            StarFate::WhiteDwarf => ThermodynamicTemperature::new::<kelvin>(25_000.), // Sirius B
-            StarFate::TypeIISupernova => {
-                type_2_supernova_temperature(temperature, time_since_death)
+            StarFate::PlanetaryNebula => {
+                planetary_nebula_temperature(temperature, mass, time_since_death)
+            }
+            StarFate::TypeIISupernova(remnant) => {
+                let supernova =
+                    type_2_supernova_luminous_intensity(luminous_intensity, time_since_death);
+                let outshining_remnant = remnant
+                    .luminous_intensity(mass, time_since_death)
+                    .filter(|remnant| time_since_death.value > 0. && *remnant > supernova)
+                    .and_then(|_| remnant.temperature(mass, time_since_death));
+                match outshining_remnant {
+                    Some(temperature) => temperature,
+                    None => type_2_supernova_temperature(temperature, time_since_death),
+                }
+            }
+            StarFate::PairInstabilitySupernova => {
+                PAIR_INSTABILITY_LIGHT_CURVE.temperature(temperature, time_since_death)
             }
+            StarFate::TypeIaSupernova => {
+                TYPE_IA_LIGHT_CURVE.temperature(temperature, time_since_death)
+            }
+        }
+    }
+}
+
+/*
+ * The envelope is ejected within a few thousand years, exposing a hot core that ionises it.
+ * The core then fades onto the white dwarf cooling track while the nebula disperses.
+ * https://en.wikipedia.org/wiki/Planetary_nebula#Life_cycle
+ */
+const PLANETARY_NEBULA_HEATING_IN_KILOYEARS: f64 = 1.;
+const PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS: f64 = 25.;
+
+fn planetary_nebula_luminous_intensity(
+    initial: LuminousIntensity,
+    mass: Option<Mass>,
+    time_since_death: Time,
+) -> LuminousIntensity {
+    let kiloyears = time_since_death.get::<kiloyear>();
+    if kiloyears < 0. {
+        return initial;
+    }
+    let white_dwarf = |kiloyears: f64| {
+        Remnant::WhiteDwarf
+            .luminous_intensity(mass, Time::new::<kiloyear>(kiloyears))
+            .unwrap_or(initial)
+    };
+    if kiloyears >= PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS {
+        return white_dwarf(kiloyears);
+    }
+    let offset = luminous_intensity_to_absolute_magnitude(initial);
+    let end = luminous_intensity_to_absolute_magnitude(white_dwarf(
+        PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS,
+    ));
+    let slope = (end - offset) / PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS;
+    absolute_magnitude_to_luminous_intensity(offset + slope * kiloyears)
+}
+
+fn planetary_nebula_temperature(
+    initial: ThermodynamicTemperature,
+    mass: Option<Mass>,
+    time_since_death: Time,
+) -> ThermodynamicTemperature {
+    let kiloyears = time_since_death.get::<kiloyear>();
+    if kiloyears < 0. {
+        return initial;
+    }
+    let white_dwarf = |kiloyears: f64| {
+        Remnant::WhiteDwarf
+            .temperature(mass, Time::new::<kiloyear>(kiloyears))
+            .unwrap_or(initial)
+    };
+    let exposed_core = white_dwarf(kiloyears.max(PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS));
+    if kiloyears < PLANETARY_NEBULA_HEATING_IN_KILOYEARS {
+        let offset = initial.get::<kelvin>();
+        let slope = (exposed_core.get::<kelvin>() - offset) / PLANETARY_NEBULA_HEATING_IN_KILOYEARS;
+        ThermodynamicTemperature::new::<kelvin>(offset + slope * kiloyears)
+    } else {
+        exposed_core
+    }
+}
+
+/*
+ * A light curve that rises linearly in magnitude to its peak, declines quickly to a tail,
+ * and then fades at the rate of its radioactive decay.
+ */
+struct LightCurve {
+    rise_days: f64,
+    peak_magnitude: f64,
+    decline_days: f64,
+    tail_magnitude: f64,
+    tail_magnitudes_per_day: f64,
+    peak_temperature: f64,
+    tail_temperature: f64,
+}
+
+/*
+ * Powered by the decay of nickel and cobalt, Type Ia supernovae are standard candles.
+ * https://en.wikipedia.org/wiki/Type_Ia_supernova#Light_curve
+ * https://ui.adsabs.harvard.edu/abs/1993ApJ...413L.105P/abstract
+ */
+const TYPE_IA_LIGHT_CURVE: LightCurve = LightCurve {
+    rise_days: 19.,
+    peak_magnitude: -19.3,
+    decline_days: 15.,
+    tail_magnitude: -18.2,
+    tail_magnitudes_per_day: 0.02,
+    peak_temperature: 15_000.,
+    tail_temperature: 6_000.,
+};
+
+/*
+ * Pair instability supernovae synthesise several solar masses of nickel,
+ * so they rise slowly to an extreme peak and fade with the cobalt decay rate.
+ * https://en.wikipedia.org/wiki/Pair-instability_supernova
+ * https://ui.adsabs.harvard.edu/abs/2011ApJ...734..102K/abstract
+ */
+const PAIR_INSTABILITY_LIGHT_CURVE: LightCurve = LightCurve {
+    rise_days: 120.,
+    peak_magnitude: PAIR_INSTABILITY_SUPERNOVA_PEAK_MAGNITUDE,
+    decline_days: 100.,
+    tail_magnitude: -20.5,
+    tail_magnitudes_per_day: 0.0098,
+    peak_temperature: 12_000.,
+    tail_temperature: 5_000.,
+};
+
+const PAIR_INSTABILITY_SUPERNOVA_PEAK_MAGNITUDE: f64 = -21.5;
+
+impl LightCurve {
+    fn luminous_intensity(
+        &self,
+        initial: LuminousIntensity,
+        time_since_death: Time,
+    ) -> LuminousIntensity {
+        let days = time_since_death.get::<day>();
+        if days < 0. {
+            return initial;
         }
+        let magnitude = if days < self.rise_days {
+            let offset = luminous_intensity_to_absolute_magnitude(initial);
+            offset + (self.peak_magnitude - offset) * days / self.rise_days
+        } else if days < self.rise_days + self.decline_days {
+            let progress = (days - self.rise_days) / self.decline_days;
+            self.peak_magnitude + (self.tail_magnitude - self.peak_magnitude) * progress
+        } else {
+            let tail_days = days - self.rise_days - self.decline_days;
+            self.tail_magnitude + self.tail_magnitudes_per_day * tail_days
+        };
+        absolute_magnitude_to_luminous_intensity(magnitude)
+    }
+
+    // Without a remnant, the ejecta cool until they vanish after ten thousand years.
+    fn temperature(
+        &self,
+        initial: ThermodynamicTemperature,
+        time_since_death: Time,
+    ) -> ThermodynamicTemperature {
+        let days = time_since_death.get::<day>();
+        let t = if days < 0. {
+            initial.get::<kelvin>()
+        } else if days < self.rise_days {
+            let offset = initial.get::<kelvin>();
+            offset + (self.peak_temperature - offset) * days / self.rise_days
+        } else if days < self.rise_days + self.decline_days {
+            let progress = (days - self.rise_days) / self.decline_days;
+            self.peak_temperature + (self.tail_temperature - self.peak_temperature) * progress
+        } else {
+            let tail_days = days - self.rise_days - self.decline_days;
+            let slope = -self.tail_temperature / Time::new::<kiloyear>(10.).get::<day>();
+            (self.tail_temperature + slope * tail_days).max(0.)
+        };
+        ThermodynamicTemperature::new::<kelvin>(t)
     }
 }
 
 const SN_PHASE_1_INCREASE: Range<f64> = 0.0..10.0;
 const SN_PHASE_2_DECREASE: Range<f64> = 10.0..20.0;
 const SN_PHASE_3_PLATEAU: Range<f64> = 20.0..110.0;
-pub(crate) const TYPE_II_SUPERNOVA_PEAK_MAGNITUDE: f64 = -16.8;
+const TYPE_II_SUPERNOVA_PEAK_MAGNITUDE: f64 = -16.8;
 
 fn type_2_supernova_luminous_intensity(
     initial: LuminousIntensity,
@@ -151,11 +420,28 @@ fn type_2_supernova_temperature(
     ThermodynamicTemperature::new::<kelvin>(t)
 }
 
+impl StoredStarFate {
+    pub(super) fn resolve(self, mass: Option<Mass>) -> StarFate {
+        match self {
+            StoredStarFate::Fate(fate) => fate,
+            StoredStarFate::Legacy(LegacyStarFate::TypeIISupernova) => {
+                StarFate::TypeIISupernova(StarFate::remnant_of_core_collapse(mass))
+            }
+        }
+    }
+}
+
 impl AstroDisplay for StarFate {
     fn astro_display(&self) -> String {
         match self {
-            StarFate::WhiteDwarf => "White Dwarf".to_string(),
-            StarFate::TypeIISupernova => "Type II Supernova".to_string(),
+            StarFate::PlanetaryNebula => "Planetary Nebula, leaving a White Dwarf".to_string(),
+            StarFate::TypeIISupernova(remnant) => {
+                format!("Type II Supernova, leaving a {}", remnant.astro_display())
+            }
+            StarFate::PairInstabilitySupernova => {
+                "Pair-Instability Supernova, leaving no remnant".to_string()
+            }
+            StarFate::TypeIaSupernova => "Type Ia Supernova, leaving no remnant".to_string(),
         }
     }
 }
@@ -163,9 +449,10 @@ impl AstroDisplay for StarFate {
 #[cfg(test)]
 mod tests {
     use astro_units::luminous_intensity::solar_luminous_intensity;
-    use uom::si::luminous_intensity::candela;
+    use uom::si::{length::kilometer, luminous_intensity::candela};
 
     use super::*;
+    use crate::stars::remnant::CHANDRASEKHAR_MASS;
 
     #[test]
     fn type_2_supernova_luminous_intensity_is_smooth() {
@@ -356,4 +643,145 @@ mod tests {
             last = current;
         }
     }
+
+    fn solar_masses(mass: f64) -> Mass {
+        Mass::new::<solar_mass>(mass)
+    }
+
+    #[test]
+    fn fates_follow_the_initial_mass() {
+        assert_eq!(
+            StarFate::new(solar_masses(1.), None),
+            StarFate::PlanetaryNebula
+        );
+        assert_eq!(
+            StarFate::new(solar_masses(15.), None),
+            StarFate::TypeIISupernova(Remnant::NeutronStar)
+        );
+        assert_eq!(
+            StarFate::new(solar_masses(40.), None),
+            StarFate::TypeIISupernova(Remnant::BlackHole)
+        );
+        assert_eq!(
+            StarFate::new(solar_masses(200.), None),
+            StarFate::TypeIISupernova(Remnant::BlackHole)
+        );
+        assert_eq!(
+            StarFate::new(solar_masses(200.), Some(SOLAR_METALLICITY / 10.)),
+            StarFate::PairInstabilitySupernova
+        );
+    }
+
+    #[test]
+    fn only_white_dwarfs_in_binaries_become_type_ia_supernovae() {
+        assert_eq!(
+            StarFate::PlanetaryNebula.in_accreting_binary(),
+            StarFate::TypeIaSupernova
+        );
+        let core_collapse = StarFate::TypeIISupernova(Remnant::NeutronStar);
+        assert_eq!(core_collapse.in_accreting_binary(), core_collapse);
+    }
+
+    #[test]
+    fn remnants_have_distinct_sizes() {
+        let white_dwarf = StarFate::PlanetaryNebula.apply_to_radius(Some(solar_masses(1.)));
+        let neutron_star = StarFate::TypeIISupernova(Remnant::NeutronStar)
+            .apply_to_radius(Some(solar_masses(15.)));
+        let black_hole =
+            StarFate::TypeIISupernova(Remnant::BlackHole).apply_to_radius(Some(solar_masses(40.)));
+        assert!(white_dwarf > black_hole);
+        assert!(black_hole > neutron_star);
+        // 12 solar masses have an event horizon of about 35 km.
+        assert!((black_hole.get::<kilometer>() - 35.4).abs() < 0.5);
+        let exploded = StarFate::TypeIaSupernova.apply_to_radius(Some(solar_masses(1.)));
+        assert_eq!(exploded.value, 0.);
+    }
+
+    #[test]
+    fn white_dwarf_masses_follow_the_initial_final_mass_relation() {
+        let sun = StarFate::PlanetaryNebula.apply_to_mass(solar_masses(1.));
+        assert!((sun.get::<solar_mass>() - 0.57).abs() < 0.01);
+        let heavy = StarFate::PlanetaryNebula.apply_to_mass(solar_masses(7.));
+        assert!(heavy > sun);
+        assert!(heavy.get::<solar_mass>() <= CHANDRASEKHAR_MASS);
+    }
+
+    #[test]
+    fn type_ia_supernova_peaks_as_a_standard_candle() {
+        let initial = absolute_magnitude_to_luminous_intensity(11.18);
+        let peak = TYPE_IA_LIGHT_CURVE
+            .luminous_intensity(initial, Time::new::<day>(TYPE_IA_LIGHT_CURVE.rise_days));
+        assert!((luminous_intensity_to_absolute_magnitude(peak) + 19.3).abs() < 1e-6);
+        let later = TYPE_IA_LIGHT_CURVE.luminous_intensity(initial, Time::new::<day>(100.));
+        assert!(later < peak);
+    }
+
+    #[test]
+    fn pair_instability_supernova_outshines_core_collapse() {
+        let initial = 1e6 * solar_luminous_intensity();
+        let fate = StarFate::PairInstabilitySupernova;
+        let pair_instability =
+            fate.apply_to_luminous_intensity(initial, None, Time::new::<day>(120.));
+        let core_collapse = StarFate::TypeIISupernova(Remnant::BlackHole)
+            .apply_to_luminous_intensity(initial, None, Time::new::<day>(10.));
+        assert!(pair_instability > core_collapse);
+    }
+
+    #[test]
+    fn planetary_nebula_fades_into_a_white_dwarf() {
+        let initial = 1000. * solar_luminous_intensity();
+        let initial_temperature = ThermodynamicTemperature::new::<kelvin>(3_500.);
+        let mass = Some(solar_masses(2.));
+        let fate = StarFate::PlanetaryNebula;
+        let hot = fate.apply_to_temperature(
+            initial_temperature,
+            initial,
+            mass,
+            Time::new::<kiloyear>(1.),
+        );
+        assert!(hot.get::<kelvin>() > 50_000.);
+
+        let mut last_luminous_intensity = fate.apply_to_luminous_intensity(
+            initial,
+            mass,
+            Time::new::<kiloyear>(PLANETARY_NEBULA_LIFETIME_IN_KILOYEARS),
+        );
+        let mut last_temperature = hot;
+        for kiloyears in [30., 100., 1e3, 1e4, 1e5, 1e6] {
+            let time = Time::new::<kiloyear>(kiloyears);
+            let luminous_intensity = fate.apply_to_luminous_intensity(initial, mass, time);
+            let temperature = fate.apply_to_temperature(initial_temperature, initial, mass, time);
+            assert!(luminous_intensity < last_luminous_intensity);
+            assert!(temperature < last_temperature);
+            last_luminous_intensity = luminous_intensity;
+            last_temperature = temperature;
+        }
+    }
+
+    #[test]
+    fn neutron_star_remains_after_its_supernova() {
+        let initial = 1e4 * solar_luminous_intensity();
+        let initial_temperature = ThermodynamicTemperature::new::<kelvin>(20_000.);
+        let fate = StarFate::TypeIISupernova(Remnant::NeutronStar);
+        let mass = Some(solar_masses(15.));
+        let time = Time::new::<kiloyear>(10.);
+        let temperature = fate.apply_to_temperature(initial_temperature, initial, mass, time);
+        let expected = Remnant::NeutronStar.temperature(mass, time).unwrap();
+        assert_eq!(temperature, expected);
+        let luminous_intensity = fate.apply_to_luminous_intensity(initial, mass, time);
+        assert!(luminous_intensity.value > 0.);
+        assert!(luminous_intensity < initial * 1e-6);
+
+        let black_hole = StarFate::TypeIISupernova(Remnant::BlackHole);
+        let darkness = black_hole.apply_to_luminous_intensity(initial, mass, time);
+        assert!(darkness < luminous_intensity);
+    }
+
+    #[test]
+    fn fates_are_displayed_with_their_remnant() {
+        assert_eq!(
+            StarFate::TypeIISupernova(Remnant::BlackHole).astro_display(),
+            "Type II Supernova, leaving a Black Hole"
+        );
+    }
 }
@@ -18,7 +18,14 @@ use uom::si::{
     time::year,
 };
 
-use crate::{color::srgb::sRGBColor, error::AstroUtilError, stars::appearance::StarAppearance};
+use crate::{
+    color::srgb::sRGBColor,
+    error::AstroUtilError,
+    stars::{
+        appearance::StarAppearance,
+        photometry::{band_minus_v, g_minus_v_from_bp_minus_rp, PhotometricBand},
+    },
+};
 
 fn get_designation(map: &HashMap<Col, GaiaCellData>) -> Option<String> {
     get_string(map.get(&Col::designation)?)
@@ -39,9 +46,24 @@ fn get_temperature(map: &HashMap<Col, GaiaCellData>) -> Option<ThermodynamicTemp
     Some(ThermodynamicTemperature::new::<kelvin>(temperature))
 }
 
-fn get_illuminance(map: &HashMap<Col, GaiaCellData>) -> Option<Illuminance> {
-    let mag = get_float(map.get(&Col::phot_g_mean_mag)?)?;
-    Some(apparent_magnitude_to_illuminance(mag))
+fn get_bp_minus_rp(map: &HashMap<Col, GaiaCellData>) -> Option<f64> {
+    get_float(map.get(&Col::bp_rp)?)
+}
+
+/*
+ * Gaia measures the broad G band, while illuminances refer to the visual V band.
+ * The Gaia color gives the difference best, and the temperature serves where it is missing.
+ */
+fn get_illuminance(
+    map: &HashMap<Col, GaiaCellData>,
+    temperature: ThermodynamicTemperature,
+) -> Option<Illuminance> {
+    let g_mag = get_float(map.get(&Col::phot_g_mean_mag)?)?;
+    let g_minus_v = match get_bp_minus_rp(map) {
+        Some(bp_minus_rp) => g_minus_v_from_bp_minus_rp(bp_minus_rp),
+        None => band_minus_v(PhotometricBand::G, temperature),
+    };
+    Some(apparent_magnitude_to_illuminance(g_mag - g_minus_v))
 }
 
 fn to_star_appearances(result: GaiaResult<Col>) -> Result<Vec<StarAppearance>, AstroUtilError> {
@@ -51,10 +73,10 @@ fn to_star_appearances(result: GaiaResult<Col>) -> Result<Vec<StarAppearance>, A
         .map(|map| {
             let name =
                 get_designation(map).ok_or(AstroUtilError::DataNotAvailable("name".to_string()))?;
-            let illuminance = get_illuminance(map)
-                .ok_or(AstroUtilError::DataNotAvailable("illuminance".to_string()))?;
             let temperature =
                 get_temperature(map).unwrap_or(ThermodynamicTemperature::new::<kelvin>(4000.));
+            let illuminance = get_illuminance(map, temperature)
+                .ok_or(AstroUtilError::DataNotAvailable("illuminance".to_string()))?;
             let color = sRGBColor::from_temperature(temperature);
             let lon =
                 get_ecl_lon(map).ok_or(AstroUtilError::DataNotAvailable("lon".to_string()))?;
@@ -75,6 +97,7 @@ fn to_star_appearances(result: GaiaResult<Col>) -> Result<Vec<StarAppearance>, A
     stars
 }
 
+// Gaia selects by G magnitude, so the limits refer to the G band.
 fn query_stars_by_brightness(
     brightest_mag: f64,
     dimmest_mag: f64,
@@ -85,6 +108,7 @@ fn query_stars_by_brightness(
             Col::ecl_lon,
             Col::ecl_lat,
             Col::phot_g_mean_mag,
+            Col::bp_rp,
             Col::teff_gspphot,
         ])
         .where_clause(GaiaCondition::GreaterThanOrEqual(
@@ -16,16 +16,20 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::collections::HashMap;
 use uom::si::{
     angle::degree,
-    f64::{Angle, Length, LuminousIntensity, Mass, ThermodynamicTemperature, Time},
+    f64::{Angle, Length, LuminousIntensity, Mass, ThermodynamicTemperature, Time, Velocity},
     length::{light_year, parsec},
     thermodynamic_temperature::kelvin,
+    velocity::kilometer_per_second,
 };
 
 use crate::{
     error::AstroUtilError,
+    kinematics::velocity_vector::VelocityVector,
     stars::{
-        data::StarData, evolution::StarDataEvolution, physical_parameters::StarPhysicalParameters,
+        data::StarData, evolution::StarDataEvolution, motion::StarMotion,
+        physical_parameters::StarPhysicalParameters,
     },
+    units::angular_velocity::angular_velocity_from_mas_per_year,
 };
 
 fn get_id(map: &HashMap<Col, GaiaCellData>) -> Option<String> {
@@ -53,28 +57,62 @@ fn get_luminous_intensity(map: &HashMap<Col, GaiaCellData>) -> Option<LuminousIn
     Some(absolute_magnitude_to_luminous_intensity(mag))
 }
 
-fn get_pos(map: &HashMap<Col, GaiaCellData>) -> Option<Cartesian> {
+fn get_ra(map: &HashMap<Col, GaiaCellData>) -> Option<Angle> {
     let ra = get_float(map.get(&Col::ra)?)?;
-    let ra = Angle::new::<degree>(ra);
+    Some(Angle::new::<degree>(ra))
+}
+
+fn get_dec(map: &HashMap<Col, GaiaCellData>) -> Option<Angle> {
     let dec = get_float(map.get(&Col::dec)?)?;
-    let dec = Angle::new::<degree>(dec);
+    Some(Angle::new::<degree>(dec))
+}
+
+fn get_distance(map: &HashMap<Col, GaiaCellData>) -> Option<Length> {
     let distance = get_float(map.get(&Col::barycentric_distance)?)?;
-    let distance = Length::new::<parsec>(distance);
-    let pos = EarthEquatorial::new(ra, dec)
+    Some(Length::new::<parsec>(distance))
+}
+
+fn get_pos(map: &HashMap<Col, GaiaCellData>) -> Option<Cartesian> {
+    let pos = EarthEquatorial::new(get_ra(map)?, get_dec(map)?)
         .to_direction()
-        .to_cartesian(distance);
+        .to_cartesian(get_distance(map)?);
     Some(pos)
 }
 
+fn get_motion(map: &HashMap<Col, GaiaCellData>) -> Option<StarMotion> {
+    let pmra = get_float(map.get(&Col::pmra)?)?;
+    let pmdec = get_float(map.get(&Col::pmdec)?)?;
+    let radial_velocity = map
+        .get(&Col::radial_velocity)
+        .and_then(get_float)
+        .unwrap_or(0.);
+    Some(StarMotion::new(
+        angular_velocity_from_mas_per_year(pmra),
+        angular_velocity_from_mas_per_year(pmdec),
+        Velocity::new::<kilometer_per_second>(radial_velocity),
+    ))
+}
+
+fn get_velocity(map: &HashMap<Col, GaiaCellData>) -> Option<VelocityVector> {
+    let motion = get_motion(map)?;
+    let velocity = motion.to_velocity(get_ra(map)?, get_dec(map)?, get_distance(map)?);
+    Some(velocity)
+}
+
 fn get_age(map: &HashMap<Col, GaiaCellData>) -> Option<Time> {
     let age = get_float(map.get(&Col::age)?)?;
     Some(Time::new::<gigayear>(age))
 }
 
 fn get_evolution(map: &HashMap<Col, GaiaCellData>) -> Option<StarDataEvolution> {
-    let age = get_age(map)?;
     let mass = get_mass(map)?;
-    Some(StarDataEvolution::from_age_and_mass(age, mass))
+    Some(StarDataEvolution::from_catalogue(
+        mass,
+        None,
+        get_age(map),
+        get_luminous_intensity(map)?,
+        get_temperature(map)?,
+    ))
 }
 
 pub(crate) fn to_star_data(result: GaiaResult<Col>) -> Result<Vec<StarData>, AstroUtilError> {
@@ -95,14 +133,18 @@ pub(crate) fn to_star_data(result: GaiaResult<Col>) -> Result<Vec<StarData>, Ast
                 StarPhysicalParameters::new(mass, radius, luminous_intensity, temperature);
 
             let pos = get_pos(map).ok_or(AstroUtilError::DataNotAvailable("pos".to_string()))?;
+            let velocity = get_velocity(map).unwrap_or_default();
             let evolution = get_evolution(map)
                 .ok_or(AstroUtilError::DataNotAvailable("evolution".to_string()))?;
             let star = StarData {
                 name,
                 params: physical_parameters,
                 pos,
+                velocity,
                 constellation: None,
                 evolution,
+                metallicity: None,
+                variability: None,
             };
             Ok(star)
         })
@@ -124,6 +166,9 @@ pub(crate) fn query_nearest_simulated_stars(
             Col::ra,
             Col::dec,
             Col::barycentric_distance,
+            Col::pmra,
+            Col::pmdec,
+            Col::radial_velocity,
             Col::age,
         ])
         .where_clause(GaiaCondition::LessThan(
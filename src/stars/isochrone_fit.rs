@@ -0,0 +1,183 @@
+use astro_units::luminous_intensity::solar_luminous_intensity;
+use serde::{Deserialize, Serialize};
+use uom::si::f64::{LuminousIntensity, Mass, ThermodynamicTemperature, Time};
+
+use super::random::{
+    metallicity::{metallicity_grid_index, DEFAULT_METALLICITY},
+    parsec::interpolation::InterpolatedTrack,
+};
+
+/*
+ * How well a star's temperature and luminosity agree with the PARSEC track of its mass at a given age.
+ * The deviations are measured in units of typical catalogue uncertainties and summed in quadrature,
+ * so a chi squared above 9 means that the star lies more than three sigma away from its track.
+ * This flags catalogue entries whose mass, age, temperature and luminosity do not fit together.
+ * https://en.wikipedia.org/wiki/Isochron_dating#Stellar_isochrones
+ * https://en.wikipedia.org/wiki/Reduced_chi-squared_statistic
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct IsochroneFit {
+    pub(super) age: Time,
+    pub(super) chi_squared: f64,
+    #[serde(default)]
+    pub(super) rejected_age: Option<Time>, // A catalogued age that the fitted one replaces
+}
+
+const LOG_LUMINOSITY_UNCERTAINTY: f64 = 0.2;
+const LOG_TEMPERATURE_UNCERTAINTY: f64 = 0.03;
+const MAX_PLAUSIBLE_CHI_SQUARED: f64 = 9.;
+const AGE_STEPS: usize = 1_000;
+
+impl IsochroneFit {
+    pub fn get_age(&self) -> Time {
+        self.age
+    }
+
+    pub fn get_chi_squared(&self) -> f64 {
+        self.chi_squared
+    }
+
+    pub fn get_rejected_age(&self) -> Option<Time> {
+        self.rejected_age
+    }
+
+    // A catalogued age beyond the lifetime of the track makes the catalogue entry implausible as well.
+    pub fn is_plausible(&self) -> bool {
+        self.chi_squared <= MAX_PLAUSIBLE_CHI_SQUARED && self.rejected_age.is_none()
+    }
+
+    pub(super) fn with_rejected_age(mut self, rejected_age: Time) -> Self {
+        self.rejected_age = Some(rejected_age);
+        self
+    }
+}
+
+// Without a known metallicity, stars are assumed to be solar.
+pub(crate) fn parsec_lifetime(mass: Mass, metallicity: Option<f64>) -> Time {
+    track(mass, metallicity).lifetime
+}
+
+/*
+ * The age along the track of the given mass that best reproduces the observed temperature and luminosity.
+ * Only ages before the end of the track are considered, since the star is still observed.
+ */
+pub(crate) fn fit_age(
+    mass: Mass,
+    metallicity: Option<f64>,
+    luminous_intensity: LuminousIntensity,
+    temperature: ThermodynamicTemperature,
+) -> IsochroneFit {
+    let track = track(mass, metallicity);
+    (0..AGE_STEPS)
+        .map(|step| {
+            let age = track.lifetime * (step as f64 / AGE_STEPS as f64);
+            evaluate(&track, age, luminous_intensity, temperature)
+        })
+        .fold(None, |best: Option<IsochroneFit>, fit| match best {
+            Some(best) if best.chi_squared <= fit.chi_squared => Some(best),
+            _ => Some(fit),
+        })
+        .unwrap_or(IsochroneFit {
+            age: Time::default(),
+            chi_squared: f64::INFINITY,
+            rejected_age: None,
+        })
+}
+
+// The quality of a catalogued age.
+pub(crate) fn evaluate_age(
+    mass: Mass,
+    metallicity: Option<f64>,
+    age: Time,
+    luminous_intensity: LuminousIntensity,
+    temperature: ThermodynamicTemperature,
+) -> IsochroneFit {
+    evaluate(
+        &track(mass, metallicity),
+        age,
+        luminous_intensity,
+        temperature,
+    )
+}
+
+fn track(mass: Mass, metallicity: Option<f64>) -> InterpolatedTrack {
+    let metallicity_index = metallicity_grid_index(metallicity.unwrap_or(DEFAULT_METALLICITY));
+    InterpolatedTrack::new(metallicity_index, mass)
+}
+
+fn evaluate(
+    track: &InterpolatedTrack,
+    age: Time,
+    luminous_intensity: LuminousIntensity,
+    temperature: ThermodynamicTemperature,
+) -> IsochroneFit {
+    let line = track.at_age(age);
+    let observed_luminosity = (luminous_intensity / solar_luminous_intensity()).value;
+    let luminosity_deviation = (observed_luminosity.log10() - line.luminosity_in_solar.log10())
+        / LOG_LUMINOSITY_UNCERTAINTY;
+    let temperature_deviation =
+        (temperature.value.log10() - line.temperature.value.log10()) / LOG_TEMPERATURE_UNCERTAINTY;
+    let mut chi_squared = luminosity_deviation.powi(2) + temperature_deviation.powi(2);
+    if chi_squared.is_nan() {
+        chi_squared = f64::INFINITY;
+    }
+    IsochroneFit {
+        age,
+        chi_squared,
+        rejected_age: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_units::mass::solar_mass;
+    use uom::si::time::year;
+
+    use super::*;
+
+    #[test]
+    fn lifetime_decreases_with_mass() {
+        assert!(parsec_access::getters::is_data_ready());
+        let masses = [0.5, 1., 2., 5., 20.];
+        let lifetimes: Vec<Time> = masses
+            .iter()
+            .map(|m| parsec_lifetime(Mass::new::<solar_mass>(*m), None))
+            .collect();
+        assert!(lifetimes.windows(2).all(|w| w[0] > w[1]));
+        assert!(lifetimes.iter().all(|l| l.value > 0.));
+    }
+
+    #[test]
+    fn fitted_age_of_a_star_on_its_track_is_recovered() {
+        assert!(parsec_access::getters::is_data_ready());
+        let mass = Mass::new::<solar_mass>(1.5);
+        let track = track(mass, None);
+        for fraction in [0.2, 0.5, 0.95] {
+            let age = track.lifetime * fraction;
+            let line = track.at_age(age);
+            let fit = fit_age(
+                mass,
+                None,
+                line.luminosity_in_solar * solar_luminous_intensity(),
+                line.temperature,
+            );
+            assert!(fit.is_plausible());
+            assert!(fit.get_chi_squared() < 0.1);
+            assert!(((fit.get_age() - age) / track.lifetime).value.abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn star_far_from_its_track_is_implausible() {
+        assert!(parsec_access::getters::is_data_ready());
+        let mass = Mass::new::<solar_mass>(1.);
+        let line = track(mass, None).at_age(Time::new::<year>(1e9));
+        let fit = fit_age(
+            mass,
+            None,
+            line.luminosity_in_solar * 1000. * solar_luminous_intensity(),
+            line.temperature,
+        );
+        assert!(!fit.is_plausible());
+    }
+}
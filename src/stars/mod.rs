@@ -2,8 +2,16 @@ pub mod appearance;
 pub mod constellation;
 pub mod data;
 pub mod evolution;
+pub mod extinction;
 pub mod fate;
 pub mod gaia;
+pub mod isochrone_fit;
+pub mod motion;
+pub mod multiple;
+pub mod photometry;
 pub mod physical_parameters;
 pub mod random;
 pub mod real_data;
+pub mod remnant;
+pub mod spectral_type;
+pub mod variability;
@@ -0,0 +1,125 @@
+use astro_coords::earth_equatorial::EarthEquatorial;
+use astro_units::angle::quarter_circ;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    angle::radian,
+    angular_velocity::radian_per_second,
+    f64::{Angle, AngularVelocity, Length, Velocity},
+    length::meter,
+    velocity::meter_per_second,
+};
+
+use crate::kinematics::velocity_vector::VelocityVector;
+
+/*
+ * The motion of a star as it is catalogued: proper motion in right ascension (already multiplied by cos(dec))
+ * and declination, plus the radial velocity along the line of sight.
+ * https://en.wikipedia.org/wiki/Proper_motion
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StarMotion {
+    pub proper_motion_ra: AngularVelocity,
+    pub proper_motion_dec: AngularVelocity,
+    pub radial_velocity: Velocity,
+}
+
+impl StarMotion {
+    pub const fn new(
+        proper_motion_ra: AngularVelocity,
+        proper_motion_dec: AngularVelocity,
+        radial_velocity: Velocity,
+    ) -> Self {
+        Self {
+            proper_motion_ra,
+            proper_motion_dec,
+            radial_velocity,
+        }
+    }
+
+    pub fn to_velocity(&self, ra: Angle, dec: Angle, distance: Length) -> VelocityVector {
+        let radial = EarthEquatorial::new(ra, dec).to_direction();
+        let east =
+            EarthEquatorial::new(ra + quarter_circ(), Angle::new::<radian>(0.)).to_direction();
+        let north = EarthEquatorial::new(ra, dec + quarter_circ()).to_direction();
+
+        let radial = VelocityVector::from_direction(&radial, self.radial_velocity);
+        let east = VelocityVector::from_direction(
+            &east,
+            tangential_velocity(self.proper_motion_ra, distance),
+        );
+        let north = VelocityVector::from_direction(
+            &north,
+            tangential_velocity(self.proper_motion_dec, distance),
+        );
+        &(&radial + &east) + &north
+    }
+}
+
+fn tangential_velocity(proper_motion: AngularVelocity, distance: Length) -> Velocity {
+    Velocity::new::<meter_per_second>(
+        proper_motion.get::<radian_per_second>() * distance.get::<meter>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_coords::cartesian::Cartesian;
+    use uom::si::{
+        angle::{degree, second},
+        f64::Time,
+        length::light_year,
+        time::year,
+        velocity::kilometer_per_second,
+    };
+
+    use crate::{tests::eq_within, units::angular_velocity::angular_velocity_from_mas_per_year};
+
+    use super::*;
+
+    #[test]
+    fn purely_radial_motion_changes_only_distance() {
+        let ra = Angle::new::<degree>(101.);
+        let dec = Angle::new::<degree>(-16.);
+        let distance = Length::new::<light_year>(10.);
+        let motion = StarMotion::new(
+            angular_velocity_from_mas_per_year(0.),
+            angular_velocity_from_mas_per_year(0.),
+            Velocity::new::<kilometer_per_second>(100.),
+        );
+        let velocity = motion.to_velocity(ra, dec, distance);
+        let pos = EarthEquatorial::new(ra, dec)
+            .to_direction()
+            .to_cartesian(distance);
+        let time = Time::new::<year>(10_000.);
+        let moved: Cartesian = &pos + &velocity.displacement(time);
+        let expected = distance + Velocity::new::<kilometer_per_second>(100.) * time;
+        assert!(eq_within(
+            moved.length().get::<light_year>(),
+            expected.get::<light_year>(),
+            1e-6
+        ));
+        assert!(pos.angle_to(&moved).unwrap().get::<second>() < 0.1);
+    }
+
+    #[test]
+    fn proper_motion_moves_star_across_the_sky() {
+        let ra = Angle::new::<degree>(213.9);
+        let dec = Angle::new::<degree>(19.2);
+        let distance = Length::new::<light_year>(36.7);
+        let motion = StarMotion::new(
+            angular_velocity_from_mas_per_year(-1093.39),
+            angular_velocity_from_mas_per_year(-2000.06),
+            Velocity::new::<kilometer_per_second>(0.),
+        );
+        let velocity = motion.to_velocity(ra, dec, distance);
+        let pos = EarthEquatorial::new(ra, dec)
+            .to_direction()
+            .to_cartesian(distance);
+        let time = Time::new::<year>(100.);
+        let moved: Cartesian = &pos + &velocity.displacement(time);
+        let total_proper_motion = (1093.39f64.powi(2) + 2000.06f64.powi(2)).sqrt() / 1000.;
+        let expected = total_proper_motion * time.get::<year>();
+        let actual = pos.angle_to(&moved).unwrap().get::<second>();
+        assert!(eq_within(actual, expected, 1e-2 * expected));
+    }
+}
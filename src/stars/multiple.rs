@@ -0,0 +1,442 @@
+use astro_coords::cartesian::Cartesian;
+use astro_units::illuminance::lux;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    angle::second,
+    f64::{Angle, Length, Mass, Time},
+    length::astronomical_unit,
+};
+
+use super::{appearance::StarAppearance, data::StarData, extinction::DustMap};
+use crate::{
+    error::AstroUtilError,
+    planets::{kepler_orbit::orbital_period, orbit_parameters::OrbitParameters},
+};
+
+/*
+ * A star with one or more companions bound to it, like Sirius, Alpha Centauri or Algol.
+ * The position and velocity of the primary are those of the barycenter of the whole system.
+ * Every companion follows a Kepler orbit relative to the primary, with the orbital plane given in ecliptic coordinates.
+ * For triples and higher multiples each companion is treated as a separate two body problem,
+ * which is a decent approximation for the hierarchical systems found in nature.
+ * https://en.wikipedia.org/wiki/Binary_star
+ * https://en.wikipedia.org/wiki/Star_system#Hierarchical_systems
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipleStar {
+    pub(super) primary: StarData,
+    pub(super) companions: Vec<Companion>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Companion {
+    pub(super) star: StarData,
+    pub(super) orbit: OrbitParameters,
+}
+
+/*
+ * Below this separation the white dwarf left by the heavier star accretes from its companion once that swells to a giant.
+ * https://en.wikipedia.org/wiki/Type_Ia_supernova#Single_degenerate_progenitors
+ */
+const MAX_ACCRETING_SEPARATION_IN_AU: f64 = 10.;
+
+// The angular resolution of the naked eye.
+// https://en.wikipedia.org/wiki/Naked_eye#Basic_accuracies
+pub const NAKED_EYE_RESOLUTION_IN_ARCSECONDS: f64 = 60.;
+
+type PlacedAppearance = (StarAppearance, Cartesian);
+
+impl MultipleStar {
+    pub fn new(primary: StarData) -> Self {
+        Self {
+            primary,
+            companions: Vec::new(),
+        }
+    }
+
+    /*
+     * The heavier star of a close pair dies first.
+     * If it leaves a white dwarf, the companion feeds it until it explodes as a Type Ia supernova.
+     */
+    pub fn with_companion(mut self, star: StarData, orbit: OrbitParameters) -> Self {
+        let mut companion = Companion { star, orbit };
+        companion.star.pos = self.primary.pos.clone();
+        companion.star.velocity = self.primary.velocity;
+        let max_accreting_separation =
+            Length::new::<astronomical_unit>(MAX_ACCRETING_SEPARATION_IN_AU);
+        if companion.orbit.get_semi_major_axis() < max_accreting_separation {
+            let companion_is_heavier = match (self.primary.params.mass, companion.star.params.mass)
+            {
+                (Some(primary_mass), Some(companion_mass)) => companion_mass > primary_mass,
+                _ => false,
+            };
+            let heavier = if companion_is_heavier {
+                &mut companion.star.evolution
+            } else {
+                &mut self.primary.evolution
+            };
+            heavier.fate = heavier.fate.in_accreting_binary();
+        }
+        self.companions.push(companion);
+        self
+    }
+
+    pub fn get_primary(&self) -> &StarData {
+        &self.primary
+    }
+
+    pub fn get_companions(&self) -> &Vec<Companion> {
+        &self.companions
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.companions.is_empty()
+    }
+
+    pub fn get_name(&self) -> &String {
+        self.primary.get_name()
+    }
+
+    pub fn get_barycenter(&self, time: Time) -> Cartesian {
+        self.primary.get_pos(time)
+    }
+
+    pub fn get_total_mass(&self, time: Time) -> Result<Mass, AstroUtilError> {
+        let mut total = mass_of(&self.primary, time)?;
+        for companion in &self.companions {
+            total += mass_of(&companion.star, time)?;
+        }
+        Ok(total)
+    }
+
+    // The primary comes first, followed by the companions in the order they were added.
+    pub fn get_component_positions(&self, time: Time) -> Result<Vec<Cartesian>, AstroUtilError> {
+        let primary_mass = mass_of(&self.primary, time)?;
+        let mut relative_positions = Vec::new();
+        let mut weighted_offset = Cartesian::origin();
+        for companion in &self.companions {
+            let mass = mass_of(&companion.star, time)?;
+            let relative = companion
+                .orbit
+                .calculate_position(mass, primary_mass, time)?;
+            weighted_offset = &weighted_offset + &(&relative * mass.value);
+            relative_positions.push(relative);
+        }
+        let total_mass = self.get_total_mass(time)?;
+        let primary_pos = &self.get_barycenter(time) - &(weighted_offset / total_mass.value);
+        let companion_positions = relative_positions
+            .iter()
+            .map(|relative| &primary_pos + relative);
+        Ok(std::iter::once(primary_pos.clone())
+            .chain(companion_positions)
+            .collect())
+    }
+
+    pub fn to_combined_star_appearance(
+        &self,
+        time_since_epoch: Time,
+    ) -> Result<StarAppearance, AstroUtilError> {
+        let (primary, companions) =
+            self.component_appearances(None, &Cartesian::origin(), time_since_epoch)?;
+        Ok(combine(primary, companions))
+    }
+
+    pub fn to_combined_star_appearance_through_dust(
+        &self,
+        dust_map: &dyn DustMap,
+        observer_position: &Cartesian,
+        time_since_epoch: Time,
+    ) -> Result<StarAppearance, AstroUtilError> {
+        let (primary, companions) =
+            self.component_appearances(Some(dust_map), observer_position, time_since_epoch)?;
+        Ok(combine(primary, companions))
+    }
+
+    pub fn to_star_appearances(
+        &self,
+        time_since_epoch: Time,
+        resolution: Angle,
+    ) -> Result<Vec<StarAppearance>, AstroUtilError> {
+        let (primary, companions) =
+            self.component_appearances(None, &Cartesian::origin(), time_since_epoch)?;
+        resolve(primary, companions, resolution)
+    }
+
+    pub fn to_star_appearances_through_dust(
+        &self,
+        dust_map: &dyn DustMap,
+        observer_position: &Cartesian,
+        time_since_epoch: Time,
+        resolution: Angle,
+    ) -> Result<Vec<StarAppearance>, AstroUtilError> {
+        let (primary, companions) =
+            self.component_appearances(Some(dust_map), observer_position, time_since_epoch)?;
+        resolve(primary, companions, resolution)
+    }
+
+    pub fn to_naked_eye_star_appearances(
+        &self,
+        time_since_epoch: Time,
+    ) -> Result<Vec<StarAppearance>, AstroUtilError> {
+        let resolution = Angle::new::<second>(NAKED_EYE_RESOLUTION_IN_ARCSECONDS);
+        self.to_star_appearances(time_since_epoch, resolution)
+    }
+
+    // The positions of the components are taken relative to the observer.
+    fn component_appearances(
+        &self,
+        dust_map: Option<&dyn DustMap>,
+        observer_position: &Cartesian,
+        time_since_epoch: Time,
+    ) -> Result<(PlacedAppearance, Vec<PlacedAppearance>), AstroUtilError> {
+        let barycenter = self.get_barycenter(time_since_epoch);
+        // The components are too close to each other to be seen through different dust.
+        let visual_extinction = dust_map
+            .map(|dust_map| dust_map.visual_extinction(observer_position, &barycenter))
+            .unwrap_or(0.);
+        let place = |star: &StarData, pos: &Cartesian| {
+            let pos = pos - observer_position;
+            let appearance = star
+                .to_star_appearance_at(&pos, time_since_epoch)
+                .with_extinction(visual_extinction);
+            (appearance, pos)
+        };
+        let mut positions = self.get_component_positions(time_since_epoch)?.into_iter();
+        let primary_pos = positions.next().unwrap_or(barycenter);
+        let primary = place(&self.primary, &primary_pos);
+        let companions = self
+            .companions
+            .iter()
+            .zip(positions)
+            .map(|(companion, pos)| place(&companion.star, &pos))
+            .collect();
+        Ok((primary, companions))
+    }
+}
+
+impl Companion {
+    pub fn get_star(&self) -> &StarData {
+        &self.star
+    }
+
+    pub fn get_orbit(&self) -> &OrbitParameters {
+        &self.orbit
+    }
+
+    pub fn get_orbital_period(&self, primary: &StarData) -> Result<Time, AstroUtilError> {
+        let time = self.orbit.get_epoch();
+        Ok(orbital_period(
+            self.orbit.get_semi_major_axis(),
+            mass_of(primary, time)?,
+            mass_of(&self.star, time)?,
+        ))
+    }
+}
+
+fn mass_of(star: &StarData, time: Time) -> Result<Mass, AstroUtilError> {
+    star.get_mass(time)
+        .ok_or(AstroUtilError::DataNotAvailable(format!(
+            "Mass of {}",
+            star.get_name()
+        )))
+}
+
+/*
+ * Companions closer to the primary than the given resolution merge with it into one point of light.
+ * Its illuminance is the sum, its color the illuminance weighted mean and its position the photocenter.
+ * Companions further away appear as stars of their own.
+ */
+fn resolve(
+    primary: PlacedAppearance,
+    companions: Vec<PlacedAppearance>,
+    resolution: Angle,
+) -> Result<Vec<StarAppearance>, AstroUtilError> {
+    let mut unresolved = Vec::new();
+    let mut resolved = Vec::new();
+    for (appearance, pos) in companions {
+        if primary.1.angle_to(&pos)? < resolution {
+            unresolved.push((appearance, pos));
+        } else {
+            resolved.push(appearance);
+        }
+    }
+    let mut appearances = vec![combine(primary, unresolved)];
+    appearances.append(&mut resolved);
+    Ok(appearances)
+}
+
+fn combine(primary: PlacedAppearance, companions: Vec<PlacedAppearance>) -> StarAppearance {
+    let (mut combined, primary_pos) = primary;
+    let mut weight = combined.illuminance.get::<lux>();
+    let mut color = &combined.color * weight;
+    let mut photocenter = &primary_pos * weight;
+    for (appearance, pos) in companions {
+        let illuminance = appearance.illuminance.get::<lux>();
+        color = &color + &(&appearance.color * illuminance);
+        photocenter = &photocenter + &(&pos * illuminance);
+        combined.illuminance += appearance.illuminance;
+        weight += illuminance;
+    }
+    if weight > 0. {
+        combined.color = &color * (1. / weight);
+        if let Ok(pos) = photocenter.to_ecliptic() {
+            combined.pos = pos;
+        }
+    }
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_coords::direction::Direction;
+    use astro_units::{
+        illuminance::Illuminance, luminous_intensity::solar_luminous_intensity, mass::solar_mass,
+    };
+    use uom::si::{
+        angle::degree, f64::ThermodynamicTemperature, length::light_year,
+        thermodynamic_temperature::kelvin, time::year,
+    };
+
+    use super::*;
+    use crate::{
+        stars::{
+            evolution::StarDataEvolution, extinction::ExponentialDustDisk, fate::StarFate,
+            physical_parameters::StarPhysicalParameters,
+        },
+        tests::eq_within,
+    };
+
+    fn star(name: &str, mass: f64, luminosity: f64, temperature: f64) -> StarData {
+        let params = StarPhysicalParameters::new(
+            Some(Mass::new::<solar_mass>(mass)),
+            None,
+            luminosity * solar_luminous_intensity(),
+            ThermodynamicTemperature::new::<kelvin>(temperature),
+        );
+        StarData::new(
+            name.to_string(),
+            None,
+            params,
+            Direction::X.to_cartesian(Length::new::<light_year>(8.6)),
+            StarDataEvolution::none(),
+        )
+    }
+
+    // https://en.wikipedia.org/wiki/Sirius#Sirius_B
+    fn sirius() -> MultipleStar {
+        let orbit = OrbitParameters::new(
+            Length::new::<astronomical_unit>(19.8),
+            0.59,
+            Angle::new::<degree>(136.3),
+            Angle::new::<degree>(45.4),
+            Angle::new::<degree>(149.2),
+            Angle::new::<degree>(0.),
+            Time::new::<year>(0.),
+        );
+        MultipleStar::new(star("Sirius A", 2.06, 25.4, 9_940.))
+            .with_companion(star("Sirius B", 1.02, 0.056, 25_000.), orbit)
+    }
+
+    #[test]
+    fn sirius_b_orbits_in_50_years() {
+        let sirius = sirius();
+        let period = sirius.companions[0]
+            .get_orbital_period(&sirius.primary)
+            .unwrap();
+        assert!(eq_within(period.get::<year>(), 50.1, 0.5));
+    }
+
+    #[test]
+    fn barycenter_stays_in_place() {
+        let sirius = sirius();
+        let masses = [2.06, 1.02];
+        for years in [0., 10., 25., 40.] {
+            let time = Time::new::<year>(years);
+            let positions = sirius.get_component_positions(time).unwrap();
+            let weighted = &(&positions[0] * masses[0]) + &(&positions[1] * masses[1]);
+            let barycenter = weighted * (1. / (masses[0] + masses[1]));
+            let offset = &barycenter - &sirius.get_barycenter(time);
+            assert!(offset.length() < Length::new::<astronomical_unit>(1e-6));
+        }
+    }
+
+    #[test]
+    fn sirius_b_is_resolved_only_with_a_telescope() {
+        let sirius = sirius();
+        let time = Time::new::<year>(0.);
+        let naked_eye = sirius.to_naked_eye_star_appearances(time).unwrap();
+        assert_eq!(naked_eye.len(), 1);
+        let telescope = sirius
+            .to_star_appearances(time, Angle::new::<second>(0.1))
+            .unwrap();
+        assert_eq!(telescope.len(), 2);
+        assert_eq!(telescope[1].get_name(), "Sirius B");
+
+        let combined = naked_eye[0].get_illuminance();
+        let summed = telescope[0].get_illuminance() + telescope[1].get_illuminance();
+        assert!(eq_within(
+            combined.get::<lux>(),
+            summed.get::<lux>(),
+            1e-6 * summed.get::<lux>()
+        ));
+        assert!(combined > Illuminance::new::<lux>(0.));
+    }
+
+    #[test]
+    fn components_are_seen_through_the_same_dust() {
+        let sirius = sirius();
+        let time = Time::new::<year>(0.);
+        let resolution = Angle::new::<second>(0.1);
+        let dust = ExponentialDustDisk::new(
+            1_000.,
+            Length::new::<light_year>(7_300.),
+            Length::new::<light_year>(440.),
+        );
+        let origin = Cartesian::origin();
+        let clear = sirius.to_star_appearances(time, resolution).unwrap();
+        let dusty = sirius
+            .to_star_appearances_through_dust(&dust, &origin, time, resolution)
+            .unwrap();
+        assert_eq!(dusty.len(), 2);
+        assert!(dusty[0].get_illuminance() < clear[0].get_illuminance() * 0.5);
+        let clear_ratio = clear[1].get_illuminance() / clear[0].get_illuminance();
+        let dusty_ratio = dusty[1].get_illuminance() / dusty[0].get_illuminance();
+        assert!(eq_within(clear_ratio.value, dusty_ratio.value, 1e-9));
+
+        let nearby = &sirius.get_barycenter(time) * 0.5;
+        let combined = sirius
+            .to_combined_star_appearance_through_dust(&dust, &nearby, time)
+            .unwrap();
+        let from_origin = sirius
+            .to_combined_star_appearance_through_dust(&dust, &origin, time)
+            .unwrap();
+        assert!(combined.get_illuminance() > from_origin.get_illuminance() * 4.);
+    }
+
+    #[test]
+    fn close_white_dwarf_progenitors_become_type_ia_supernovae() {
+        let close_orbit = OrbitParameters::new(
+            Length::new::<astronomical_unit>(1.),
+            0.,
+            Angle::new::<degree>(0.),
+            Angle::new::<degree>(0.),
+            Angle::new::<degree>(0.),
+            Angle::new::<degree>(0.),
+            Time::new::<year>(0.),
+        );
+        let mut wide_orbit = close_orbit.clone();
+        wide_orbit.semi_major_axis = Length::new::<astronomical_unit>(1_000.);
+
+        let close = MultipleStar::new(star("A", 3., 100., 12_000.))
+            .with_companion(star("B", 1., 1., 5_800.), close_orbit);
+        assert_eq!(close.primary.get_fate(), &StarFate::TypeIaSupernova);
+        assert_eq!(
+            close.companions[0].star.get_fate(),
+            &StarFate::PlanetaryNebula
+        );
+
+        let wide = MultipleStar::new(star("A", 3., 100., 12_000.))
+            .with_companion(star("B", 1., 1., 5_800.), wide_orbit);
+        assert_eq!(wide.primary.get_fate(), &StarFate::PlanetaryNebula);
+    }
+}
@@ -0,0 +1,374 @@
+use std::f64::consts::PI;
+
+use astro_units::luminous_intensity::{
+    absolute_magnitude_to_luminous_intensity, luminous_intensity_to_absolute_magnitude,
+};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{Length, LuminousIntensity, Power, ThermodynamicTemperature},
+    length::meter,
+    power::watt,
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::astro_display::AstroDisplay;
+
+/*
+ * The photometric bands in which catalogues report magnitudes.
+ * A luminous intensity is always understood as the brightness in the Johnson V band,
+ * and the other bands follow from the colors of a star of the given temperature.
+ * https://en.wikipedia.org/wiki/Photometric_system
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PhotometricBand {
+    U,
+    B,
+    V,
+    G,
+    BP,
+    RP,
+    J,
+    H,
+    K,
+}
+
+// https://en.wikipedia.org/wiki/Bolometric_correction
+const SOLAR_BOLOMETRIC_MAGNITUDE: f64 = 4.74;
+pub(super) const SOLAR_LUMINOSITY_IN_WATTS: f64 = 3.828e26;
+const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.670_374_419e-8;
+
+/*
+ * The mean colors and bolometric corrections of dwarf stars along the temperature sequence,
+ * from O5 to M9. Giants of the same temperature have similar colors, so the sequence is used for all stars.
+ * The 2MASS K band is the short K.
+ * https://ui.adsabs.harvard.edu/abs/2013ApJS..208....9P/abstract
+ * https://www.pas.rochester.edu/~emamajek/EEM_dwarf_UBVIJHK_colors_Teff.txt
+ */
+struct ColorSequenceEntry {
+    temperature: f64,
+    bolometric_correction: f64,
+    u_minus_b: f64,
+    b_minus_v: f64,
+    bp_minus_rp: f64,
+    g_minus_rp: f64,
+    v_minus_k: f64,
+    j_minus_h: f64,
+    h_minus_k: f64,
+}
+
+const fn entry(values: [f64; 9]) -> ColorSequenceEntry {
+    ColorSequenceEntry {
+        temperature: values[0],
+        bolometric_correction: values[1],
+        u_minus_b: values[2],
+        b_minus_v: values[3],
+        bp_minus_rp: values[4],
+        g_minus_rp: values[5],
+        v_minus_k: values[6],
+        j_minus_h: values[7],
+        h_minus_k: values[8],
+    }
+}
+
+// Sorted by decreasing temperature.
+const COLOR_SEQUENCE: [ColorSequenceEntry; 16] = [
+    entry([
+        41_400., -3.85, -1.17, -0.33, -0.40, -0.20, -0.93, -0.11, -0.09,
+    ]),
+    entry([
+        31_400., -3.16, -1.08, -0.30, -0.35, -0.16, -0.87, -0.12, -0.06,
+    ]),
+    entry([
+        15_700., -1.26, -0.58, -0.16, -0.13, -0.06, -0.42, -0.07, -0.04,
+    ]),
+    entry([9_700., -0.21, -0.01, 0.00, 0.01, 0.00, 0.00, 0.00, 0.00]),
+    entry([8_080., -0.03, 0.10, 0.16, 0.22, 0.12, 0.38, 0.07, 0.02]),
+    entry([7_220., -0.01, 0.03, 0.30, 0.45, 0.25, 0.72, 0.14, 0.03]),
+    entry([6_510., -0.02, -0.02, 0.44, 0.59, 0.33, 1.10, 0.22, 0.04]),
+    entry([5_920., -0.07, 0.06, 0.59, 0.76, 0.43, 1.43, 0.29, 0.05]),
+    entry([5_770., -0.08, 0.14, 0.65, 0.82, 0.46, 1.56, 0.31, 0.06]),
+    entry([5_270., -0.19, 0.44, 0.82, 0.98, 0.54, 1.89, 0.40, 0.08]),
+    entry([4_440., -0.57, 0.98, 1.15, 1.43, 0.75, 2.81, 0.56, 0.13]),
+    entry([3_850., -1.18, 1.22, 1.42, 1.84, 0.92, 3.65, 0.62, 0.19]),
+    entry([3_410., -1.86, 1.24, 1.49, 2.37, 1.12, 4.60, 0.57, 0.24]),
+    entry([3_060., -2.73, 1.30, 1.80, 3.08, 1.31, 5.80, 0.56, 0.29]),
+    entry([2_650., -3.99, 1.70, 2.00, 4.00, 1.50, 7.30, 0.64, 0.37]),
+    entry([2_400., -4.85, 1.80, 2.10, 4.50, 1.60, 8.50, 0.72, 0.43]),
+];
+
+/*
+ * The transformation between Gaia EDR3 G and Johnson V in terms of the Gaia color.
+ * It is only valid for -0.5 < BP-RP < 2.75, so redder and bluer colors are clamped to that range.
+ * https://ui.adsabs.harvard.edu/abs/2021A%26A...649A...3R/abstract
+ */
+const VALID_BP_MINUS_RP: (f64, f64) = (-0.5, 2.75);
+
+pub fn g_minus_v_from_bp_minus_rp(bp_minus_rp: f64) -> f64 {
+    let x = bp_minus_rp.clamp(VALID_BP_MINUS_RP.0, VALID_BP_MINUS_RP.1);
+    -0.027_04 + 0.014_24 * x - 0.215_6 * x.powi(2) + 0.014_26 * x.powi(3)
+}
+
+// The magnitude in the given band minus the V magnitude.
+pub fn band_minus_v(band: PhotometricBand, temperature: ThermodynamicTemperature) -> f64 {
+    let colors = interpolated_colors(temperature);
+    let g_minus_v = g_minus_v_from_bp_minus_rp(colors.bp_minus_rp);
+    let k_minus_v = -colors.v_minus_k;
+    match band {
+        PhotometricBand::U => colors.u_minus_b + colors.b_minus_v,
+        PhotometricBand::B => colors.b_minus_v,
+        PhotometricBand::V => 0.,
+        PhotometricBand::G => g_minus_v,
+        PhotometricBand::BP => g_minus_v - colors.g_minus_rp + colors.bp_minus_rp,
+        PhotometricBand::RP => g_minus_v - colors.g_minus_rp,
+        PhotometricBand::J => k_minus_v + colors.h_minus_k + colors.j_minus_h,
+        PhotometricBand::H => k_minus_v + colors.h_minus_k,
+        PhotometricBand::K => k_minus_v,
+    }
+}
+
+pub fn color_index(
+    band: PhotometricBand,
+    other_band: PhotometricBand,
+    temperature: ThermodynamicTemperature,
+) -> f64 {
+    band_minus_v(band, temperature) - band_minus_v(other_band, temperature)
+}
+
+/*
+ * BC_V = M_bol - M_V
+ * Beyond the hottest stars of the sequence, the V band lies on the Rayleigh-Jeans tail of the spectrum,
+ * where the visual flux only grows linearly with the temperature while the total flux grows with T^4.
+ * https://en.wikipedia.org/wiki/Rayleigh%E2%80%93Jeans_law
+ */
+pub fn bolometric_correction(temperature: ThermodynamicTemperature) -> f64 {
+    let hottest = COLOR_SEQUENCE[0].temperature;
+    let rayleigh_jeans_tail = (temperature.get::<kelvin>() / hottest).max(1.);
+    interpolated_colors(temperature).bolometric_correction - 7.5 * rayleigh_jeans_tail.log10()
+}
+
+pub fn absolute_magnitude_in_band(
+    band: PhotometricBand,
+    luminous_intensity: LuminousIntensity,
+    temperature: ThermodynamicTemperature,
+) -> f64 {
+    luminous_intensity_to_absolute_magnitude(luminous_intensity) + band_minus_v(band, temperature)
+}
+
+// The inverse of the above, for catalogues that report a band other than V.
+pub fn luminous_intensity_from_band(
+    band: PhotometricBand,
+    absolute_magnitude: f64,
+    temperature: ThermodynamicTemperature,
+) -> LuminousIntensity {
+    absolute_magnitude_to_luminous_intensity(absolute_magnitude - band_minus_v(band, temperature))
+}
+
+/*
+ * With a known radius, the Stefan-Boltzmann law gives the total power radiated at all wavelengths.
+ * Otherwise the bolometric correction extends the visual brightness to the full spectrum.
+ * https://en.wikipedia.org/wiki/Stefan%E2%80%93Boltzmann_law
+ */
+pub fn bolometric_luminosity(
+    radius: Option<Length>,
+    temperature: ThermodynamicTemperature,
+    luminous_intensity: LuminousIntensity,
+) -> Power {
+    let watts = match radius {
+        Some(radius) => {
+            4. * PI
+                * radius.get::<meter>().powi(2)
+                * STEFAN_BOLTZMANN_CONSTANT
+                * temperature.get::<kelvin>().powi(4)
+        }
+        None => {
+            let bolometric_magnitude = luminous_intensity_to_absolute_magnitude(luminous_intensity)
+                + bolometric_correction(temperature);
+            SOLAR_LUMINOSITY_IN_WATTS
+                * 10f64.powf(-0.4 * (bolometric_magnitude - SOLAR_BOLOMETRIC_MAGNITUDE))
+        }
+    };
+    Power::new::<watt>(watts)
+}
+
+// The visual brightness of a body that radiates the given power at the given temperature.
+pub fn luminous_intensity_from_bolometric_luminosity(
+    luminosity: Power,
+    temperature: ThermodynamicTemperature,
+) -> LuminousIntensity {
+    let bolometric_magnitude = SOLAR_BOLOMETRIC_MAGNITUDE
+        - 2.5 * (luminosity.get::<watt>() / SOLAR_LUMINOSITY_IN_WATTS).log10();
+    absolute_magnitude_to_luminous_intensity(
+        bolometric_magnitude - bolometric_correction(temperature),
+    )
+}
+
+// The radius of a black body that radiates the given power at the given temperature.
+pub fn radius_from_bolometric_luminosity(
+    luminosity: Power,
+    temperature: ThermodynamicTemperature,
+) -> Length {
+    let area = luminosity.get::<watt>()
+        / (STEFAN_BOLTZMANN_CONSTANT * temperature.get::<kelvin>().powi(4));
+    Length::new::<meter>((area / (4. * PI)).sqrt())
+}
+
+// Linear in log temperature, clamped to the ends of the sequence.
+fn interpolated_colors(temperature: ThermodynamicTemperature) -> ColorSequenceEntry {
+    let first = &COLOR_SEQUENCE[0];
+    let last = &COLOR_SEQUENCE[COLOR_SEQUENCE.len() - 1];
+    let temperature = temperature
+        .get::<kelvin>()
+        .clamp(last.temperature, first.temperature);
+    let upper = COLOR_SEQUENCE
+        .partition_point(|entry| entry.temperature > temperature)
+        .clamp(1, COLOR_SEQUENCE.len() - 1);
+    let (hot, cool) = (&COLOR_SEQUENCE[upper - 1], &COLOR_SEQUENCE[upper]);
+    let weight = (temperature / hot.temperature).ln() / (cool.temperature / hot.temperature).ln();
+    let lerp = |a: f64, b: f64| a + (b - a) * weight;
+    ColorSequenceEntry {
+        temperature,
+        bolometric_correction: lerp(hot.bolometric_correction, cool.bolometric_correction),
+        u_minus_b: lerp(hot.u_minus_b, cool.u_minus_b),
+        b_minus_v: lerp(hot.b_minus_v, cool.b_minus_v),
+        bp_minus_rp: lerp(hot.bp_minus_rp, cool.bp_minus_rp),
+        g_minus_rp: lerp(hot.g_minus_rp, cool.g_minus_rp),
+        v_minus_k: lerp(hot.v_minus_k, cool.v_minus_k),
+        j_minus_h: lerp(hot.j_minus_h, cool.j_minus_h),
+        h_minus_k: lerp(hot.h_minus_k, cool.h_minus_k),
+    }
+}
+
+impl AstroDisplay for PhotometricBand {
+    fn astro_display(&self) -> String {
+        match self {
+            PhotometricBand::U => "U".to_string(),
+            PhotometricBand::B => "B".to_string(),
+            PhotometricBand::V => "V".to_string(),
+            PhotometricBand::G => "G".to_string(),
+            PhotometricBand::BP => "G_BP".to_string(),
+            PhotometricBand::RP => "G_RP".to_string(),
+            PhotometricBand::J => "J".to_string(),
+            PhotometricBand::H => "H".to_string(),
+            PhotometricBand::K => "K_s".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_units::{length::solar_radius, luminous_intensity::solar_luminous_intensity};
+
+    use super::*;
+    use crate::tests::eq_within;
+
+    fn kelvin(temperature: f64) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(temperature)
+    }
+
+    #[test]
+    fn vega_has_vanishing_colors() {
+        let vega = kelvin(9_700.);
+        for band in [
+            PhotometricBand::U,
+            PhotometricBand::B,
+            PhotometricBand::J,
+            PhotometricBand::H,
+            PhotometricBand::K,
+        ] {
+            assert!(band_minus_v(band, vega).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn the_sun_has_its_known_colors() {
+        let sun = kelvin(5_772.);
+        let b_minus_v = color_index(PhotometricBand::B, PhotometricBand::V, sun);
+        let bp_minus_rp = color_index(PhotometricBand::BP, PhotometricBand::RP, sun);
+        let v_minus_k = color_index(PhotometricBand::V, PhotometricBand::K, sun);
+        assert!(eq_within(b_minus_v, 0.65, 0.02));
+        assert!(eq_within(bp_minus_rp, 0.82, 0.02));
+        assert!(eq_within(v_minus_k, 1.56, 0.05));
+        assert!(eq_within(bolometric_correction(sun), -0.08, 0.02));
+    }
+
+    #[test]
+    fn red_stars_are_brighter_in_g_than_in_v() {
+        let red_dwarf = kelvin(3_400.);
+        let g_minus_v = band_minus_v(PhotometricBand::G, red_dwarf);
+        assert!(g_minus_v < -0.5);
+        assert!(band_minus_v(PhotometricBand::G, kelvin(9_700.)).abs() < 0.05);
+    }
+
+    #[test]
+    fn g_minus_v_stays_within_its_valid_colors() {
+        assert_eq!(
+            g_minus_v_from_bp_minus_rp(4.5),
+            g_minus_v_from_bp_minus_rp(2.75)
+        );
+        assert_eq!(
+            g_minus_v_from_bp_minus_rp(-1.),
+            g_minus_v_from_bp_minus_rp(-0.5)
+        );
+        let coolest = band_minus_v(PhotometricBand::G, kelvin(2_400.));
+        assert!((-1.5..-0.8).contains(&coolest));
+    }
+
+    #[test]
+    fn colors_redden_with_decreasing_temperature() {
+        let temperatures = [40_000., 20_000., 10_000., 7_000., 5_000., 3_500., 2_500.];
+        let colors: Vec<f64> = temperatures
+            .iter()
+            .map(|t| color_index(PhotometricBand::B, PhotometricBand::V, kelvin(*t)))
+            .collect();
+        assert!(colors.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn band_conversions_are_invertible() {
+        let temperature = kelvin(4_000.);
+        let luminous_intensity = solar_luminous_intensity() * 3.;
+        for band in [PhotometricBand::U, PhotometricBand::G, PhotometricBand::K] {
+            let magnitude = absolute_magnitude_in_band(band, luminous_intensity, temperature);
+            let back = luminous_intensity_from_band(band, magnitude, temperature);
+            assert!(eq_within((back / luminous_intensity).value, 1., 1e-9));
+        }
+    }
+
+    #[test]
+    fn bolometric_luminosity_of_the_sun() {
+        let sun = kelvin(5_772.);
+        let from_radius = bolometric_luminosity(
+            Some(Length::new::<solar_radius>(1.)),
+            sun,
+            solar_luminous_intensity(),
+        );
+        let from_correction = bolometric_luminosity(None, sun, solar_luminous_intensity());
+        for luminosity in [from_radius, from_correction] {
+            assert!(eq_within(
+                luminosity.get::<watt>() / SOLAR_LUMINOSITY_IN_WATTS,
+                1.,
+                0.05
+            ));
+        }
+    }
+
+    #[test]
+    fn bolometric_luminosity_is_invertible() {
+        for temperature in [3_000., 5_772., 20_000., 1e6] {
+            let luminous_intensity = 3. * solar_luminous_intensity();
+            let luminosity = bolometric_luminosity(None, kelvin(temperature), luminous_intensity);
+            let back =
+                luminous_intensity_from_bolometric_luminosity(luminosity, kelvin(temperature));
+            assert!(eq_within((back / luminous_intensity).value, 1., 1e-9));
+        }
+    }
+
+    #[test]
+    fn hot_stars_radiate_mostly_outside_the_visual() {
+        let hot = bolometric_luminosity(None, kelvin(30_000.), solar_luminous_intensity());
+        let cool = bolometric_luminosity(None, kelvin(3_000.), solar_luminous_intensity());
+        let visual_only = bolometric_luminosity(None, kelvin(7_000.), solar_luminous_intensity());
+        assert!(hot > visual_only * 10.);
+        assert!(cool > visual_only * 10.);
+    }
+}
@@ -0,0 +1,500 @@
+use astro_coords::cartesian::Cartesian;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::Length,
+    length::{light_year, meter},
+};
+
+use crate::{
+    error::AstroUtilError,
+    stars::{data::StarData, random::parsec::mass_distribution::MassIndexDistributions},
+};
+
+use super::{
+    cluster::StarCluster,
+    config::GalaxyGenerationConfig,
+    params::GenerationParams,
+    random_stars::{derive_seed, generate_random_stars_in_volume},
+};
+
+// The position of a cubic cell, counted in cell sizes from the one containing the origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CellIndex {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl CellIndex {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn containing(pos: &Cartesian, cell_size: Length) -> Self {
+        let index = |coordinate: Length| (coordinate / cell_size).value.floor() as i64;
+        Self::new(index(pos.x), index(pos.y), index(pos.z))
+    }
+
+    pub fn lower_corner(&self, cell_size: Length) -> Cartesian {
+        Cartesian::new(
+            cell_size * self.x as f64,
+            cell_size * self.y as f64,
+            cell_size * self.z as f64,
+        )
+    }
+
+    pub fn center(&self, cell_size: Length) -> Cartesian {
+        let half = cell_size / 2.;
+        &self.lower_corner(cell_size) + &Cartesian::new(half, half, half)
+    }
+
+    // Mixes all three coordinates into the seed, so that neighbouring cells are uncorrelated.
+    pub(super) fn seed(&self, galaxy_seed: u64) -> u64 {
+        let seed = derive_seed(galaxy_seed, self.x as u64);
+        let seed = derive_seed(seed, self.y as u64);
+        derive_seed(seed, self.z as u64)
+    }
+
+    fn distance_to(&self, pos: &Cartesian, cell_size: Length) -> Length {
+        let lower = self.lower_corner(cell_size);
+        let outside = |coordinate: Length, lower: Length| {
+            let zero = Length::new::<meter>(0.);
+            (lower - coordinate)
+                .max(zero)
+                .max(coordinate - (lower + cell_size))
+        };
+        let dx = outside(pos.x, lower.x);
+        let dy = outside(pos.y, lower.y);
+        let dz = outside(pos.z, lower.z);
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    fn random_point<R: Rng + ?Sized>(&self, rng: &mut R, cell_size: Length) -> Cartesian {
+        let lower = self.lower_corner(cell_size);
+        let mut coordinate = |lower: Length| lower + cell_size * rng.random::<f64>();
+        let x = coordinate(lower.x);
+        let y = coordinate(lower.y);
+        let z = coordinate(lower.z);
+        Cartesian::new(x, y, z)
+    }
+}
+
+/*
+ * An unbounded galaxy that is split into cubic cells and generated on demand.
+ * Every cell draws its stars from a seed derived from its coordinates, so revisiting a region yields the same stars.
+ *
+ * A cell contains its share of the old stars, and the star forming regions whose centres lie inside it.
+ * The stars of a star forming region belong to the cell of its centre, even if they have wandered further.
+ * Whether a star is visible only decides whether it is returned, never which random numbers are drawn,
+ * so moving the observer reveals or hides stars but never moves them.
+ */
+pub struct ChunkedGalaxy {
+    config: GalaxyGenerationConfig,
+    cell_size: Length,
+    seed: u64,
+    mass_index_distributions: MassIndexDistributions,
+}
+
+impl ChunkedGalaxy {
+    pub fn new(
+        config: GalaxyGenerationConfig,
+        cell_size: Length,
+        seed: u64,
+    ) -> Result<Self, AstroUtilError> {
+        if !parsec_access::getters::is_data_ready() {
+            return Err(AstroUtilError::DataNotAvailable(
+                "Parsec data not ready".to_string(),
+            ));
+        }
+        let mass_index_distributions = MassIndexDistributions::new(
+            &config.metallicity.possible_grid_indices(),
+            config.get_initial_mass_function(),
+        )?;
+        Ok(Self {
+            config,
+            cell_size,
+            seed,
+            mass_index_distributions,
+        })
+    }
+
+    pub fn get_config(&self) -> &GalaxyGenerationConfig {
+        &self.config
+    }
+
+    pub fn get_cell_size(&self) -> Length {
+        self.cell_size
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // All cells that intersect the sphere, in a fixed order.
+    pub fn cells_within(&self, center: &Cartesian, radius: Length) -> Vec<CellIndex> {
+        let offset = Cartesian::new(radius, radius, radius);
+        let lower = CellIndex::containing(&(center - &offset), self.cell_size);
+        let upper = CellIndex::containing(&(center + &offset), self.cell_size);
+        let mut cells = Vec::new();
+        for x in lower.x..=upper.x {
+            for y in lower.y..=upper.y {
+                for z in lower.z..=upper.z {
+                    let cell = CellIndex::new(x, y, z);
+                    if cell.distance_to(center, self.cell_size) <= radius {
+                        cells.push(cell);
+                    }
+                }
+            }
+        }
+        cells
+    }
+
+    // The stars of the cell that are visible from the observer.
+    pub fn generate_cell(
+        &self,
+        cell: &CellIndex,
+        observer: &Cartesian,
+    ) -> Result<Vec<StarData>, AstroUtilError> {
+        let config = self.config.clone().with_observer_position(observer.clone());
+        let old_stars_range = self.old_stars_range(&config);
+        self.generate_cell_with_range(cell, &config, old_stars_range)
+    }
+
+    /*
+     * Lazily generates one cell after the other, each time returning the stars of that cell
+     * that lie within max_distance of the observer and are visible from there.
+     */
+    pub fn stream_visible_stars<'a>(
+        &'a self,
+        observer: &'a Cartesian,
+        max_distance: Length,
+    ) -> impl Iterator<Item = Result<Vec<StarData>, AstroUtilError>> + 'a {
+        let config = self.config.clone().with_observer_position(observer.clone());
+        let old_stars_range = self.old_stars_range(&config);
+        self.cells_within(observer, max_distance)
+            .into_iter()
+            .map(move |cell| {
+                let stars = self.generate_cell_with_range(&cell, &config, old_stars_range)?;
+                Ok(within(stars, observer, max_distance))
+            })
+    }
+
+    // Generates all cells around the observer in parallel.
+    pub fn visible_stars(
+        &self,
+        observer: &Cartesian,
+        max_distance: Length,
+    ) -> Result<Vec<StarData>, AstroUtilError> {
+        let config = self.config.clone().with_observer_position(observer.clone());
+        let old_stars_range = self.old_stars_range(&config);
+        let cells = self.cells_within(observer, max_distance);
+        let stars = cells
+            .par_iter()
+            .map(|cell| self.generate_cell_with_range(cell, &config, old_stars_range))
+            .collect::<Result<Vec<Vec<StarData>>, AstroUtilError>>()?;
+        Ok(within(
+            stars.into_iter().flatten().collect(),
+            observer,
+            max_distance,
+        ))
+    }
+
+    fn old_stars(&self, cell: &CellIndex, config: &GalaxyGenerationConfig) -> GenerationParams {
+        let mut params = GenerationParams::old_stars(self.cell_size * 3f64.sqrt() / 2., config);
+        params.pos = cell.center(self.cell_size);
+        params
+    }
+
+    // The old stars of all cells share their age, and therefore the distance up to which they can be seen.
+    fn old_stars_range(&self, config: &GalaxyGenerationConfig) -> Length {
+        self.old_stars(&CellIndex::new(0, 0, 0), config)
+            .visibility_range(config)
+    }
+
+    fn generate_cell_with_range(
+        &self,
+        cell: &CellIndex,
+        config: &GalaxyGenerationConfig,
+        old_stars_range: Length,
+    ) -> Result<Vec<StarData>, AstroUtilError> {
+        let cell_seed = cell.seed(self.seed);
+        let mut stars = Vec::new();
+
+        let mut rng = StdRng::seed_from_u64(derive_seed(cell_seed, 0));
+        let mut old_stars = self.old_stars(cell, config);
+        old_stars.number = expected_to_number(
+            config.stars_per_cubic_light_year
+                * self.relative_densities(cell, config).0
+                * self.cell_volume_in_cubic_light_years(),
+            &mut rng,
+        );
+        if cell.distance_to(&config.observer_position, self.cell_size) <= old_stars_range {
+            stars.extend(generate_random_stars_in_volume(
+                &old_stars,
+                config,
+                &self.mass_index_distributions,
+                &mut rng,
+                |rng| Some(cell.random_point(rng, self.cell_size)),
+            ));
+        }
+
+        for (cluster, mut rng) in self.nurseries(cell, config) {
+            stars.extend(cluster.generate_members(
+                config,
+                &self.mass_index_distributions,
+                &mut rng,
+            ));
+        }
+        Ok(stars)
+    }
+
+    // Cells are assumed to be small compared to the scales on which the galactic structure varies.
+    fn relative_densities(&self, cell: &CellIndex, config: &GalaxyGenerationConfig) -> (f64, f64) {
+        let center = cell.center(self.cell_size);
+        match &config.galactic_structure {
+            Some(structure) => (
+                structure.relative_star_density(&center),
+                structure.relative_nursery_density(&center),
+            ),
+            None => (1., 1.),
+        }
+    }
+
+    /*
+     * The nurseries of a cell do not depend on where it is seen from.
+     * Their number is drawn from stream 1 of the cell seed, and nursery i from stream i + 2.
+     * Each nursery comes with the generator that then populates it.
+     */
+    fn nurseries(
+        &self,
+        cell: &CellIndex,
+        config: &GalaxyGenerationConfig,
+    ) -> Vec<(StarCluster, StdRng)> {
+        let cell_seed = cell.seed(self.seed);
+        let star_formation_history = &config.star_formation_history;
+        let mut rng = StdRng::seed_from_u64(derive_seed(cell_seed, 1));
+        let number_of_nurseries = expected_to_number(
+            config.nurseries_per_cubic_light_year
+                * self.relative_densities(cell, config).1
+                * star_formation_history.mean_rate(config.age_of_disk)
+                * self.cell_volume_in_cubic_light_years(),
+            &mut rng,
+        );
+        let age_distribution = match star_formation_history.age_distribution(config.age_of_disk) {
+            Some(distribution) => distribution,
+            None => return Vec::new(),
+        };
+        (0..number_of_nurseries)
+            .map(|i| {
+                let mut rng = StdRng::seed_from_u64(derive_seed(cell_seed, i as u64 + 2));
+                let pos = cell.random_point(&mut rng, self.cell_size);
+                let max_age = age_distribution.sample(&mut rng);
+                let mass_fraction = config.metallicity.mass_fraction(max_age, &mut rng);
+                let name = format!("Cluster {} in cell ({}, {}, {})", i, cell.x, cell.y, cell.z);
+                let cluster = StarCluster::from_nursery(name, pos, max_age, mass_fraction, config);
+                (cluster, rng)
+            })
+            .collect()
+    }
+
+    fn cell_volume_in_cubic_light_years(&self) -> f64 {
+        self.cell_size.get::<light_year>().powi(3)
+    }
+}
+
+// Rounds the expected number up or down at random, so that the average is preserved.
+fn expected_to_number<R: Rng + ?Sized>(expected: f64, rng: &mut R) -> usize {
+    let whole = expected.floor();
+    let fraction = expected - whole;
+    whole as usize + usize::from(rng.random::<f64>() < fraction)
+}
+
+fn within(stars: Vec<StarData>, observer: &Cartesian, max_distance: Length) -> Vec<StarData> {
+    stars
+        .into_iter()
+        .filter(|star| star.get_pos_at_epoch().distance(observer) <= max_distance)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_coords::direction::Direction;
+
+    use crate::stars::random::{
+        galactic_structure::GalacticStructure, random_stars::random_point_in_sphere,
+    };
+
+    use super::*;
+
+    fn galaxy(seed: u64) -> ChunkedGalaxy {
+        let cell_size = Length::new::<light_year>(100.);
+        ChunkedGalaxy::new(GalaxyGenerationConfig::default(), cell_size, seed).unwrap()
+    }
+
+    #[test]
+    fn cell_index_rounds_towards_negative_infinity() {
+        let cell_size = Length::new::<light_year>(100.);
+        let pos = Cartesian::new(
+            Length::new::<light_year>(150.),
+            Length::new::<light_year>(-50.),
+            Length::new::<light_year>(-100.),
+        );
+        assert_eq!(
+            CellIndex::containing(&pos, cell_size),
+            CellIndex::new(1, -1, -1)
+        );
+        let center = CellIndex::new(1, -1, -1).center(cell_size);
+        assert_eq!(
+            CellIndex::containing(&center, cell_size),
+            CellIndex::new(1, -1, -1)
+        );
+    }
+
+    #[test]
+    fn cells_within_cover_the_sphere() {
+        let galaxy = galaxy(0);
+        let center = Direction::X.to_cartesian(Length::new::<light_year>(1234.));
+        let radius = Length::new::<light_year>(250.);
+        let cells = galaxy.cells_within(&center, radius);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let pos = &center + &random_point_in_sphere(&mut rng, radius);
+            let cell = CellIndex::containing(&pos, galaxy.get_cell_size());
+            assert!(cells.contains(&cell));
+        }
+        assert!(cells.len() < 7 * 7 * 7);
+    }
+
+    #[test]
+    fn the_same_cell_always_contains_the_same_stars() {
+        let galaxy = galaxy(42);
+        let cell = CellIndex::new(3, -2, 0);
+        let observer = cell.center(galaxy.get_cell_size());
+        let first = galaxy.generate_cell(&cell, &observer).unwrap();
+        let second = galaxy.generate_cell(&cell, &observer).unwrap();
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+        let other = galaxy
+            .generate_cell(&CellIndex::new(3, -2, 1), &observer)
+            .unwrap();
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn moving_the_observer_does_not_move_the_stars() {
+        let config = GalaxyGenerationConfig::default().with_limiting_magnitude(30.);
+        let cell_size = Length::new::<light_year>(20.);
+        let galaxy = ChunkedGalaxy::new(config, cell_size, 7).unwrap();
+        let cell = CellIndex::new(-5, 8, 2);
+        let here = cell.center(cell_size);
+        let there = &here + &Direction::Y.to_cartesian(Length::new::<light_year>(50.));
+        let seen_from_here = galaxy.generate_cell(&cell, &here).unwrap();
+        let seen_from_there = galaxy.generate_cell(&cell, &there).unwrap();
+        assert!(!seen_from_here.is_empty());
+        assert_eq!(seen_from_here, seen_from_there);
+    }
+
+    #[test]
+    fn visible_stars_are_a_subset_of_fainter_ones() {
+        let cell_size = Length::new::<light_year>(20.);
+        let bright = ChunkedGalaxy::new(GalaxyGenerationConfig::default(), cell_size, 9).unwrap();
+        let faint_config = GalaxyGenerationConfig::default().with_limiting_magnitude(30.);
+        let faint = ChunkedGalaxy::new(faint_config, cell_size, 9).unwrap();
+        let cell = CellIndex::new(0, 0, 0);
+        let observer = Cartesian::origin();
+        let bright_stars = bright.generate_cell(&cell, &observer).unwrap();
+        let faint_stars = faint.generate_cell(&cell, &observer).unwrap();
+        assert!(bright_stars.len() < faint_stars.len());
+        for star in bright_stars {
+            assert!(faint_stars
+                .iter()
+                .any(|other| other.get_pos_at_epoch() == star.get_pos_at_epoch()));
+        }
+    }
+
+    #[test]
+    fn streamed_stars_match_parallel_generation() {
+        let galaxy = galaxy(11);
+        let observer = Direction::Z.to_cartesian(Length::new::<light_year>(-3_000.));
+        let max_distance = Length::new::<light_year>(250.);
+        let streamed: Vec<StarData> = galaxy
+            .stream_visible_stars(&observer, max_distance)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+        let parallel = galaxy.visible_stars(&observer, max_distance).unwrap();
+        assert!(!parallel.is_empty());
+        assert_eq!(streamed, parallel);
+        for star in parallel {
+            assert!(star.get_pos_at_epoch().distance(&observer) <= max_distance);
+        }
+    }
+
+    #[test]
+    fn expected_numbers_are_preserved_on_average() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let total: usize = (0..10_000).map(|_| expected_to_number(2.3, &mut rng)).sum();
+        assert!((total as f64 / 10_000. - 2.3).abs() < 0.02);
+    }
+
+    #[test]
+    fn cells_follow_the_galactic_structure() {
+        let structure = GalacticStructure::milky_way();
+        let config = GalaxyGenerationConfig::default()
+            .with_stellar_density(1e-5)
+            .with_nursery_density(0.)
+            .with_limiting_magnitude(40.)
+            .with_galactic_structure(structure.clone());
+        let cell_size = Length::new::<light_year>(1_000.);
+        let galaxy = ChunkedGalaxy::new(config, cell_size, 3).unwrap();
+        let above_plane = structure
+            .get_north_galactic_pole()
+            .to_cartesian(Length::new::<light_year>(5_000.));
+        let number_of_stars = |pos: &Cartesian| {
+            let cell = CellIndex::containing(pos, cell_size);
+            galaxy.generate_cell(&cell, pos).unwrap().len()
+        };
+        let local = number_of_stars(&Cartesian::origin());
+        assert!(local > 1_000);
+        assert!(number_of_stars(&above_plane) < local / 10);
+    }
+
+    #[test]
+    fn the_nurseries_of_a_cell_do_not_depend_on_the_observer() {
+        let config = GalaxyGenerationConfig::default().with_nursery_density(1e-5);
+        let cell_size = Length::new::<light_year>(100.);
+        let galaxy = ChunkedGalaxy::new(config.clone(), cell_size, 5).unwrap();
+        let cell = CellIndex::new(0, 0, 0);
+        let here = cell.center(cell_size);
+        let beyond_old_stars = galaxy.old_stars_range(&config) + cell_size * 2.;
+        let there = &here + &Direction::X.to_cartesian(beyond_old_stars);
+        let clusters = |observer: &Cartesian| {
+            let config = config.clone().with_observer_position(observer.clone());
+            galaxy
+                .nurseries(&cell, &config)
+                .into_iter()
+                .map(|(cluster, _)| cluster)
+                .collect::<Vec<_>>()
+        };
+        assert!(!clusters(&here).is_empty());
+        assert_eq!(clusters(&here), clusters(&there));
+
+        let seen_from_here = galaxy.generate_cell(&cell, &here).unwrap();
+        let seen_from_there = galaxy.generate_cell(&cell, &there).unwrap();
+        assert!(!seen_from_there.is_empty());
+        // Stars of the cell that are visible from afar are also visible from closer by.
+        let closer_to_here = seen_from_there.iter().filter(|star| {
+            let pos = star.get_pos_at_epoch();
+            pos.distance(&here) <= pos.distance(&there)
+        });
+        for star in closer_to_here {
+            assert!(seen_from_here
+                .iter()
+                .any(|other| other.get_pos_at_epoch() == star.get_pos_at_epoch()));
+        }
+    }
+}
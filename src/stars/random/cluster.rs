@@ -0,0 +1,607 @@
+use std::f64::consts::PI;
+
+use astro_coords::{cartesian::Cartesian, earth_equatorial::EarthEquatorial};
+use astro_units::time::megayear;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::Distribution;
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Time, Velocity},
+    length::light_year,
+    velocity::kilometer_per_second,
+};
+
+use crate::{
+    error::AstroUtilError,
+    kinematics::velocity_vector::VelocityVector,
+    stars::{data::StarData, random::parsec::mass_distribution::MassIndexDistributions},
+};
+
+use super::{
+    config::GalaxyGenerationConfig,
+    metallicity::{
+        iron_abundance_to_mass_fraction, metallicity_grid_index, standard_normal,
+        DEFAULT_METALLICITY,
+    },
+    params::GenerationParams,
+    parsec::{getters::get_star_data_if_visible, interpolation::random_mass_in_bin},
+    random_stars::random_point_in_sphere,
+};
+
+/*
+ * The distribution of the members of a star cluster around its center.
+ * The uniform profile spreads the members evenly over the distance they travelled with the velocity dispersion,
+ * which is how star forming regions were populated originally.
+ * https://en.wikipedia.org/wiki/Plummer_model
+ * https://ui.adsabs.harvard.edu/abs/1962AJ.....67..471K/abstract
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ClusterProfile {
+    Uniform,
+    Plummer {
+        scale_radius: Length,
+    },
+    King {
+        core_radius: Length,
+        tidal_radius: Length,
+    },
+}
+
+// Globular clusters are gravitationally bound, while open clusters dissolve over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClusterKind {
+    Open,
+    Globular,
+}
+
+/*
+ * A group of stars that formed together, sharing their age and metallicity.
+ * The members of an open cluster drift away from their birth positions with random velocities
+ * drawn from the velocity dispersion, so that young clusters stay compact and old ones dissolve.
+ * https://en.wikipedia.org/wiki/Star_cluster
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StarCluster {
+    pub(super) name: String,
+    pub(super) kind: ClusterKind,
+    pub(super) pos: Cartesian,
+    pub(super) age: Time,
+    pub(super) age_spread: Time,
+    pub(super) metallicity: f64,
+    pub(super) number_of_stars: usize,
+    pub(super) profile: ClusterProfile,
+    pub(super) velocity_dispersion: Velocity,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratedCluster {
+    pub(super) cluster: StarCluster,
+    pub(super) members: Vec<StarData>,
+}
+
+// The Plummer sphere is cut off at this many scale radii, which contain 98.5% of its mass.
+const PLUMMER_CUTOFF: f64 = 10.;
+const KING_RADIAL_BINS: usize = 256;
+
+impl StarCluster {
+    pub fn open(name: String, pos: Cartesian, age: Time) -> Self {
+        Self {
+            name,
+            kind: ClusterKind::Open,
+            pos,
+            age,
+            age_spread: Time::new::<megayear>(0.),
+            metallicity: DEFAULT_METALLICITY,
+            number_of_stars: 1_000,
+            profile: ClusterProfile::Plummer {
+                scale_radius: Length::new::<light_year>(6.),
+            },
+            velocity_dispersion: Velocity::new::<kilometer_per_second>(0.5),
+        }
+    }
+
+    pub fn globular(name: String, pos: Cartesian, age: Time) -> Self {
+        Self {
+            name,
+            kind: ClusterKind::Globular,
+            pos,
+            age,
+            age_spread: Time::new::<megayear>(0.),
+            metallicity: iron_abundance_to_mass_fraction(-1.5),
+            number_of_stars: 100_000,
+            profile: ClusterProfile::King {
+                core_radius: Length::new::<light_year>(3.),
+                tidal_radius: Length::new::<light_year>(150.),
+            },
+            velocity_dispersion: Velocity::new::<kilometer_per_second>(10.),
+        }
+    }
+
+    // https://en.wikipedia.org/wiki/Pleiades
+    pub fn pleiades() -> Self {
+        let direction =
+            EarthEquatorial::new(Angle::new::<degree>(56.75), Angle::new::<degree>(24.117))
+                .to_direction();
+        let pos = direction.to_cartesian(Length::new::<light_year>(444.));
+        Self::open("Pleiades".to_string(), pos, Time::new::<megayear>(100.))
+    }
+
+    // https://en.wikipedia.org/wiki/47_Tucanae
+    pub fn tucanae_47() -> Self {
+        let direction =
+            EarthEquatorial::new(Angle::new::<degree>(6.024), Angle::new::<degree>(-72.081))
+                .to_direction();
+        let pos = direction.to_cartesian(Length::new::<light_year>(14_700.));
+        Self::globular(
+            "47 Tucanae".to_string(),
+            pos,
+            Time::new::<megayear>(11_800.),
+        )
+        .with_metallicity(iron_abundance_to_mass_fraction(-0.72))
+        .with_profile(ClusterProfile::King {
+            core_radius: Length::new::<light_year>(1.7),
+            tidal_radius: Length::new::<light_year>(180.),
+        })
+    }
+
+    // A star forming region of a randomly generated galaxy, shaped according to the config.
+    pub(super) fn from_nursery(
+        name: String,
+        pos: Cartesian,
+        max_age: Time,
+        metallicity: f64,
+        config: &GalaxyGenerationConfig,
+    ) -> Self {
+        let velocity_dispersion = match config.cluster_profile {
+            ClusterProfile::Uniform => config.stellar_velocity,
+            _ => config.cluster_velocity_dispersion,
+        };
+        Self {
+            name,
+            kind: ClusterKind::Open,
+            pos,
+            age: max_age,
+            age_spread: config.nursery_lifetime,
+            metallicity,
+            number_of_stars: config.stars_per_nursery,
+            profile: config.cluster_profile,
+            velocity_dispersion,
+        }
+    }
+
+    pub fn with_age_spread(mut self, age_spread: Time) -> Self {
+        self.age_spread = age_spread;
+        self
+    }
+
+    pub fn with_metallicity(mut self, metallicity: f64) -> Self {
+        self.metallicity = metallicity;
+        self
+    }
+
+    pub fn with_number_of_stars(mut self, number_of_stars: usize) -> Self {
+        self.number_of_stars = number_of_stars;
+        self
+    }
+
+    pub fn with_profile(mut self, profile: ClusterProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_velocity_dispersion(mut self, velocity_dispersion: Velocity) -> Self {
+        self.velocity_dispersion = velocity_dispersion;
+        self
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_kind(&self) -> ClusterKind {
+        self.kind
+    }
+
+    pub fn get_pos(&self) -> &Cartesian {
+        &self.pos
+    }
+
+    pub fn get_age(&self) -> Time {
+        self.age
+    }
+
+    pub fn get_age_spread(&self) -> Time {
+        self.age_spread
+    }
+
+    pub fn get_metallicity(&self) -> f64 {
+        self.metallicity
+    }
+
+    pub fn get_number_of_stars(&self) -> usize {
+        self.number_of_stars
+    }
+
+    pub fn get_profile(&self) -> ClusterProfile {
+        self.profile
+    }
+
+    pub fn get_velocity_dispersion(&self) -> Velocity {
+        self.velocity_dispersion
+    }
+
+    pub fn is_bound(&self) -> bool {
+        self.kind == ClusterKind::Globular
+    }
+
+    // The radius around the center beyond which practically no members are found.
+    pub fn extent(&self) -> Length {
+        let drift = if self.is_bound() {
+            Length::new::<light_year>(0.)
+        } else {
+            self.velocity_dispersion * self.age * 3.
+        };
+        match self.profile {
+            ClusterProfile::Uniform => self.velocity_dispersion * self.age,
+            ClusterProfile::Plummer { scale_radius } => scale_radius * PLUMMER_CUTOFF + drift,
+            ClusterProfile::King { tidal_radius, .. } => tidal_radius + drift,
+        }
+    }
+
+    /*
+     * The random numbers drawn for a member do not depend on whether it turns out to be visible,
+     * so the members stay where they are no matter where the observer is.
+     */
+    pub(super) fn generate_members<R: Rng + ?Sized>(
+        &self,
+        config: &GalaxyGenerationConfig,
+        mass_index_distributions: &MassIndexDistributions,
+        rng: &mut R,
+    ) -> Vec<StarData> {
+        let params = GenerationParams::cluster(self);
+        if params.is_out_of_sight(config) {
+            return Vec::new();
+        }
+        self.generate_members_with_params(&params, config, mass_index_distributions, rng)
+    }
+
+    /*
+     * A cluster with a uniform profile is only populated where it can be within sight, as for the old stars.
+     * The other profiles are concentrated towards their center, so they are generated in full.
+     */
+    pub(super) fn generate_members_within_sight<R: Rng + ?Sized>(
+        &self,
+        config: &GalaxyGenerationConfig,
+        mass_index_distributions: &MassIndexDistributions,
+        rng: &mut R,
+    ) -> Vec<StarData> {
+        if self.profile != ClusterProfile::Uniform {
+            return self.generate_members(config, mass_index_distributions, rng);
+        }
+        let mut params = GenerationParams::cluster(self);
+        params.adjust_distance_for_performance(config);
+        self.generate_members_with_params(&params, config, mass_index_distributions, rng)
+    }
+
+    fn generate_members_with_params<R: Rng + ?Sized>(
+        &self,
+        params: &GenerationParams,
+        config: &GalaxyGenerationConfig,
+        mass_index_distributions: &MassIndexDistributions,
+        rng: &mut R,
+    ) -> Vec<StarData> {
+        let radial_distribution = match self.profile {
+            ClusterProfile::Uniform => RadialDistribution::Uniform(params.radius),
+            ClusterProfile::Plummer { scale_radius } => RadialDistribution::plummer(scale_radius),
+            ClusterProfile::King {
+                core_radius,
+                tidal_radius,
+            } => RadialDistribution::king(core_radius, tidal_radius),
+        };
+        let metallicity_index = metallicity_grid_index(self.metallicity);
+        let mass_index_distr = match mass_index_distributions.get(metallicity_index) {
+            Some(distr) => distr,
+            None => return Vec::new(),
+        };
+        (0..params.number)
+            .filter_map(|_| {
+                let age = self.age - self.age_spread * rng.random::<f64>();
+                let mass_index = mass_index_distr.sample(rng);
+                let mass = random_mass_in_bin(metallicity_index, mass_index, rng);
+                let mut offset = radial_distribution.random_offset(rng);
+                let mut velocity = VelocityVector::zero();
+                if self.profile != ClusterProfile::Uniform {
+                    velocity = random_velocity(rng, self.velocity_dispersion);
+                    if !self.is_bound() {
+                        offset = &offset + &velocity.displacement(age);
+                    }
+                }
+                let pos = &self.pos + &offset;
+                let mut star = get_star_data_if_visible(config, self.metallicity, mass, age, pos)?;
+                star.set_velocity(velocity);
+                Some(star)
+            })
+            .collect()
+    }
+}
+
+impl GeneratedCluster {
+    pub fn get_cluster(&self) -> &StarCluster {
+        &self.cluster
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.cluster.name
+    }
+
+    pub fn get_members(&self) -> &Vec<StarData> {
+        &self.members
+    }
+
+    pub fn into_members(self) -> Vec<StarData> {
+        self.members
+    }
+}
+
+// Generates the members of the cluster that are visible according to the config.
+pub fn generate_cluster(
+    cluster: &StarCluster,
+    config: &GalaxyGenerationConfig,
+    seed: u64,
+) -> Result<GeneratedCluster, AstroUtilError> {
+    if !parsec_access::getters::is_data_ready() {
+        return Err(AstroUtilError::DataNotAvailable(
+            "Parsec data not ready".to_string(),
+        ));
+    }
+    let mass_index_distributions = MassIndexDistributions::new(
+        &[metallicity_grid_index(cluster.metallicity)],
+        config.get_initial_mass_function(),
+    )?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let members = cluster.generate_members(config, &mass_index_distributions, &mut rng);
+    Ok(GeneratedCluster {
+        cluster: cluster.clone(),
+        members,
+    })
+}
+
+enum RadialDistribution {
+    Uniform(Length),
+    Plummer {
+        scale_radius: Length,
+        enclosed_at_cutoff: f64,
+    },
+    // Cumulative fractions of the members within the outer edges of equally wide radial bins.
+    Tabulated {
+        outer_radius: Length,
+        cumulative: Vec<f64>,
+    },
+}
+
+impl RadialDistribution {
+    fn plummer(scale_radius: Length) -> Self {
+        RadialDistribution::Plummer {
+            scale_radius,
+            enclosed_at_cutoff: PLUMMER_CUTOFF.powi(3) / (1. + PLUMMER_CUTOFF.powi(2)).powf(1.5),
+        }
+    }
+
+    fn king(core_radius: Length, tidal_radius: Length) -> Self {
+        let ratio = (tidal_radius / core_radius).value;
+        let mut total = 0.;
+        let cumulative = (0..KING_RADIAL_BINS)
+            .map(|i| {
+                let x = (i as f64 + 0.5) / KING_RADIAL_BINS as f64 * ratio;
+                total += x * x * king_density(x, ratio);
+                total
+            })
+            .collect::<Vec<_>>();
+        RadialDistribution::Tabulated {
+            outer_radius: tidal_radius,
+            cumulative: cumulative.iter().map(|c| c / total).collect(),
+        }
+    }
+
+    fn random_offset<R: Rng + ?Sized>(&self, rng: &mut R) -> Cartesian {
+        match self {
+            RadialDistribution::Uniform(radius) => random_point_in_sphere(rng, *radius),
+            RadialDistribution::Plummer {
+                scale_radius,
+                enclosed_at_cutoff,
+            } => {
+                let enclosed = (1. - rng.random::<f64>()) * enclosed_at_cutoff;
+                let radius = *scale_radius / (enclosed.powf(-2. / 3.) - 1.).sqrt();
+                random_point_on_sphere(rng, radius)
+            }
+            RadialDistribution::Tabulated {
+                outer_radius,
+                cumulative,
+            } => {
+                let u = rng.random::<f64>();
+                let bin = cumulative.partition_point(|c| *c < u);
+                let within_bin = rng.random::<f64>();
+                let fraction = (bin as f64 + within_bin) / cumulative.len() as f64;
+                random_point_on_sphere(rng, *outer_radius * fraction)
+            }
+        }
+    }
+}
+
+/*
+ * The spatial density of a King model, up to a constant factor,
+ * at x core radii from the center of a cluster whose tidal radius is the given number of core radii.
+ */
+fn king_density(x: f64, tidal_ratio: f64) -> f64 {
+    if x >= tidal_ratio {
+        return 0.;
+    }
+    let z = ((1. + x * x) / (1. + tidal_ratio * tidal_ratio)).sqrt();
+    (z.acos() / z - (1. - z * z).sqrt()) / (z * z)
+}
+
+fn random_point_on_sphere<R: Rng + ?Sized>(rng: &mut R, radius: Length) -> Cartesian {
+    let cos_theta: f64 = 2. * rng.random::<f64>() - 1.;
+    let phi = 2. * PI * rng.random::<f64>();
+    let sin_theta = (1. - cos_theta * cos_theta).sqrt();
+    Cartesian::new(
+        radius * sin_theta * phi.cos(),
+        radius * sin_theta * phi.sin(),
+        radius * cos_theta,
+    )
+}
+
+fn random_velocity<R: Rng + ?Sized>(rng: &mut R, dispersion: Velocity) -> VelocityVector {
+    VelocityVector::new(
+        dispersion * standard_normal(rng),
+        dispersion * standard_normal(rng),
+        dispersion * standard_normal(rng),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stars::random::{
+        metallicity::SOLAR_METALLICITY,
+        random_stars::{generate_random_clusters_with_config, generate_random_stars_with_config},
+    };
+
+    use super::*;
+
+    fn distances_to_center(cluster: &StarCluster, members: &[StarData]) -> Vec<f64> {
+        let mut distances: Vec<f64> = members
+            .iter()
+            .map(|star| {
+                star.get_pos_at_epoch()
+                    .distance(&cluster.pos)
+                    .get::<light_year>()
+            })
+            .collect();
+        distances.sort_by(f64::total_cmp);
+        distances
+    }
+
+    fn all_visible() -> GalaxyGenerationConfig {
+        GalaxyGenerationConfig::default().with_limiting_magnitude(40.)
+    }
+
+    #[test]
+    fn plummer_profile_has_the_expected_half_mass_radius() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let scale_radius = Length::new::<light_year>(10.);
+        let distribution = RadialDistribution::plummer(scale_radius);
+        let mut radii: Vec<f64> = (0..20_000)
+            .map(|_| {
+                distribution
+                    .random_offset(&mut rng)
+                    .length()
+                    .get::<light_year>()
+            })
+            .collect();
+        radii.sort_by(f64::total_cmp);
+        let median = radii[radii.len() / 2];
+        assert!((median / 10. - 1.29).abs() < 0.05);
+        assert!(radii[radii.len() - 1] <= 10. * PLUMMER_CUTOFF);
+    }
+
+    #[test]
+    fn king_profile_is_concentrated_within_the_tidal_radius() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let core_radius = Length::new::<light_year>(2.);
+        let tidal_radius = Length::new::<light_year>(100.);
+        let distribution = RadialDistribution::king(core_radius, tidal_radius);
+        let radii: Vec<f64> = (0..20_000)
+            .map(|_| {
+                distribution
+                    .random_offset(&mut rng)
+                    .length()
+                    .get::<light_year>()
+            })
+            .collect();
+        assert!(radii.iter().all(|r| *r <= 100.));
+        let within_ten_core_radii = radii.iter().filter(|r| **r < 20.).count();
+        // A uniform sphere would only have 0.8% of its members there.
+        assert!(within_ten_core_radii as f64 / radii.len() as f64 > 0.3);
+    }
+
+    #[test]
+    fn same_seed_generates_same_cluster() {
+        let cluster = StarCluster::pleiades().with_number_of_stars(500);
+        let first = generate_cluster(&cluster, &all_visible(), 3).unwrap();
+        let second = generate_cluster(&cluster, &all_visible(), 3).unwrap();
+        assert!(!first.get_members().is_empty());
+        assert_eq!(first, second);
+        assert_eq!(first.get_name(), "Pleiades");
+        for star in first.get_members() {
+            assert_eq!(star.get_metallicity(), Some(DEFAULT_METALLICITY));
+        }
+    }
+
+    #[test]
+    fn pleiades_are_visible_to_the_naked_eye() {
+        let config = GalaxyGenerationConfig::default();
+        let generated = generate_cluster(&StarCluster::pleiades(), &config, 4).unwrap();
+        assert!(!generated.get_members().is_empty());
+        assert!(generated.get_members().len() < 100);
+    }
+
+    #[test]
+    fn open_clusters_dissolve_over_time() {
+        let young = StarCluster::open(
+            "Young".to_string(),
+            Cartesian::origin(),
+            Time::new::<megayear>(10.),
+        )
+        .with_number_of_stars(2_000);
+        let old = young
+            .clone()
+            .with_profile(ClusterProfile::Plummer {
+                scale_radius: Length::new::<light_year>(6.),
+            })
+            .with_velocity_dispersion(Velocity::new::<kilometer_per_second>(0.5));
+        let old = StarCluster {
+            age: Time::new::<megayear>(500.),
+            ..old
+        };
+        let median = |cluster: &StarCluster| {
+            let generated = generate_cluster(cluster, &all_visible(), 5).unwrap();
+            let distances = distances_to_center(cluster, generated.get_members());
+            distances[distances.len() / 2]
+        };
+        assert!(median(&old) > 10. * median(&young));
+    }
+
+    #[test]
+    fn globular_clusters_stay_bound() {
+        let cluster = StarCluster::tucanae_47().with_number_of_stars(5_000);
+        let generated = generate_cluster(&cluster, &all_visible(), 6).unwrap();
+        assert!(!generated.get_members().is_empty());
+        let distances = distances_to_center(&cluster, generated.get_members());
+        assert!(distances[distances.len() - 1] <= 180. * 1.0001);
+        for star in generated.get_members() {
+            assert!(star.get_metallicity().unwrap() < SOLAR_METALLICITY);
+        }
+    }
+
+    #[test]
+    fn random_clusters_hold_the_nursery_stars() {
+        let max_distance = Length::new::<light_year>(300.);
+        let config = GalaxyGenerationConfig::default()
+            .with_nursery_density(1e-7)
+            .with_stars_per_nursery(2_000)
+            .with_limiting_magnitude(12.)
+            .with_cluster_profile(ClusterProfile::Plummer {
+                scale_radius: Length::new::<light_year>(6.),
+            });
+        let stars = generate_random_stars_with_config(max_distance, &config, 7).unwrap();
+        let clusters = generate_random_clusters_with_config(max_distance, &config, 7).unwrap();
+        assert!(!clusters.is_empty());
+        for cluster in &clusters {
+            assert!(!cluster.get_members().is_empty());
+            for member in cluster.get_members() {
+                assert!(stars.contains(member));
+            }
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use astro_coords::cartesian::Cartesian;
+use astro_units::illuminance::{
+    apparent_magnitude_to_illuminance, illuminance_to_apparent_magnitude, Illuminance,
+};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{Time, Velocity},
+    velocity::kilometer_per_second,
+};
+
+use crate::stars::{
+    evolution::EvolutionMode,
+    extinction::{DustMap, SharedDustMap},
+};
+
+use super::{
+    cluster::ClusterProfile,
+    galactic_structure::GalacticStructure,
+    initial_mass_function::{InitialMassFunction, SharedInitialMassFunction},
+    metallicity::Metallicity,
+    random_stars::{
+        age_of_milky_way_thin_disk, dimmest_illuminance, nursery_lifetime, stellar_velocity,
+        NUMBER_OF_STARS_FORMED_IN_NURSERY, NURSERIES_PER_LY_CUBED, STARS_PER_LY_CUBED,
+    },
+    star_formation_history::StarFormationHistory,
+};
+
+/*
+ * The parameters that shape a randomly generated galaxy.
+ * The defaults reproduce the solar neighbourhood as seen with the naked eye from the origin.
+ * Stars form over the age of the disk, partly in a smooth old population
+ * and partly in star forming regions that disperse with the stellar velocity.
+ * By default the regions form at a constant rate, which the star formation history can change.
+ * Alternatively, the star forming regions can be shaped like real clusters that dissolve with their own,
+ * much smaller velocity dispersion.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GalaxyGenerationConfig {
+    pub(super) stars_per_cubic_light_year: f64,
+    pub(super) nurseries_per_cubic_light_year: f64,
+    pub(super) stars_per_nursery: usize,
+    pub(super) nursery_lifetime: Time,
+    pub(super) stellar_velocity: Velocity,
+    pub(super) age_of_disk: Time,
+    pub(super) dimmest_illuminance: Illuminance,
+    pub(super) observer_position: Cartesian,
+    pub(super) metallicity: Metallicity,
+    #[serde(default)]
+    pub(super) galactic_structure: Option<GalacticStructure>,
+    #[serde(default = "default_cluster_profile")]
+    pub(super) cluster_profile: ClusterProfile,
+    #[serde(default = "cluster_velocity_dispersion")]
+    pub(super) cluster_velocity_dispersion: Velocity,
+    #[serde(default)]
+    pub(super) star_formation_history: StarFormationHistory,
+    #[serde(default)]
+    pub(super) evolution_mode: EvolutionMode,
+    // Custom initial mass functions cannot be serialized, so a deserialized config uses the default one.
+    #[serde(skip)]
+    pub(super) initial_mass_function: SharedInitialMassFunction,
+    // Neither can dust maps, and a deserialized config sees through the dust.
+    #[serde(skip)]
+    pub(super) dust_map: Option<SharedDustMap>,
+}
+
+fn default_cluster_profile() -> ClusterProfile {
+    ClusterProfile::Uniform
+}
+
+// https://ui.adsabs.harvard.edu/abs/2019A%26A...628A..66K/abstract
+fn cluster_velocity_dispersion() -> Velocity {
+    Velocity::new::<kilometer_per_second>(1.)
+}
+
+impl GalaxyGenerationConfig {
+    pub fn new() -> Self {
+        Self {
+            stars_per_cubic_light_year: STARS_PER_LY_CUBED,
+            nurseries_per_cubic_light_year: NURSERIES_PER_LY_CUBED,
+            stars_per_nursery: NUMBER_OF_STARS_FORMED_IN_NURSERY,
+            nursery_lifetime: nursery_lifetime(),
+            stellar_velocity: stellar_velocity(),
+            age_of_disk: age_of_milky_way_thin_disk(),
+            dimmest_illuminance: dimmest_illuminance(),
+            observer_position: Cartesian::origin(),
+            metallicity: Metallicity::default(),
+            galactic_structure: None,
+            cluster_profile: default_cluster_profile(),
+            cluster_velocity_dispersion: cluster_velocity_dispersion(),
+            star_formation_history: StarFormationHistory::default(),
+            evolution_mode: EvolutionMode::default(),
+            initial_mass_function: SharedInitialMassFunction::default(),
+            dust_map: None,
+        }
+    }
+
+    pub fn with_stellar_density(mut self, stars_per_cubic_light_year: f64) -> Self {
+        self.stars_per_cubic_light_year = stars_per_cubic_light_year;
+        self
+    }
+
+    pub fn with_nursery_density(mut self, nurseries_per_cubic_light_year: f64) -> Self {
+        self.nurseries_per_cubic_light_year = nurseries_per_cubic_light_year;
+        self
+    }
+
+    pub fn with_stars_per_nursery(mut self, stars_per_nursery: usize) -> Self {
+        self.stars_per_nursery = stars_per_nursery;
+        self
+    }
+
+    pub fn with_nursery_lifetime(mut self, nursery_lifetime: Time) -> Self {
+        self.nursery_lifetime = nursery_lifetime;
+        self
+    }
+
+    pub fn with_stellar_velocity(mut self, stellar_velocity: Velocity) -> Self {
+        self.stellar_velocity = stellar_velocity;
+        self
+    }
+
+    pub fn with_age_of_disk(mut self, age_of_disk: Time) -> Self {
+        self.age_of_disk = age_of_disk;
+        self
+    }
+
+    pub fn with_limiting_magnitude(mut self, limiting_magnitude: f64) -> Self {
+        self.dimmest_illuminance = apparent_magnitude_to_illuminance(limiting_magnitude);
+        self
+    }
+
+    pub fn with_observer_position(mut self, observer_position: Cartesian) -> Self {
+        self.observer_position = observer_position;
+        self
+    }
+
+    pub fn with_metallicity(mut self, metallicity: Metallicity) -> Self {
+        self.metallicity = metallicity;
+        self
+    }
+
+    // Without a galactic structure, stars and nurseries are distributed uniformly.
+    pub fn with_galactic_structure(mut self, galactic_structure: GalacticStructure) -> Self {
+        self.galactic_structure = Some(galactic_structure);
+        self
+    }
+
+    pub fn with_cluster_profile(mut self, cluster_profile: ClusterProfile) -> Self {
+        self.cluster_profile = cluster_profile;
+        self
+    }
+
+    pub fn with_cluster_velocity_dispersion(mut self, velocity_dispersion: Velocity) -> Self {
+        self.cluster_velocity_dispersion = velocity_dispersion;
+        self
+    }
+
+    pub fn with_star_formation_history(
+        mut self,
+        star_formation_history: StarFormationHistory,
+    ) -> Self {
+        self.star_formation_history = star_formation_history;
+        self
+    }
+
+    pub fn with_evolution_mode(mut self, evolution_mode: EvolutionMode) -> Self {
+        self.evolution_mode = evolution_mode;
+        self
+    }
+
+    pub fn with_initial_mass_function<T: InitialMassFunction + 'static>(
+        mut self,
+        initial_mass_function: T,
+    ) -> Self {
+        self.initial_mass_function = SharedInitialMassFunction::new(initial_mass_function);
+        self
+    }
+
+    // Without a dust map, stars are generated as if the space between them was empty.
+    pub fn with_dust_map<T: DustMap + 'static>(mut self, dust_map: T) -> Self {
+        self.dust_map = Some(SharedDustMap::new(dust_map));
+        self
+    }
+
+    pub fn get_stellar_density(&self) -> f64 {
+        self.stars_per_cubic_light_year
+    }
+
+    pub fn get_nursery_density(&self) -> f64 {
+        self.nurseries_per_cubic_light_year
+    }
+
+    pub fn get_stars_per_nursery(&self) -> usize {
+        self.stars_per_nursery
+    }
+
+    pub fn get_nursery_lifetime(&self) -> Time {
+        self.nursery_lifetime
+    }
+
+    pub fn get_stellar_velocity(&self) -> Velocity {
+        self.stellar_velocity
+    }
+
+    pub fn get_age_of_disk(&self) -> Time {
+        self.age_of_disk
+    }
+
+    pub fn get_limiting_magnitude(&self) -> f64 {
+        illuminance_to_apparent_magnitude(self.dimmest_illuminance)
+    }
+
+    pub fn get_dimmest_illuminance(&self) -> Illuminance {
+        self.dimmest_illuminance
+    }
+
+    pub fn get_observer_position(&self) -> &Cartesian {
+        &self.observer_position
+    }
+
+    pub fn get_metallicity(&self) -> &Metallicity {
+        &self.metallicity
+    }
+
+    pub fn get_galactic_structure(&self) -> &Option<GalacticStructure> {
+        &self.galactic_structure
+    }
+
+    pub fn get_cluster_profile(&self) -> ClusterProfile {
+        self.cluster_profile
+    }
+
+    pub fn get_cluster_velocity_dispersion(&self) -> Velocity {
+        self.cluster_velocity_dispersion
+    }
+
+    pub fn get_star_formation_history(&self) -> &StarFormationHistory {
+        &self.star_formation_history
+    }
+
+    pub fn get_evolution_mode(&self) -> EvolutionMode {
+        self.evolution_mode
+    }
+
+    pub fn get_initial_mass_function(&self) -> &dyn InitialMassFunction {
+        self.initial_mass_function.get()
+    }
+
+    pub fn get_dust_map(&self) -> Option<&dyn DustMap> {
+        self.dust_map.as_ref().map(|dust_map| dust_map.get())
+    }
+}
+
+impl Default for GalaxyGenerationConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::eq_within;
+
+    use super::*;
+
+    #[test]
+    fn default_dimmest_illuminance_is_unchanged() {
+        let config = GalaxyGenerationConfig::default();
+        assert_eq!(config.get_dimmest_illuminance(), dimmest_illuminance());
+    }
+
+    #[test]
+    fn builder_overrides_defaults() {
+        let config = GalaxyGenerationConfig::new()
+            .with_stellar_density(1e-3)
+            .with_stars_per_nursery(10)
+            .with_limiting_magnitude(3.);
+        assert_eq!(config.get_stellar_density(), 1e-3);
+        assert_eq!(config.get_stars_per_nursery(), 10);
+        assert!(eq_within(config.get_limiting_magnitude(), 3., 1e-10));
+        assert_eq!(
+            config.get_nursery_density(),
+            GalaxyGenerationConfig::default().get_nursery_density()
+        );
+    }
+}
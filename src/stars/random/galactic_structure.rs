@@ -0,0 +1,552 @@
+use std::f64::consts::PI;
+
+use astro_coords::{cartesian::Cartesian, direction::Direction, earth_equatorial::EarthEquatorial};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    angle::{degree, radian},
+    f64::{Angle, Length},
+    length::light_year,
+};
+
+use crate::kinematics::vector_math::{cross, dot, normalized};
+
+/*
+ * A density model of the Milky Way, relative to the density in the solar neighbourhood.
+ * The origin of the coordinate system is the Sun, whose position in the galaxy is given by its distance
+ * to the galactic center, its height above the galactic plane, and the directions towards the galactic center
+ * and the north galactic pole.
+ * https://en.wikipedia.org/wiki/Galactic_coordinate_system
+ *
+ * Stars are distributed over an exponential thin and thick disk, a gaussian bulge and a flattened power law halo.
+ * https://ui.adsabs.harvard.edu/abs/2008ApJ...673..864J/abstract
+ * Star forming regions follow the thin disk, optionally concentrated along logarithmic spiral arms.
+ * https://en.wikipedia.org/wiki/Milky_Way#Spiral_arms
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GalacticStructure {
+    pub(super) sun_distance_to_center: Length,
+    pub(super) sun_height_above_plane: Length,
+    pub(super) galactic_center_direction: Direction,
+    pub(super) north_galactic_pole: Direction,
+    pub(super) thin_disk: ExponentialDisk,
+    pub(super) thick_disk: ExponentialDisk,
+    pub(super) bulge: Bulge,
+    pub(super) halo: Halo,
+    pub(super) spiral_arms: Option<SpiralArms>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExponentialDisk {
+    pub(super) local_density: f64,
+    pub(super) scale_length: Length,
+    pub(super) scale_height: Length,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Bulge {
+    pub(super) central_density: f64,
+    pub(super) scale_length: Length,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Halo {
+    pub(super) local_density: f64,
+    pub(super) power_law_index: f64,
+    pub(super) flattening: f64,
+    pub(super) core_radius: Length,
+}
+
+// The arm passing closest to the Sun crosses the solar circle at the given phase.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpiralArms {
+    pub(super) number_of_arms: usize,
+    pub(super) pitch_angle: Angle,
+    pub(super) width: Length,
+    pub(super) density_contrast: f64,
+    pub(super) phase_at_sun: Angle,
+}
+
+// Position relative to the galactic center, in light years and radians.
+struct Galactocentric {
+    radius: f64,
+    height: f64,
+    azimuth: f64,
+}
+
+impl ExponentialDisk {
+    pub fn new(local_density: f64, scale_length: Length, scale_height: Length) -> Self {
+        Self {
+            local_density,
+            scale_length,
+            scale_height,
+        }
+    }
+
+    pub fn milky_way_thin_disk() -> Self {
+        Self::new(
+            1.,
+            Length::new::<light_year>(8_500.),
+            Length::new::<light_year>(980.),
+        )
+    }
+
+    pub fn milky_way_thick_disk() -> Self {
+        Self::new(
+            0.12,
+            Length::new::<light_year>(11_700.),
+            Length::new::<light_year>(2_900.),
+        )
+    }
+
+    fn density(&self, radius: f64, height: f64, sun_radius: f64) -> f64 {
+        let scale_length = self.scale_length.get::<light_year>();
+        let scale_height = self.scale_height.get::<light_year>();
+        self.local_density
+            * (-(radius - sun_radius) / scale_length).exp()
+            * (-height.abs() / scale_height).exp()
+    }
+}
+
+impl Bulge {
+    pub fn new(central_density: f64, scale_length: Length) -> Self {
+        Self {
+            central_density,
+            scale_length,
+        }
+    }
+
+    pub fn milky_way() -> Self {
+        Self::new(30., Length::new::<light_year>(2_300.))
+    }
+
+    fn density(&self, distance_to_center: f64) -> f64 {
+        let x = distance_to_center / self.scale_length.get::<light_year>();
+        self.central_density * (-x * x / 2.).exp()
+    }
+}
+
+impl Halo {
+    pub fn new(
+        local_density: f64,
+        power_law_index: f64,
+        flattening: f64,
+        core_radius: Length,
+    ) -> Self {
+        Self {
+            local_density,
+            power_law_index,
+            flattening,
+            core_radius,
+        }
+    }
+
+    pub fn milky_way() -> Self {
+        Self::new(0.005, 2.77, 0.64, Length::new::<light_year>(3_000.))
+    }
+
+    fn density(&self, radius: f64, height: f64, sun_radius: f64) -> f64 {
+        let core_squared = self.core_radius.get::<light_year>().powi(2);
+        let flattened_squared = radius * radius + (height / self.flattening).powi(2);
+        let ratio = (flattened_squared + core_squared) / (sun_radius * sun_radius + core_squared);
+        self.local_density * ratio.powf(-self.power_law_index / 2.)
+    }
+}
+
+impl SpiralArms {
+    pub fn new(
+        number_of_arms: usize,
+        pitch_angle: Angle,
+        width: Length,
+        density_contrast: f64,
+        phase_at_sun: Angle,
+    ) -> Self {
+        Self {
+            number_of_arms,
+            pitch_angle,
+            width,
+            density_contrast,
+            phase_at_sun,
+        }
+    }
+
+    // Four arms, with the Sun roughly halfway between two of them.
+    pub fn milky_way() -> Self {
+        Self::new(
+            4,
+            Angle::new::<degree>(12.),
+            Length::new::<light_year>(1_000.),
+            3.,
+            Angle::new::<degree>(45.),
+        )
+    }
+
+    fn enhancement(&self, position: &Galactocentric, sun_radius: f64) -> f64 {
+        if self.number_of_arms == 0 || position.radius <= 0. {
+            return 1.;
+        }
+        let pitch = self.pitch_angle.get::<radian>();
+        let winding = (position.radius / sun_radius).ln() / pitch.tan();
+        let spacing = 2. * PI / self.number_of_arms as f64;
+        let offset = position.azimuth - self.phase_at_sun.get::<radian>() - winding;
+        let offset_to_closest_arm = offset - spacing * (offset / spacing).round();
+        let distance_to_arm = position.radius * offset_to_closest_arm.abs() * pitch.sin();
+        let width = self.width.get::<light_year>();
+        1. + self.density_contrast * (-distance_to_arm.powi(2) / (2. * width * width)).exp()
+    }
+}
+
+impl GalacticStructure {
+    pub fn milky_way() -> Self {
+        Self {
+            sun_distance_to_center: Length::new::<light_year>(26_700.),
+            sun_height_above_plane: Length::new::<light_year>(68.),
+            galactic_center_direction: EarthEquatorial::new(
+                Angle::new::<degree>(266.405),
+                Angle::new::<degree>(-28.936),
+            )
+            .to_direction(),
+            north_galactic_pole: EarthEquatorial::new(
+                Angle::new::<degree>(192.859),
+                Angle::new::<degree>(27.128),
+            )
+            .to_direction(),
+            thin_disk: ExponentialDisk::milky_way_thin_disk(),
+            thick_disk: ExponentialDisk::milky_way_thick_disk(),
+            bulge: Bulge::milky_way(),
+            halo: Halo::milky_way(),
+            spiral_arms: Some(SpiralArms::milky_way()),
+        }
+    }
+
+    pub fn with_sun_position(
+        mut self,
+        distance_to_center: Length,
+        height_above_plane: Length,
+    ) -> Self {
+        self.sun_distance_to_center = distance_to_center;
+        self.sun_height_above_plane = height_above_plane;
+        self
+    }
+
+    pub fn with_orientation(
+        mut self,
+        galactic_center_direction: Direction,
+        north_galactic_pole: Direction,
+    ) -> Self {
+        self.galactic_center_direction = galactic_center_direction;
+        self.north_galactic_pole = north_galactic_pole;
+        self
+    }
+
+    pub fn with_thin_disk(mut self, thin_disk: ExponentialDisk) -> Self {
+        self.thin_disk = thin_disk;
+        self
+    }
+
+    pub fn with_thick_disk(mut self, thick_disk: ExponentialDisk) -> Self {
+        self.thick_disk = thick_disk;
+        self
+    }
+
+    pub fn with_bulge(mut self, bulge: Bulge) -> Self {
+        self.bulge = bulge;
+        self
+    }
+
+    pub fn with_halo(mut self, halo: Halo) -> Self {
+        self.halo = halo;
+        self
+    }
+
+    pub fn with_spiral_arms(mut self, spiral_arms: Option<SpiralArms>) -> Self {
+        self.spiral_arms = spiral_arms;
+        self
+    }
+
+    pub fn get_sun_distance_to_center(&self) -> Length {
+        self.sun_distance_to_center
+    }
+
+    pub fn get_sun_height_above_plane(&self) -> Length {
+        self.sun_height_above_plane
+    }
+
+    pub fn get_galactic_center_direction(&self) -> &Direction {
+        &self.galactic_center_direction
+    }
+
+    pub fn get_north_galactic_pole(&self) -> &Direction {
+        &self.north_galactic_pole
+    }
+
+    pub fn get_spiral_arms(&self) -> &Option<SpiralArms> {
+        &self.spiral_arms
+    }
+
+    // The height of a position above the galactic plane.
+    pub fn height_above_plane(&self, pos: &Cartesian) -> Length {
+        Length::new::<light_year>(self.galactocentric(pos).height)
+    }
+
+    // The distance of a position to the rotation axis of the galaxy.
+    pub fn distance_to_axis(&self, pos: &Cartesian) -> Length {
+        Length::new::<light_year>(self.galactocentric(pos).radius)
+    }
+
+    pub fn distance_to_center(&self, pos: &Cartesian) -> Length {
+        let position = self.galactocentric(pos);
+        Length::new::<light_year>(position.radius.hypot(position.height))
+    }
+
+    // The stellar density at the position, divided by that at the position of the Sun.
+    pub fn relative_star_density(&self, pos: &Cartesian) -> f64 {
+        self.star_density(&self.galactocentric(pos)) / self.star_density(&self.sun())
+    }
+
+    // The density of star forming regions at the position, divided by that at the position of the Sun.
+    pub fn relative_nursery_density(&self, pos: &Cartesian) -> f64 {
+        self.nursery_density(&self.galactocentric(pos)) / self.nursery_density(&self.sun())
+    }
+
+    /*
+     * Upper bounds of the relative densities within a sphere.
+     * Every component decreases with the distance to the center and to the plane,
+     * so it is evaluated at the smallest distances that occur within the sphere.
+     */
+    pub(super) fn max_relative_star_density_in_sphere(
+        &self,
+        center: &Cartesian,
+        radius: Length,
+    ) -> f64 {
+        self.star_density(&self.closest_to_center(center, radius)) / self.star_density(&self.sun())
+    }
+
+    pub(super) fn max_relative_nursery_density_in_sphere(
+        &self,
+        center: &Cartesian,
+        radius: Length,
+    ) -> f64 {
+        let closest = self.closest_to_center(center, radius);
+        let max_enhancement = self
+            .spiral_arms
+            .map_or(1., |arms| 1. + arms.density_contrast.max(0.));
+        self.thin_disk_density(&closest) * max_enhancement / self.nursery_density(&self.sun())
+    }
+
+    fn star_density(&self, position: &Galactocentric) -> f64 {
+        let sun_radius = self.sun_distance_to_center.get::<light_year>();
+        let distance_to_center = position.radius.hypot(position.height);
+        self.thin_disk_density(position)
+            + self
+                .thick_disk
+                .density(position.radius, position.height, sun_radius)
+            + self.bulge.density(distance_to_center)
+            + self
+                .halo
+                .density(position.radius, position.height, sun_radius)
+    }
+
+    fn nursery_density(&self, position: &Galactocentric) -> f64 {
+        let sun_radius = self.sun_distance_to_center.get::<light_year>();
+        let enhancement = self
+            .spiral_arms
+            .map_or(1., |arms| arms.enhancement(position, sun_radius));
+        self.thin_disk_density(position) * enhancement
+    }
+
+    fn thin_disk_density(&self, position: &Galactocentric) -> f64 {
+        let sun_radius = self.sun_distance_to_center.get::<light_year>();
+        self.thin_disk
+            .density(position.radius, position.height, sun_radius)
+    }
+
+    fn sun(&self) -> Galactocentric {
+        self.galactocentric(&Cartesian::origin())
+    }
+
+    fn closest_to_center(&self, center: &Cartesian, radius: Length) -> Galactocentric {
+        let position = self.galactocentric(center);
+        let radius = radius.get::<light_year>();
+        Galactocentric {
+            radius: (position.radius - radius).max(0.),
+            height: (position.height.abs() - radius).max(0.),
+            azimuth: position.azimuth,
+        }
+    }
+
+    fn galactocentric(&self, pos: &Cartesian) -> Galactocentric {
+        let [towards_center, north, rotation] = self.axes();
+        let sun_radius = self.sun_distance_to_center.get::<light_year>();
+        let pos = in_light_years(pos);
+        // Relative to the point on the galactic plane closest to the Sun, in galactic coordinates.
+        let x = dot(&pos, &towards_center) - sun_radius;
+        let y = dot(&pos, &rotation);
+        let z = dot(&pos, &north) + self.sun_height_above_plane.get::<light_year>();
+        Galactocentric {
+            radius: x.hypot(y),
+            height: z,
+            azimuth: y.atan2(-x),
+        }
+    }
+
+    // Orthonormal axes towards the galactic center, towards the north galactic pole, and completing them.
+    fn axes(&self) -> [[f64; 3]; 3] {
+        let towards_center = normalized(&in_light_years(
+            &self
+                .galactic_center_direction
+                .to_cartesian(Length::new::<light_year>(1.)),
+        ));
+        let pole = in_light_years(
+            &self
+                .north_galactic_pole
+                .to_cartesian(Length::new::<light_year>(1.)),
+        );
+        let overlap = dot(&pole, &towards_center);
+        let north = normalized(&[0, 1, 2].map(|i| pole[i] - overlap * towards_center[i]));
+        let rotation = cross(&north, &towards_center);
+        [towards_center, north, rotation]
+    }
+}
+
+impl Default for GalacticStructure {
+    fn default() -> Self {
+        Self::milky_way()
+    }
+}
+
+fn in_light_years(pos: &Cartesian) -> [f64; 3] {
+    [
+        pos.x.get::<light_year>(),
+        pos.y.get::<light_year>(),
+        pos.z.get::<light_year>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{stars::random::random_stars::random_point_in_sphere, tests::eq_within};
+
+    use super::*;
+
+    fn towards(direction: &Direction, distance: f64) -> Cartesian {
+        direction.to_cartesian(Length::new::<light_year>(distance))
+    }
+
+    #[test]
+    fn densities_are_one_at_the_sun() {
+        let structure = GalacticStructure::milky_way();
+        assert!(eq_within(
+            structure.relative_star_density(&Cartesian::origin()),
+            1.,
+            1e-10
+        ));
+        assert!(eq_within(
+            structure.relative_nursery_density(&Cartesian::origin()),
+            1.,
+            1e-10
+        ));
+    }
+
+    #[test]
+    fn sun_position_is_configurable() {
+        let structure = GalacticStructure::milky_way().with_sun_position(
+            Length::new::<light_year>(20_000.),
+            Length::new::<light_year>(-100.),
+        );
+        let sun = Cartesian::origin();
+        assert!(eq_within(
+            structure.height_above_plane(&sun).get::<light_year>(),
+            -100.,
+            1e-6
+        ));
+        assert!(eq_within(
+            structure.distance_to_center(&sun).get::<light_year>(),
+            20_000f64.hypot(100.),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn galactic_center_lies_in_the_given_direction() {
+        let structure = GalacticStructure::milky_way();
+        let center = towards(structure.get_galactic_center_direction(), 26_700.);
+        assert!(structure.distance_to_center(&center).get::<light_year>() < 100.);
+    }
+
+    #[test]
+    fn density_falls_off_away_from_the_plane() {
+        let structure = GalacticStructure::milky_way();
+        let pole = structure.get_north_galactic_pole().clone();
+        let mut previous = structure.relative_star_density(&Cartesian::origin());
+        for height in [500., 1_000., 3_000., 10_000., 30_000.] {
+            let density = structure.relative_star_density(&towards(&pole, height));
+            assert!(density < previous);
+            previous = density;
+        }
+        assert!(previous < 0.01);
+    }
+
+    #[test]
+    fn density_rises_towards_the_galactic_center() {
+        let structure = GalacticStructure::milky_way();
+        let center = structure.get_galactic_center_direction().clone();
+        let inwards = structure.relative_star_density(&towards(&center, 10_000.));
+        let outwards = structure.relative_star_density(&towards(&center, -10_000.));
+        let bulge = structure.relative_star_density(&towards(&center, 26_700.));
+        assert!(outwards < 1.);
+        assert!(inwards > 1.);
+        assert!(bulge > 10. * inwards);
+    }
+
+    fn on_solar_circle(structure: &GalacticStructure, azimuth: f64) -> Cartesian {
+        let [towards_center, _, rotation] = structure.axes();
+        let sun_radius = structure.sun_distance_to_center.get::<light_year>();
+        let x = sun_radius * (1. - azimuth.cos());
+        let y = sun_radius * azimuth.sin();
+        let [x, y, z] =
+            [0, 1, 2].map(|i| Length::new::<light_year>(x * towards_center[i] + y * rotation[i]));
+        Cartesian::new(x, y, z)
+    }
+
+    #[test]
+    fn nurseries_concentrate_in_spiral_arms() {
+        let azimuths = (0..72).map(|i| i as f64 * 5. / 180. * PI);
+        let structure = GalacticStructure::milky_way();
+        let densities: Vec<f64> = azimuths
+            .clone()
+            .map(|azimuth| {
+                structure.relative_nursery_density(&on_solar_circle(&structure, azimuth))
+            })
+            .collect();
+        let max = densities.iter().cloned().fold(f64::MIN, f64::max);
+        let min = densities.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(max > 2. * min);
+
+        let without_arms = GalacticStructure::milky_way().with_spiral_arms(None);
+        for azimuth in azimuths {
+            let pos = on_solar_circle(&without_arms, azimuth);
+            assert!(eq_within(
+                without_arms.relative_nursery_density(&pos),
+                1.,
+                1e-6
+            ));
+        }
+    }
+
+    #[test]
+    fn maximum_in_sphere_bounds_the_densities() {
+        let structure = GalacticStructure::milky_way();
+        let mut rng = StdRng::seed_from_u64(0);
+        let center = towards(structure.get_galactic_center_direction(), 5_000.);
+        let radius = Length::new::<light_year>(8_000.);
+        let max_stars = structure.max_relative_star_density_in_sphere(&center, radius);
+        let max_nurseries = structure.max_relative_nursery_density_in_sphere(&center, radius);
+        for _ in 0..10_000 {
+            let pos = &center + &random_point_in_sphere(&mut rng, radius);
+            assert!(structure.relative_star_density(&pos) <= max_stars);
+            assert!(structure.relative_nursery_density(&pos) <= max_nurseries);
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    f64::consts::LN_10,
+    fmt::{self, Debug},
+    sync::{Arc, OnceLock},
+};
+
+/*
+ * The distribution of masses with which stars are born.
+ * The density is the number of stars per solar mass, normalised to one star in total,
+ * and vanishes outside of the mass range.
+ * https://en.wikipedia.org/wiki/Initial_mass_function
+ */
+pub trait InitialMassFunction: Debug + Send + Sync {
+    fn density(&self, mass_in_solar_masses: f64) -> f64;
+
+    fn mass_range(&self) -> (f64, f64);
+
+    fn fraction_between(&self, lower: f64, upper: f64) -> f64 {
+        let (min, max) = self.mass_range();
+        integrate_over_log_mass(|m| self.density(m), lower.max(min), upper.min(max))
+    }
+
+    fn mean_mass(&self) -> f64 {
+        let (min, max) = self.mass_range();
+        integrate_over_log_mass(|m| m * self.density(m), min, max)
+    }
+}
+
+// Stars below this mass never fuse hydrogen, and PARSEC does not cover them.
+pub const MIN_MASS_FOR_HYDROGEN_FUSION: f64 = 0.08;
+
+// A power law dN/dm ~ m^-alpha, with alpha changing at the upper edge of each segment.
+#[derive(Debug, Clone, PartialEq)]
+struct BrokenPowerLaw {
+    min_mass: f64,
+    upper_edges: Vec<f64>,
+    slopes: Vec<f64>,
+    prefactors: Vec<f64>,
+    normalization: f64,
+}
+
+impl BrokenPowerLaw {
+    fn new(min_mass: f64, segments: &[(f64, f64)]) -> Self {
+        let upper_edges: Vec<f64> = segments.iter().map(|(edge, _)| *edge).collect();
+        let slopes: Vec<f64> = segments.iter().map(|(_, slope)| *slope).collect();
+        // Keeps the density continuous at the edges.
+        let mut prefactors = vec![1.];
+        for i in 1..slopes.len() {
+            let edge = upper_edges[i - 1];
+            prefactors.push(prefactors[i - 1] * edge.powf(slopes[i] - slopes[i - 1]));
+        }
+        let mut power_law = Self {
+            min_mass,
+            upper_edges,
+            slopes,
+            prefactors,
+            normalization: 1.,
+        };
+        let (min, max) = power_law.mass_range();
+        power_law.normalization = 1. / integrate_over_log_mass(|m| power_law.density(m), min, max);
+        power_law
+    }
+
+    fn density(&self, mass: f64) -> f64 {
+        if mass < self.min_mass {
+            return 0.;
+        }
+        match self.upper_edges.iter().position(|edge| mass <= *edge) {
+            Some(i) => self.normalization * self.prefactors[i] * mass.powf(-self.slopes[i]),
+            None => 0.,
+        }
+    }
+
+    fn mass_range(&self) -> (f64, f64) {
+        let max_mass = self.upper_edges.last().copied().unwrap_or(self.min_mass);
+        (self.min_mass, max_mass)
+    }
+}
+
+/*
+ * https://ui.adsabs.harvard.edu/abs/2001MNRAS.322..231K/abstract
+ * The adjusted version steepens the slope above one solar mass and cuts off the high mass tail above 20 solar masses,
+ * which reproduces the Gaia data better. It is what random stars are generated with by default.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kroupa {
+    power_law: BrokenPowerLaw,
+}
+
+impl Kroupa {
+    pub fn new() -> Self {
+        Self {
+            power_law: BrokenPowerLaw::new(
+                MIN_MASS_FOR_HYDROGEN_FUSION,
+                &[(0.5, 1.3), (150., 2.3)],
+            ),
+        }
+    }
+
+    pub fn adjusted() -> Self {
+        Self {
+            power_law: BrokenPowerLaw::new(
+                MIN_MASS_FOR_HYDROGEN_FUSION,
+                &[(0.5, 1.3), (1., 2.3), (20., 2.7), (1000., 5.)],
+            ),
+        }
+    }
+}
+
+impl Default for Kroupa {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InitialMassFunction for Kroupa {
+    fn density(&self, mass_in_solar_masses: f64) -> f64 {
+        self.power_law.density(mass_in_solar_masses)
+    }
+
+    fn mass_range(&self) -> (f64, f64) {
+        self.power_law.mass_range()
+    }
+}
+
+// https://ui.adsabs.harvard.edu/abs/1955ApJ...121..161S/abstract
+#[derive(Debug, Clone, PartialEq)]
+pub struct Salpeter {
+    power_law: BrokenPowerLaw,
+}
+
+impl Salpeter {
+    pub fn new() -> Self {
+        Self::with_mass_range(0.1, 100.)
+    }
+
+    pub fn with_mass_range(min_mass: f64, max_mass: f64) -> Self {
+        Self {
+            power_law: BrokenPowerLaw::new(min_mass, &[(max_mass, 2.35)]),
+        }
+    }
+}
+
+impl Default for Salpeter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InitialMassFunction for Salpeter {
+    fn density(&self, mass_in_solar_masses: f64) -> f64 {
+        self.power_law.density(mass_in_solar_masses)
+    }
+
+    fn mass_range(&self) -> (f64, f64) {
+        self.power_law.mass_range()
+    }
+}
+
+/*
+ * A lognormal distribution in log(m) below one solar mass, continued by a power law above.
+ * https://ui.adsabs.harvard.edu/abs/2003PASP..115..763C/abstract
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chabrier {
+    min_mass: f64,
+    max_mass: f64,
+    normalization: f64,
+}
+
+const CHABRIER_CHARACTERISTIC_MASS: f64 = 0.079;
+const CHABRIER_WIDTH: f64 = 0.69;
+const CHABRIER_HIGH_MASS_SLOPE: f64 = 1.3;
+
+impl Chabrier {
+    pub fn new() -> Self {
+        Self::with_mass_range(MIN_MASS_FOR_HYDROGEN_FUSION, 100.)
+    }
+
+    pub fn with_mass_range(min_mass: f64, max_mass: f64) -> Self {
+        let mut chabrier = Self {
+            min_mass,
+            max_mass,
+            normalization: 1.,
+        };
+        chabrier.normalization =
+            1. / integrate_over_log_mass(|m| chabrier.density(m), min_mass, max_mass);
+        chabrier
+    }
+
+    // The number of stars per logarithmic mass interval, up to normalization.
+    fn per_log_mass(mass: f64) -> f64 {
+        let lognormal = |m: f64| {
+            let x = (m.log10() - CHABRIER_CHARACTERISTIC_MASS.log10()) / CHABRIER_WIDTH;
+            (-x * x / 2.).exp()
+        };
+        if mass <= 1. {
+            lognormal(mass)
+        } else {
+            lognormal(1.) * mass.powf(-CHABRIER_HIGH_MASS_SLOPE)
+        }
+    }
+}
+
+impl Default for Chabrier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InitialMassFunction for Chabrier {
+    fn density(&self, mass_in_solar_masses: f64) -> f64 {
+        let m = mass_in_solar_masses;
+        if m < self.min_mass || m > self.max_mass {
+            return 0.;
+        }
+        self.normalization * Self::per_log_mass(m) / (m * LN_10)
+    }
+
+    fn mass_range(&self) -> (f64, f64) {
+        (self.min_mass, self.max_mass)
+    }
+}
+
+// Any density given as a function of the mass in solar masses, which is normalised over the mass range.
+#[derive(Clone)]
+pub struct CustomInitialMassFunction {
+    density: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    min_mass: f64,
+    max_mass: f64,
+    normalization: f64,
+}
+
+impl CustomInitialMassFunction {
+    pub fn new<F>(min_mass: f64, max_mass: f64, density: F) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        let normalization = 1. / integrate_over_log_mass(&density, min_mass, max_mass);
+        Self {
+            density: Arc::new(density),
+            min_mass,
+            max_mass,
+            normalization,
+        }
+    }
+}
+
+impl Debug for CustomInitialMassFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomInitialMassFunction")
+            .field("min_mass", &self.min_mass)
+            .field("max_mass", &self.max_mass)
+            .field("normalization", &self.normalization)
+            .finish()
+    }
+}
+
+impl InitialMassFunction for CustomInitialMassFunction {
+    fn density(&self, mass_in_solar_masses: f64) -> f64 {
+        let m = mass_in_solar_masses;
+        if m < self.min_mass || m > self.max_mass {
+            return 0.;
+        }
+        self.normalization * (self.density)(m)
+    }
+
+    fn mass_range(&self) -> (f64, f64) {
+        (self.min_mass, self.max_mass)
+    }
+}
+
+/*
+ * Lets the generation config, which is cloned, compared and serialized, hold any initial mass function.
+ * Two of them are only considered equal if they are the same instance, because custom densities
+ * with the same parameters can still differ. All defaults share one instance.
+ */
+#[derive(Clone)]
+pub struct SharedInitialMassFunction(Arc<dyn InitialMassFunction>);
+
+impl SharedInitialMassFunction {
+    pub fn new<T: InitialMassFunction + 'static>(initial_mass_function: T) -> Self {
+        Self(Arc::new(initial_mass_function))
+    }
+
+    pub fn get(&self) -> &dyn InitialMassFunction {
+        self.0.as_ref()
+    }
+}
+
+impl Default for SharedInitialMassFunction {
+    fn default() -> Self {
+        static DEFAULT: OnceLock<SharedInitialMassFunction> = OnceLock::new();
+        DEFAULT
+            .get_or_init(|| Self::new(Kroupa::adjusted()))
+            .clone()
+    }
+}
+
+impl Debug for SharedInitialMassFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq for SharedInitialMassFunction {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// Simpson's rule in ln(m), which resolves the steep low mass end and the long high mass tail alike.
+fn integrate_over_log_mass<F: Fn(f64) -> f64>(f: F, lower: f64, upper: f64) -> f64 {
+    const STEPS_PER_E_FOLD: f64 = 1_000.;
+    if lower <= 0. || upper <= lower {
+        return 0.;
+    }
+    let (start, end) = (lower.ln(), upper.ln());
+    let steps = (((end - start) * STEPS_PER_E_FOLD).ceil() as usize).max(1) * 2;
+    let h = (end - start) / steps as f64;
+    let integrand = |u: f64| {
+        let m = u.exp();
+        f(m) * m
+    };
+    let mut sum = integrand(start) + integrand(end);
+    for i in 1..steps {
+        let weight = if i % 2 == 1 { 4. } else { 2. };
+        sum += weight * integrand(start + i as f64 * h);
+    }
+    sum * h / 3.
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::eq_within;
+
+    use super::*;
+
+    fn all() -> Vec<Box<dyn InitialMassFunction>> {
+        vec![
+            Box::new(Kroupa::new()),
+            Box::new(Kroupa::adjusted()),
+            Box::new(Salpeter::new()),
+            Box::new(Chabrier::new()),
+            Box::new(CustomInitialMassFunction::new(0.5, 2., |_| 3.)),
+        ]
+    }
+
+    #[test]
+    fn initial_mass_functions_are_normalised() {
+        for imf in all() {
+            let (min, max) = imf.mass_range();
+            let total = imf.fraction_between(0., 2. * max);
+            assert!(
+                eq_within(total, 1., 1e-6),
+                "{:?} integrates to {}",
+                imf,
+                total
+            );
+            assert_eq!(imf.density(min * 0.99), 0.);
+            assert_eq!(imf.density(max * 1.01), 0.);
+        }
+    }
+
+    #[test]
+    fn mean_masses_match_the_literature() {
+        // Salpeter is integrated analytically, the others with an independent numerical integration.
+        let expected: [(Box<dyn InitialMassFunction>, f64); 5] = [
+            (Box::new(Kroupa::new()), 0.5859),
+            (Box::new(Kroupa::adjusted()), 0.4317),
+            (Box::new(Salpeter::new()), 0.3514),
+            (Box::new(Chabrier::new()), 0.6045),
+            (
+                Box::new(CustomInitialMassFunction::new(0.5, 2., |_| 3.)),
+                1.25,
+            ),
+        ];
+        for (imf, mean_mass) in expected {
+            assert!(
+                eq_within(imf.mean_mass(), mean_mass, 1e-3),
+                "{:?} has mean mass {}",
+                imf,
+                imf.mean_mass()
+            );
+        }
+    }
+
+    #[test]
+    fn power_laws_are_continuous() {
+        for imf in [Kroupa::new(), Kroupa::adjusted()] {
+            for edge in [0.5, 1., 20.] {
+                let below = imf.density(edge * (1. - 1e-9));
+                let above = imf.density(edge * (1. + 1e-9));
+                assert!(eq_within(below / above, 1., 1e-6));
+            }
+        }
+    }
+
+    #[test]
+    fn chabrier_is_continuous_at_one_solar_mass() {
+        let chabrier = Chabrier::new();
+        let below = chabrier.density(1. - 1e-9);
+        let above = chabrier.density(1. + 1e-9);
+        assert!(eq_within(below / above, 1., 1e-6));
+    }
+
+    #[test]
+    fn shared_initial_mass_functions_compare_by_instance() {
+        assert_eq!(
+            SharedInitialMassFunction::default(),
+            SharedInitialMassFunction::default()
+        );
+        let flat = CustomInitialMassFunction::new(0.1, 10., |_| 1.);
+        let steep = CustomInitialMassFunction::new(0.1, 10., |m| m.powi(-3));
+        let shared = SharedInitialMassFunction::new(flat);
+        assert_eq!(shared, shared.clone());
+        assert_ne!(shared, SharedInitialMassFunction::new(steep));
+    }
+}
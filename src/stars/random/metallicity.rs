@@ -0,0 +1,173 @@
+use std::f64::consts::PI;
+
+use astro_units::time::gigayear;
+use parsec_access::getters::get_closest_metallicity_index_from_mass_fraction;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uom::si::f64::Time;
+
+/*
+ * Mass fraction of elements heavier than helium of the Sun, which corresponds to [Fe/H] = 0.
+ * https://ui.adsabs.harvard.edu/abs/2012MNRAS.427..127B/abstract
+ */
+pub const SOLAR_METALLICITY: f64 = 0.0152;
+
+// Selects the PARSEC grid that was used for all random stars before metallicity became configurable.
+pub const DEFAULT_METALLICITY: f64 = 0.01;
+
+/*
+ * The metallicity of randomly generated stars, given as mass fraction Z of elements heavier than helium.
+ * It is either fixed, for example low for halo populations and high for the bulge,
+ * or drawn from a linear age-metallicity relation with gaussian scatter in [Fe/H].
+ * https://en.wikipedia.org/wiki/Metallicity
+ * https://ui.adsabs.harvard.edu/abs/2011A%26A...530A.138C/abstract
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Metallicity {
+    Fixed(f64),
+    AgeMetallicityRelation {
+        present_day_iron_abundance: f64,
+        iron_abundance_change_per_gigayear: f64,
+        scatter: f64,
+    },
+}
+
+impl Metallicity {
+    pub fn solar() -> Self {
+        Metallicity::Fixed(SOLAR_METALLICITY)
+    }
+
+    pub fn from_iron_abundance(iron_abundance: f64) -> Self {
+        Metallicity::Fixed(iron_abundance_to_mass_fraction(iron_abundance))
+    }
+
+    pub fn milky_way_thin_disk() -> Self {
+        Metallicity::AgeMetallicityRelation {
+            present_day_iron_abundance: 0.,
+            iron_abundance_change_per_gigayear: -0.03,
+            scatter: 0.15,
+        }
+    }
+
+    pub fn mass_fraction<R: Rng + ?Sized>(&self, age: Time, rng: &mut R) -> f64 {
+        match self {
+            Metallicity::Fixed(mass_fraction) => *mass_fraction,
+            Metallicity::AgeMetallicityRelation {
+                present_day_iron_abundance,
+                iron_abundance_change_per_gigayear,
+                scatter,
+            } => {
+                let mean = present_day_iron_abundance
+                    + iron_abundance_change_per_gigayear * age.get::<gigayear>();
+                iron_abundance_to_mass_fraction(mean + scatter * standard_normal(rng))
+            }
+        }
+    }
+
+    // The PARSEC grids that stars generated with this metallicity can end up on.
+    pub(crate) fn possible_grid_indices(&self) -> Vec<usize> {
+        match self {
+            Metallicity::Fixed(mass_fraction) => vec![metallicity_grid_index(*mass_fraction)],
+            Metallicity::AgeMetallicityRelation { .. } => {
+                (metallicity_grid_index(0.)..=metallicity_grid_index(1.)).collect()
+            }
+        }
+    }
+}
+
+impl Default for Metallicity {
+    fn default() -> Self {
+        Metallicity::Fixed(DEFAULT_METALLICITY)
+    }
+}
+
+// Neglects the enhancement of alpha elements in old populations.
+pub fn iron_abundance_to_mass_fraction(iron_abundance: f64) -> f64 {
+    SOLAR_METALLICITY * 10f64.powf(iron_abundance)
+}
+
+pub fn mass_fraction_to_iron_abundance(mass_fraction: f64) -> f64 {
+    (mass_fraction / SOLAR_METALLICITY).log10()
+}
+
+pub(crate) fn metallicity_grid_index(mass_fraction: f64) -> usize {
+    get_closest_metallicity_index_from_mass_fraction(mass_fraction)
+}
+
+// https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform
+pub(super) fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = 1. - rng.random::<f64>();
+    let u2: f64 = rng.random();
+    (-2. * u1.ln()).sqrt() * (2. * PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::tests::eq;
+
+    use super::*;
+
+    #[test]
+    fn default_metallicity_selects_the_previously_used_grid() {
+        assert_eq!(metallicity_grid_index(DEFAULT_METALLICITY), 8);
+        assert_eq!(
+            Metallicity::default(),
+            Metallicity::Fixed(DEFAULT_METALLICITY)
+        );
+    }
+
+    #[test]
+    fn solar_metallicity_has_vanishing_iron_abundance() {
+        assert!(eq(mass_fraction_to_iron_abundance(SOLAR_METALLICITY), 0.));
+        assert!((mass_fraction_to_iron_abundance(DEFAULT_METALLICITY) + 0.18).abs() < 0.01);
+    }
+
+    #[test]
+    fn iron_abundance_roundtrip() {
+        for iron_abundance in [-2., -0.5, 0., 0.3] {
+            let mass_fraction = iron_abundance_to_mass_fraction(iron_abundance);
+            assert!(eq(
+                mass_fraction_to_iron_abundance(mass_fraction),
+                iron_abundance
+            ));
+        }
+    }
+
+    #[test]
+    fn metal_poor_stars_use_a_lower_grid() {
+        let poor = Metallicity::from_iron_abundance(-1.5).possible_grid_indices();
+        let rich = Metallicity::from_iron_abundance(0.4).possible_grid_indices();
+        assert!(poor[0] < metallicity_grid_index(SOLAR_METALLICITY));
+        assert!(rich[0] > metallicity_grid_index(SOLAR_METALLICITY));
+    }
+
+    #[test]
+    fn old_stars_are_metal_poorer_on_average() {
+        let relation = Metallicity::milky_way_thin_disk();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mean_iron_abundance = |age: f64, rng: &mut StdRng| {
+            let n = 10_000;
+            (0..n)
+                .map(|_| {
+                    mass_fraction_to_iron_abundance(
+                        relation.mass_fraction(Time::new::<gigayear>(age), rng),
+                    )
+                })
+                .sum::<f64>()
+                / n as f64
+        };
+        let young = mean_iron_abundance(0.1, &mut rng);
+        let old = mean_iron_abundance(8., &mut rng);
+        assert!(young.abs() < 0.02);
+        assert!((old + 0.24).abs() < 0.02);
+    }
+
+    #[test]
+    fn age_metallicity_relation_covers_several_grids() {
+        let indices = Metallicity::milky_way_thin_disk().possible_grid_indices();
+        assert!(indices.len() > 1);
+        assert!(indices.contains(&metallicity_grid_index(SOLAR_METALLICITY)));
+    }
+}
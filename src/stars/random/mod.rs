@@ -1,3 +1,11 @@
+pub mod chunked_galaxy;
+pub mod cluster;
+pub mod config;
+pub mod galactic_structure;
+pub mod initial_mass_function;
+pub mod metallicity;
+pub mod multiplicity;
 mod params;
-mod parsec;
+pub(crate) mod parsec;
 pub mod random_stars;
+pub mod star_formation_history;
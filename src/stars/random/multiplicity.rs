@@ -0,0 +1,300 @@
+use std::f64::consts::PI;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use uom::si::{
+    angle::radian,
+    f64::{Angle, Length, Mass, Time},
+    length::meter,
+    time::{day, second, year},
+};
+
+use astro_units::mass::solar_mass;
+
+use crate::{
+    error::AstroUtilError,
+    planets::{kepler_orbit::gravitational_parameter, orbit_parameters::OrbitParameters},
+    stars::{data::StarData, multiple::MultipleStar},
+};
+
+use super::{
+    config::GalaxyGenerationConfig,
+    metallicity::{standard_normal, DEFAULT_METALLICITY},
+    parsec::getters::get_companion,
+    random_stars::{derive_seed, generate_random_stars_with_config},
+};
+
+/*
+ * The fraction of stars with at least one companion grows with mass,
+ * from a fifth of the brown dwarf boundary stars to more than two thirds of the O stars.
+ * https://ui.adsabs.harvard.edu/abs/2013ARA%26A..51..269D/abstract
+ */
+const MULTIPLICITY_FRACTIONS: [(f64, f64); 6] = [
+    (0.1, 0.22),
+    (0.6, 0.26),
+    (1.5, 0.44),
+    (5., 0.50),
+    (16., 0.60),
+    (f64::INFINITY, 0.69),
+];
+
+/*
+ * Solar type binaries have periods log-normally distributed around 300 years,
+ * while O stars prefer close companions, following Öpik's law in log P with a slope of -0.55.
+ * https://ui.adsabs.harvard.edu/abs/2010ApJS..190....1R/abstract
+ * https://ui.adsabs.harvard.edu/abs/2012Sci...337..444S/abstract
+ */
+const MIN_MASS_FOR_CLOSE_BINARIES: f64 = 16.;
+const MEAN_LOG_PERIOD_IN_DAYS: f64 = 5.03;
+const SIGMA_LOG_PERIOD_IN_DAYS: f64 = 2.28;
+const MAX_LOG_PERIOD_IN_DAYS: f64 = 10.;
+const MIN_MASSIVE_LOG_PERIOD_IN_DAYS: f64 = 0.15;
+const MAX_MASSIVE_LOG_PERIOD_IN_DAYS: f64 = 5.5;
+const MASSIVE_LOG_PERIOD_EXPONENT: f64 = -0.55;
+
+// The mass ratio is roughly flat, down to the lightest companions that surveys detect.
+const MIN_MASS_RATIO: f64 = 0.1;
+
+// Tides circularise the closest orbits, the wider ones have roughly flat eccentricities.
+// https://en.wikipedia.org/wiki/Tidal_circularization
+const MAX_CIRCULARISED_PERIOD_IN_DAYS: f64 = 10.;
+const MAX_ECCENTRICITY: f64 = 0.8;
+
+/*
+ * The same stars as generated with the same config and seed, each paired up with a companion according to its mass.
+ * The companions are drawn from their own generator, so they do not change which stars are generated.
+ */
+pub fn generate_random_multiple_stars_with_config(
+    max_distance: Length,
+    config: &GalaxyGenerationConfig,
+    seed: u64,
+) -> Result<Vec<MultipleStar>, AstroUtilError> {
+    let stars = generate_random_stars_with_config(max_distance, config, seed)?;
+    let mut rng = StdRng::seed_from_u64(derive_seed(seed, u64::MAX));
+    Ok(stars
+        .into_iter()
+        .map(|star| with_random_companion(star, config, &mut rng))
+        .collect())
+}
+
+/*
+ * The companion forms together with the star, which is its heavier component.
+ * Stars without mass or age remain single, because no companion can be derived for them.
+ */
+pub fn with_random_companion<R: Rng + ?Sized>(
+    primary: StarData,
+    config: &GalaxyGenerationConfig,
+    rng: &mut R,
+) -> MultipleStar {
+    let (primary_mass, age) = match (primary.get_mass_at_epoch(), primary.get_age_at_epoch()) {
+        (Some(mass), Some(age)) => (mass, age),
+        _ => return MultipleStar::new(primary),
+    };
+    if rng.random::<f64>() >= multiplicity_fraction(primary_mass) {
+        return MultipleStar::new(primary);
+    }
+    // Both stars were born together, so the mass ratio holds between their initial masses.
+    let primary_initial_mass = primary
+        .get_evolution()
+        .get_initial_mass()
+        .unwrap_or(primary_mass);
+    let mass_ratio = MIN_MASS_RATIO + rng.random::<f64>() * (1. - MIN_MASS_RATIO);
+    let metallicity = primary.get_metallicity().unwrap_or(DEFAULT_METALLICITY);
+    let mut companion = get_companion(
+        config,
+        metallicity,
+        primary_initial_mass * mass_ratio,
+        age,
+        primary.get_pos_at_epoch().clone(),
+    );
+    if !primary.get_name().is_empty() {
+        companion.set_name(format!("{} B", primary.get_name()));
+    }
+    let companion_mass = companion
+        .get_mass_at_epoch()
+        .unwrap_or(primary_initial_mass * mass_ratio);
+    let period = random_period(primary_mass, rng);
+    let orbit = random_orbit(period, primary_mass, companion_mass, rng);
+    MultipleStar::new(primary).with_companion(companion, orbit)
+}
+
+fn multiplicity_fraction(mass: Mass) -> f64 {
+    let mass = mass.get::<solar_mass>();
+    MULTIPLICITY_FRACTIONS
+        .iter()
+        .find(|(max_mass, _)| mass < *max_mass)
+        .map_or(0., |(_, fraction)| *fraction)
+}
+
+fn random_period<R: Rng + ?Sized>(primary_mass: Mass, rng: &mut R) -> Time {
+    let log_period = if primary_mass.get::<solar_mass>() >= MIN_MASS_FOR_CLOSE_BINARIES {
+        // Inverse transform sampling of the power law in log P.
+        let exponent = MASSIVE_LOG_PERIOD_EXPONENT + 1.;
+        let min = MIN_MASSIVE_LOG_PERIOD_IN_DAYS.powf(exponent);
+        let max = MAX_MASSIVE_LOG_PERIOD_IN_DAYS.powf(exponent);
+        (min + rng.random::<f64>() * (max - min)).powf(1. / exponent)
+    } else {
+        let mut log_period = -1.;
+        while !(0. ..=MAX_LOG_PERIOD_IN_DAYS).contains(&log_period) {
+            log_period = MEAN_LOG_PERIOD_IN_DAYS + SIGMA_LOG_PERIOD_IN_DAYS * standard_normal(rng);
+        }
+        log_period
+    };
+    Time::new::<day>(10f64.powf(log_period))
+}
+
+// The orbital planes are oriented isotropically.
+fn random_orbit<R: Rng + ?Sized>(
+    period: Time,
+    primary_mass: Mass,
+    companion_mass: Mass,
+    rng: &mut R,
+) -> OrbitParameters {
+    let mu = gravitational_parameter(primary_mass, companion_mass);
+    let period_in_seconds = period.get::<second>();
+    let semi_major_axis = (mu * period_in_seconds.powi(2) / (4. * PI * PI)).cbrt();
+    let eccentricity = if period < Time::new::<day>(MAX_CIRCULARISED_PERIOD_IN_DAYS) {
+        0.
+    } else {
+        rng.random::<f64>() * MAX_ECCENTRICITY
+    };
+    let random_angle = |rng: &mut R| Angle::new::<radian>(2. * PI * rng.random::<f64>());
+    let inclination = Angle::new::<radian>((1. - 2. * rng.random::<f64>()).acos());
+    OrbitParameters::new(
+        Length::new::<meter>(semi_major_axis),
+        eccentricity,
+        inclination,
+        random_angle(rng),
+        random_angle(rng),
+        random_angle(rng),
+        Time::new::<year>(0.),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_coords::cartesian::Cartesian;
+    use astro_units::time::gigayear;
+    use uom::si::length::light_year;
+
+    use super::*;
+    use crate::stars::random::{
+        metallicity::metallicity_grid_index, parsec::interpolation::InterpolatedTrack,
+    };
+
+    fn star(mass: f64, age: Time) -> StarData {
+        get_companion(
+            &GalaxyGenerationConfig::default(),
+            DEFAULT_METALLICITY,
+            Mass::new::<solar_mass>(mass),
+            age,
+            Cartesian::origin(),
+        )
+    }
+
+    fn random_systems(mass: f64, age: Time, number: usize) -> Vec<MultipleStar> {
+        let config = GalaxyGenerationConfig::default();
+        let mut rng = StdRng::seed_from_u64(0);
+        let primary = star(mass, age);
+        (0..number)
+            .map(|_| with_random_companion(primary.clone(), &config, &mut rng))
+            .collect()
+    }
+
+    fn median_log_period_in_days(systems: &[MultipleStar]) -> f64 {
+        let mut log_periods: Vec<f64> = systems
+            .iter()
+            .filter(|system| !system.is_single())
+            .map(|system| {
+                system.get_companions()[0]
+                    .get_orbital_period(system.get_primary())
+                    .unwrap()
+                    .get::<day>()
+                    .log10()
+            })
+            .collect();
+        log_periods.sort_by(f64::total_cmp);
+        log_periods[log_periods.len() / 2]
+    }
+
+    #[test]
+    fn about_half_of_sunlike_stars_have_a_companion() {
+        assert!(parsec_access::getters::is_data_ready());
+        let systems = random_systems(1., Time::new::<gigayear>(4.6), 2_000);
+        let binaries = systems.iter().filter(|s| !s.is_single()).count();
+        let fraction = binaries as f64 / systems.len() as f64;
+        assert!((0.4..0.48).contains(&fraction));
+        assert!((4.7..5.4).contains(&median_log_period_in_days(&systems)));
+    }
+
+    #[test]
+    fn companions_are_lighter_and_of_the_same_age() {
+        assert!(parsec_access::getters::is_data_ready());
+        let age = Time::new::<gigayear>(1.);
+        for system in random_systems(2., age, 200) {
+            for companion in system.get_companions() {
+                let star = companion.get_star();
+                assert!(star.get_mass_at_epoch() <= system.get_primary().get_mass_at_epoch());
+                assert_eq!(star.get_age_at_epoch(), Some(age));
+            }
+        }
+    }
+
+    #[test]
+    fn mass_ratios_refer_to_the_initial_mass_of_evolved_primaries() {
+        assert!(parsec_access::getters::is_data_ready());
+        let initial_mass = Mass::new::<solar_mass>(5.);
+        let track =
+            InterpolatedTrack::new(metallicity_grid_index(DEFAULT_METALLICITY), initial_mass);
+        let age = track.lifetime * 0.999;
+        let primary = star(5., age);
+        let primary_mass = primary.get_mass_at_epoch().unwrap();
+        let primary_initial_mass = primary.get_evolution().get_initial_mass().unwrap();
+        assert!(primary_mass < primary_initial_mass);
+        let companion_initial_masses: Vec<Mass> = random_systems(5., age, 200)
+            .iter()
+            .flat_map(|system| system.get_companions().to_vec())
+            .map(|companion| {
+                companion
+                    .get_star()
+                    .get_evolution()
+                    .get_initial_mass()
+                    .unwrap()
+            })
+            .collect();
+        assert!(companion_initial_masses
+            .iter()
+            .all(|mass| *mass <= primary_initial_mass));
+        assert!(companion_initial_masses
+            .iter()
+            .any(|mass| *mass > primary_mass));
+    }
+
+    #[test]
+    fn massive_stars_have_more_and_closer_companions() {
+        assert!(parsec_access::getters::is_data_ready());
+        let young = Time::new::<gigayear>(0.001);
+        let massive = random_systems(30., young, 2_000);
+        let sunlike = random_systems(1., young, 2_000);
+        let binaries = |systems: &[MultipleStar]| systems.iter().filter(|s| !s.is_single()).count();
+        assert!(binaries(&massive) > binaries(&sunlike));
+        assert!(median_log_period_in_days(&massive) < 3.);
+    }
+
+    #[test]
+    fn generated_multiple_stars_contain_the_same_primaries() {
+        assert!(parsec_access::getters::is_data_ready());
+        let config = GalaxyGenerationConfig::default();
+        let max_distance = Length::new::<light_year>(50.);
+        let stars = generate_random_stars_with_config(max_distance, &config, 7).unwrap();
+        let systems = generate_random_multiple_stars_with_config(max_distance, &config, 7).unwrap();
+        assert_eq!(stars.len(), systems.len());
+        for (star, system) in stars.iter().zip(systems.iter()) {
+            let primary = system.get_primary();
+            assert_eq!(star.get_pos_at_epoch(), primary.get_pos_at_epoch());
+            assert_eq!(
+                star.get_luminous_intensity_at_epoch(),
+                primary.get_luminous_intensity_at_epoch()
+            );
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use astro_coords::cartesian::Cartesian;
 use astro_units::length::solar_radius;
-use uom::si::f64::{Length, Time};
+use uom::si::{
+    f64::{Length, LuminousIntensity, Time},
+    luminous_intensity::candela,
+};
 
 use super::{
-    parsec::getters::get_most_luminous_intensity_possible,
-    random_stars::{
-        age_of_milky_way_thin_disk, dimmest_illuminance, number_in_sphere, stellar_velocity,
-        NUMBER_OF_STARS_FORMED_IN_NURSERY, STARS_PER_LY_CUBED,
-    },
+    cluster::StarCluster, config::GalaxyGenerationConfig, metallicity::metallicity_grid_index,
+    parsec::getters::get_most_luminous_intensity_possible, random_stars::number_in_sphere,
 };
 
 pub(super) struct GenerationParams {
@@ -15,46 +15,64 @@ pub(super) struct GenerationParams {
     pub(super) max_age: Time,
     pub(super) radius: Length,
     pub(super) number: usize,
+    pub(super) metallicity: Option<f64>, // None if every star draws its own metallicity
 }
 
 impl GenerationParams {
-    pub(super) fn old_stars(max_distance: Length) -> Self {
-        let pos = Cartesian::origin();
-        let max_age = age_of_milky_way_thin_disk();
+    pub(super) fn old_stars(max_distance: Length, config: &GalaxyGenerationConfig) -> Self {
+        let pos = config.observer_position.clone();
+        let max_age = config.age_of_disk;
         let radius = max_distance;
-        let number = number_in_sphere(STARS_PER_LY_CUBED, radius);
+        let number = number_in_sphere(config.stars_per_cubic_light_year, radius);
         GenerationParams {
             pos,
             max_age,
             radius,
             number,
+            metallicity: None,
         }
     }
 
-    pub(super) fn nursery(pos: Cartesian, max_age: Time) -> Self {
-        let radius = stellar_velocity() * max_age;
-        let number = NUMBER_OF_STARS_FORMED_IN_NURSERY;
+    pub(super) fn cluster(cluster: &StarCluster) -> Self {
         GenerationParams {
-            pos,
-            max_age,
-            radius,
-            number,
+            pos: cluster.pos.clone(),
+            max_age: cluster.age,
+            radius: cluster.extent(),
+            number: cluster.number_of_stars,
+            metallicity: Some(cluster.metallicity),
         }
     }
 
-    pub(super) fn adjust_distance_for_performance(&mut self) {
+    // Beyond this distance from the observer, none of the stars can be visible.
+    pub(super) fn visibility_range(&self, config: &GalaxyGenerationConfig) -> Length {
+        let metallicity_indices = match self.metallicity {
+            Some(mass_fraction) => vec![metallicity_grid_index(mass_fraction)],
+            None => config.metallicity.possible_grid_indices(),
+        };
+        let most_luminous_intensity = metallicity_indices
+            .into_iter()
+            .map(|index| get_most_luminous_intensity_possible(index, self.max_age, config))
+            .fold(LuminousIntensity::new::<candela>(0.), |a, b| a.max(b));
+        (most_luminous_intensity / config.dimmest_illuminance).sqrt()
+    }
+
+    pub(super) fn is_out_of_sight(&self, config: &GalaxyGenerationConfig) -> bool {
+        let distance_to_observer = self.pos.distance(&config.observer_position);
+        distance_to_observer - self.radius > self.visibility_range(config)
+    }
+
+    pub(super) fn adjust_distance_for_performance(&mut self, config: &GalaxyGenerationConfig) {
         let original_radius = self.radius;
-        let most_luminous_intensity = get_most_luminous_intensity_possible(self.max_age);
-        let required_distance = (most_luminous_intensity / dimmest_illuminance()).sqrt();
-        let distance_to_origin = self.pos.length();
-        let closest_possible = distance_to_origin - self.radius;
-        let farthest_possible = distance_to_origin + self.radius;
-        if distance_to_origin > self.radius {
+        let required_distance = self.visibility_range(config);
+        let distance_to_observer = self.pos.distance(&config.observer_position);
+        let closest_possible = distance_to_observer - self.radius;
+        let farthest_possible = distance_to_observer + self.radius;
+        if distance_to_observer > self.radius {
             if closest_possible > required_distance {
                 self.radius = Length::new::<solar_radius>(0.);
             }
         } else if farthest_possible > required_distance {
-            self.radius = required_distance - distance_to_origin
+            self.radius = required_distance - distance_to_observer
         }
         self.number = (self.number as f64 * (self.radius / original_radius).value.powi(3)) as usize;
     }
@@ -67,25 +85,36 @@ mod tests {
     use parsec_access::getters::is_data_ready;
     use uom::si::length::{light_year, meter};
 
-    use crate::tests::{eq_within, TEST_ACCURACY};
+    use crate::{
+        stars::random::metallicity::DEFAULT_METALLICITY,
+        tests::{eq_within, TEST_ACCURACY},
+    };
 
     use super::*;
 
+    fn nursery(pos: Cartesian, max_age: Time, config: &GalaxyGenerationConfig) -> GenerationParams {
+        let cluster =
+            StarCluster::from_nursery(String::new(), pos, max_age, DEFAULT_METALLICITY, config);
+        GenerationParams::cluster(&cluster)
+    }
+
     #[test]
     fn large_distance_for_old_stars_is_adjusted() {
         let max_distance = Length::new::<light_year>(10_000.);
-        let mut params = GenerationParams::old_stars(max_distance);
+        let mut params =
+            GenerationParams::old_stars(max_distance, &GalaxyGenerationConfig::default());
         assert!(is_data_ready());
-        params.adjust_distance_for_performance();
+        params.adjust_distance_for_performance(&GalaxyGenerationConfig::default());
         assert!(params.radius < max_distance);
     }
 
     #[test]
     fn short_distance_for_old_stars_is_not_adjusted() {
         let max_distance = Length::new::<light_year>(10.);
-        let mut params = GenerationParams::old_stars(max_distance);
+        let mut params =
+            GenerationParams::old_stars(max_distance, &GalaxyGenerationConfig::default());
         assert!(is_data_ready());
-        params.adjust_distance_for_performance();
+        params.adjust_distance_for_performance(&GalaxyGenerationConfig::default());
         assert!(eq_within(
             params.radius.get::<light_year>(),
             max_distance.get::<light_year>(),
@@ -95,11 +124,11 @@ mod tests {
 
     #[test]
     fn old_stars_far_away_are_adjusted() {
-        let max_age = age_of_milky_way_thin_disk();
+        let max_age = GalaxyGenerationConfig::default().age_of_disk;
         let origin = Direction::Z.to_cartesian(Length::new::<light_year>(10_000.));
-        let mut params = GenerationParams::nursery(origin, max_age);
+        let mut params = nursery(origin, max_age, &GalaxyGenerationConfig::default());
         assert!(is_data_ready());
-        params.adjust_distance_for_performance();
+        params.adjust_distance_for_performance(&GalaxyGenerationConfig::default());
         assert!(params.radius.get::<meter>() < 1.);
     }
 
@@ -107,10 +136,53 @@ mod tests {
     fn young_stars_far_away_are_not_adjusted() {
         let max_age = Time::new::<kiloyear>(10.);
         let origin = Direction::Z.to_cartesian(Length::new::<light_year>(1000.));
-        let mut params = GenerationParams::nursery(origin, max_age);
+        let mut params = nursery(origin, max_age, &GalaxyGenerationConfig::default());
+        let max_distance = params.radius;
+        assert!(is_data_ready());
+        params.adjust_distance_for_performance(&GalaxyGenerationConfig::default());
+        assert!(eq_within(
+            params.radius.get::<light_year>(),
+            max_distance.get::<light_year>(),
+            TEST_ACCURACY
+        ));
+    }
+
+    #[test]
+    fn nursery_close_to_a_distant_observer_is_not_adjusted() {
+        let max_age = Time::new::<kiloyear>(10.);
+        let observer = Direction::Z.to_cartesian(Length::new::<light_year>(20_000.));
+        let config = GalaxyGenerationConfig::default().with_observer_position(observer.clone());
+        let origin = &observer + &Direction::X.to_cartesian(Length::new::<light_year>(1000.));
+        let mut params = nursery(origin, max_age, &config);
         let max_distance = params.radius;
         assert!(is_data_ready());
-        params.adjust_distance_for_performance();
+        params.adjust_distance_for_performance(&config);
+        assert!(eq_within(
+            params.radius.get::<light_year>(),
+            max_distance.get::<light_year>(),
+            TEST_ACCURACY
+        ));
+    }
+
+    #[test]
+    fn old_stars_near_the_origin_are_dropped_for_a_distant_observer() {
+        let max_age = GalaxyGenerationConfig::default().age_of_disk;
+        let observer = Direction::Z.to_cartesian(Length::new::<light_year>(10_000.));
+        let config = GalaxyGenerationConfig::default().with_observer_position(observer);
+        let mut params = nursery(Cartesian::origin(), max_age, &config);
+        assert!(is_data_ready());
+        params.adjust_distance_for_performance(&config);
+        assert!(params.radius.get::<meter>() < 1.);
+    }
+
+    #[test]
+    fn old_stars_are_centered_on_the_observer() {
+        let observer = Direction::X.to_cartesian(Length::new::<light_year>(3_000.));
+        let config = GalaxyGenerationConfig::default().with_observer_position(observer.clone());
+        let max_distance = Length::new::<light_year>(10.);
+        let mut params = GenerationParams::old_stars(max_distance, &config);
+        params.adjust_distance_for_performance(&config);
+        assert_eq!(params.pos, observer);
         assert!(eq_within(
             params.radius.get::<light_year>(),
             max_distance.get::<light_year>(),
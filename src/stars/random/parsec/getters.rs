@@ -1,83 +1,138 @@
 use astro_coords::cartesian::Cartesian;
-use astro_units::luminous_intensity::{
-    absolute_magnitude_to_luminous_intensity, solar_luminous_intensity,
-};
-use astro_units::mass::solar_mass;
+use astro_units::luminous_intensity::solar_luminous_intensity;
 use astro_units::time::kiloyear;
 use parsec_access::getters::{
     get_closest_age_index, get_masses_in_solar, get_parameters, get_trajectory,
 };
-use parsec_access::line::ParsecLine;
-use parsec_access::trajectory::Trajectory;
-use uom::si::f64::{LuminousIntensity, Mass, Time};
+use uom::si::f64::{Area, LuminousIntensity, Mass, Time};
 use uom::si::luminous_intensity::candela;
 use uom::si::time::year;
 
+use crate::kinematics::velocity_vector::VelocityVector;
 use crate::stars::data::StarData;
-use crate::stars::evolution::{StarDataEvolution, StarDataLifestageEvolution};
-use crate::stars::fate::{StarFate, TYPE_II_SUPERNOVA_PEAK_MAGNITUDE};
+use crate::stars::evolution::{EvolutionMode, StarDataEvolution, StarDataLifestageEvolution};
+use crate::stars::extinction::transmission;
+use crate::stars::fate::StarFate;
 use crate::stars::physical_parameters::StarPhysicalParameters;
-use crate::stars::random::random_stars::{dimmest_illuminance, get_min_age, METALLICITY_INDEX};
+use crate::stars::random::config::GalaxyGenerationConfig;
+use crate::stars::random::metallicity::metallicity_grid_index;
+use crate::stars::random::random_stars::get_min_age;
+
+use super::interpolation::{InterpolatedLine, InterpolatedTrack};
+
+// The lifestage evolution is the local slope of the track, taken over this fraction of the lifetime.
+const LIFESTAGE_STEP_FRACTION: f64 = 1e-3;
 
 pub(crate) fn get_star_data_if_visible(
-    mass_index: usize,
+    config: &GalaxyGenerationConfig,
+    metallicity: f64,
+    initial_mass: Mass,
     age: Time,
     pos: Cartesian,
 ) -> Option<StarData> {
-    let trajectory = get_trajectory(METALLICITY_INDEX, mass_index);
-    let was_alive_10_millenia_ago = age - Time::new::<kiloyear>(10.) < trajectory.lifetime;
+    let metallicity_index = metallicity_grid_index(metallicity);
+    let track = InterpolatedTrack::new(metallicity_index, initial_mass);
+    let was_alive_10_millenia_ago = age - Time::new::<kiloyear>(10.) < track.lifetime;
     if !was_alive_10_millenia_ago {
         return None;
     }
 
-    let age_index = get_closest_age_index(METALLICITY_INDEX, mass_index, age);
-    let params = get_parameters(METALLICITY_INDEX, mass_index, age_index);
+    let line = track.at_age(age);
 
-    let is_currently_visible = is_visible(params, &pos);
+    let is_currently_visible = is_visible(config, &line, &pos);
     if is_currently_visible {
-        return Some(get_star(mass_index, age, pos));
+        return Some(get_star(config, metallicity, &track, age, pos));
     }
-    let has_visible_death_within_10k_years = is_visible_supernova(trajectory, &pos)
-        && age + Time::new::<kiloyear>(10.) > trajectory.lifetime;
+    let has_visible_death_within_10k_years = is_visible_supernova(config, track.initial_mass, &pos)
+        && age + Time::new::<kiloyear>(10.) > track.lifetime;
     if has_visible_death_within_10k_years {
-        return Some(get_star(mass_index, age, pos));
+        return Some(get_star(config, metallicity, &track, age, pos));
     }
     None
 }
 
-fn is_visible(line: &ParsecLine, pos: &Cartesian) -> bool {
-    let min_luminous_intensity = dimmest_illuminance() * pos.length_squared();
-    line.luminosity_in_solar * solar_luminous_intensity() >= min_luminous_intensity
+fn is_visible(config: &GalaxyGenerationConfig, line: &InterpolatedLine, pos: &Cartesian) -> bool {
+    is_visible_with_luminous_intensity(
+        config,
+        line.luminosity_in_solar * solar_luminous_intensity(),
+        pos,
+    )
 }
 
-fn is_visible_supernova(trajectory: &Trajectory, pos: &Cartesian) -> bool {
-    if trajectory.initial_mass < Mass::new::<solar_mass>(8.) {
+fn is_visible_supernova(
+    config: &GalaxyGenerationConfig,
+    initial_mass: Mass,
+    pos: &Cartesian,
+) -> bool {
+    match StarFate::most_luminous_death(initial_mass) {
+        Some(luminous_intensity) => {
+            is_visible_with_luminous_intensity(config, luminous_intensity, pos)
+        }
+        None => false,
+    }
+}
+
+// The dust is only integrated through for stars that would be visible without it.
+fn is_visible_with_luminous_intensity(
+    config: &GalaxyGenerationConfig,
+    luminous_intensity: LuminousIntensity,
+    pos: &Cartesian,
+) -> bool {
+    let min_luminous_intensity =
+        config.dimmest_illuminance * squared_distance_to_observer(config, pos);
+    if luminous_intensity < min_luminous_intensity {
         return false;
     }
-    let min_luminous_intensity = dimmest_illuminance() * pos.length_squared();
-    let supernova_luminous_intensity =
-        absolute_magnitude_to_luminous_intensity(TYPE_II_SUPERNOVA_PEAK_MAGNITUDE);
-    supernova_luminous_intensity >= min_luminous_intensity
+    match config.get_dust_map() {
+        Some(dust_map) => {
+            let visual_extinction = dust_map.visual_extinction(&config.observer_position, pos);
+            luminous_intensity * transmission(visual_extinction) >= min_luminous_intensity
+        }
+        None => true,
+    }
 }
 
-pub(crate) fn get_most_luminous_intensity_possible(max_age: Time) -> LuminousIntensity {
+fn squared_distance_to_observer(config: &GalaxyGenerationConfig, pos: &Cartesian) -> Area {
+    (pos - &config.observer_position).length_squared()
+}
+
+/*
+ * Stars between two grid masses are interpolated at the same fraction of their lifetime,
+ * so each track is scanned over the ages that correspond to the age range on its neighbours.
+ */
+pub(crate) fn get_most_luminous_intensity_possible(
+    metallicity_index: usize,
+    max_age: Time,
+    config: &GalaxyGenerationConfig,
+) -> LuminousIntensity {
     let mut max_luminous_intensity = LuminousIntensity::new::<candela>(0.);
-    let min_age = get_min_age(max_age);
-    let masses = get_masses_in_solar(METALLICITY_INDEX);
-    for (mass_index, _mass) in masses.iter().enumerate() {
-        let trajectory = get_trajectory(METALLICITY_INDEX, mass_index);
-        if min_age > trajectory.lifetime {
+    let min_age = get_min_age(max_age, config.nursery_lifetime);
+    let masses = get_masses_in_solar(metallicity_index);
+    let lifetime = |mass_index: usize| get_trajectory(metallicity_index, mass_index).lifetime;
+    for mass_index in 0..masses.len() {
+        let trajectory = get_trajectory(metallicity_index, mass_index);
+        let neighbours = mass_index.saturating_sub(1)..=(mass_index + 1).min(masses.len() - 1);
+        let longest_lifetime = neighbours
+            .clone()
+            .map(lifetime)
+            .fold(trajectory.lifetime, Time::max);
+        let shortest_lifetime = neighbours
+            .map(lifetime)
+            .fold(trajectory.lifetime, Time::min);
+        if min_age > longest_lifetime {
             continue;
         }
-        if trajectory.initial_mass > Mass::new::<solar_mass>(8.)
-            && (min_age..max_age).contains(&trajectory.lifetime)
-        {
-            return absolute_magnitude_to_luminous_intensity(TYPE_II_SUPERNOVA_PEAK_MAGNITUDE);
+        if let Some(supernova) = StarFate::most_luminous_death(trajectory.initial_mass) {
+            if min_age < longest_lifetime && max_age > shortest_lifetime {
+                max_luminous_intensity = max_luminous_intensity.max(supernova);
+            }
         }
-        let min_age_index = get_closest_age_index(METALLICITY_INDEX, mass_index, min_age);
-        let max_age_index = get_closest_age_index(METALLICITY_INDEX, mass_index, max_age);
-        for age_index in min_age_index..=max_age_index {
-            let params = get_parameters(METALLICITY_INDEX, mass_index, age_index);
+        let earliest = trajectory.lifetime * (min_age / longest_lifetime).value;
+        let latest = trajectory.lifetime * (max_age / shortest_lifetime).value;
+        let min_age_index = get_closest_age_index(metallicity_index, mass_index, earliest);
+        let max_age_index = get_closest_age_index(metallicity_index, mass_index, latest);
+        for age_index in min_age_index.saturating_sub(1)..=max_age_index {
+            let params = get_parameters(metallicity_index, mass_index, age_index);
             let luminous_intensity = params.luminosity_in_solar * solar_luminous_intensity();
             if luminous_intensity > max_luminous_intensity {
                 max_luminous_intensity = luminous_intensity;
@@ -87,41 +142,61 @@ pub(crate) fn get_most_luminous_intensity_possible(max_age: Time) -> LuminousInt
     max_luminous_intensity
 }
 
-fn get_star(mass_index: usize, age: Time, pos: Cartesian) -> StarData {
-    let age_index = get_closest_age_index(METALLICITY_INDEX, mass_index, age);
-    let mut star = star_without_evolution(mass_index, age_index, pos.clone());
-    let other_age_index = if age_index == 0 {
-        age_index + 1
-    } else {
-        age_index - 1
-    };
-    let other_star = star_without_evolution(mass_index, other_age_index, pos);
+// Companions form together with their primary, so they share its age and metallicity.
+pub(crate) fn get_companion(
+    config: &GalaxyGenerationConfig,
+    metallicity: f64,
+    initial_mass: Mass,
+    age: Time,
+    pos: Cartesian,
+) -> StarData {
+    let track = InterpolatedTrack::new(metallicity_grid_index(metallicity), initial_mass);
+    get_star(config, metallicity, &track, age, pos)
+}
+
+fn get_star(
+    config: &GalaxyGenerationConfig,
+    metallicity: f64,
+    track: &InterpolatedTrack,
+    age: Time,
+    pos: Cartesian,
+) -> StarData {
+    let mut star = star_without_evolution(track, age, pos.clone());
+    let step = track.lifetime * LIFESTAGE_STEP_FRACTION;
+    let other_age = if age > step { age - step } else { age + step };
+    let other_star = star_without_evolution(track, other_age, pos);
 
     let lifestage_evolution = get_lifestage_evolution(&star, other_star);
-    let trajectory = get_trajectory(METALLICITY_INDEX, mass_index);
-    let fate = StarFate::new(trajectory.initial_mass);
-    star.evolution =
-        StarDataEvolution::new(lifestage_evolution, Some(age), trajectory.lifetime, fate);
+    let fate = StarFate::new(track.initial_mass, Some(metallicity));
+    star.evolution = StarDataEvolution::new(lifestage_evolution, Some(age), track.lifetime, fate)
+        .with_initial_mass(track.initial_mass);
+    if config.evolution_mode == EvolutionMode::ParsecTrack {
+        star.evolution = star.evolution.with_track(track.to_parsec_track());
+    }
+    star.metallicity = Some(metallicity);
     star
 }
 
-fn star_without_evolution(mass_index: usize, age_index: usize, pos: Cartesian) -> StarData {
-    let params = get_parameters(METALLICITY_INDEX, mass_index, age_index);
-    let luminous_intensity = params.luminosity_in_solar * solar_luminous_intensity();
+fn star_without_evolution(track: &InterpolatedTrack, age: Time, pos: Cartesian) -> StarData {
+    let line = track.at_age(age);
+    let luminous_intensity = line.luminosity_in_solar * solar_luminous_intensity();
     let physical_parameters = StarPhysicalParameters {
-        mass: Some(params.mass),
+        mass: Some(line.mass),
         luminous_intensity,
-        temperature: params.temperature,
-        radius: Some(params.radius),
+        temperature: line.temperature,
+        radius: Some(line.radius),
     };
     let mut evolution = StarDataEvolution::none();
-    evolution.age = Some(params.age);
+    evolution.age = Some(age);
     StarData {
         name: "".to_string(),
         params: physical_parameters,
         pos,
+        velocity: VelocityVector::zero(),
         constellation: None,
         evolution,
+        metallicity: None,
+        variability: None,
     }
 }
 
@@ -138,18 +213,31 @@ fn get_lifestage_evolution(
 #[cfg(test)]
 mod tests {
     use astro_coords::direction::Direction;
-    use astro_units::{luminous_intensity::calc_illuminance, time::gigayear};
+    use astro_units::{luminous_intensity::calc_illuminance, mass::solar_mass, time::gigayear};
     use uom::si::{f64::Length, length::light_year};
 
     use super::*;
+    use crate::stars::random::metallicity::DEFAULT_METALLICITY;
     use crate::{astro_display::AstroDisplay, real_data::stars::all::get_many_stars};
-    use parsec_access::getters::get_closest_mass_index;
+
+    fn heaviest_track() -> InterpolatedTrack {
+        let metallicity_index = metallicity_grid_index(DEFAULT_METALLICITY);
+        let masses = get_masses_in_solar(metallicity_index);
+        let heaviest = Mass::new::<solar_mass>(masses[masses.len() - 1]);
+        InterpolatedTrack::new(metallicity_index, heaviest)
+    }
 
     #[test]
     fn infant_star_has_valid_evolution() {
         assert!(parsec_access::getters::is_data_ready());
-        let mass_index = get_masses_in_solar(METALLICITY_INDEX).len() - 1;
-        let star = get_star(mass_index, Time::new::<year>(0.), Cartesian::origin());
+        let track = heaviest_track();
+        let star = get_star(
+            &GalaxyGenerationConfig::default(),
+            DEFAULT_METALLICITY,
+            &track,
+            Time::new::<year>(0.),
+            Cartesian::origin(),
+        );
         assert!(star
             .evolution
             .get_lifestage_luminous_intensity_per_year()
@@ -175,9 +263,14 @@ mod tests {
     #[test]
     fn old_star_has_finite_evolution() {
         assert!(parsec_access::getters::is_data_ready());
-        let mass_index = get_masses_in_solar(METALLICITY_INDEX).len() - 1;
-        let age = get_trajectory(METALLICITY_INDEX, mass_index).lifetime;
-        let star = get_star(mass_index, age, Cartesian::origin());
+        let track = heaviest_track();
+        let star = get_star(
+            &GalaxyGenerationConfig::default(),
+            DEFAULT_METALLICITY,
+            &track,
+            track.lifetime,
+            Cartesian::origin(),
+        );
         assert!(star
             .evolution
             .get_lifestage_luminous_intensity_per_year()
@@ -218,9 +311,14 @@ mod tests {
             }
             let mass = mass.unwrap();
             let age = age.unwrap();
-            let mass_index = get_closest_mass_index(METALLICITY_INDEX, mass);
             let pos = star.pos.clone();
-            let generated = get_star_data_if_visible(mass_index, age, pos);
+            let generated = get_star_data_if_visible(
+                &GalaxyGenerationConfig::default(),
+                DEFAULT_METALLICITY,
+                mass,
+                age,
+                pos,
+            );
             if generated.is_none() {
                 failures += 1;
                 println!(
@@ -244,8 +342,15 @@ mod tests {
         assert!(parsec_access::getters::is_data_ready());
         let pos = Direction::Z.to_cartesian(Length::new::<light_year>(1000.));
         let age = Time::new::<gigayear>(1.);
-        for mass_index in 0..30 {
-            let star = get_star_data_if_visible(mass_index, age, pos.clone());
+        let masses = get_masses_in_solar(metallicity_grid_index(DEFAULT_METALLICITY));
+        for mass in masses.iter().take(30) {
+            let star = get_star_data_if_visible(
+                &GalaxyGenerationConfig::default(),
+                DEFAULT_METALLICITY,
+                Mass::new::<solar_mass>(*mass),
+                age,
+                pos.clone(),
+            );
             assert!(
                 star.is_none(),
                 "Star {:?} is visible at 1000 lyr, while it should not be.",
@@ -253,4 +358,84 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn visibility_is_relative_to_the_observer() {
+        let observer = Direction::X.to_cartesian(Length::new::<light_year>(10_000.));
+        let config = GalaxyGenerationConfig::default().with_observer_position(observer.clone());
+        let pos = &observer + &Direction::Z.to_cartesian(Length::new::<light_year>(1.));
+        let mass = Mass::new::<solar_mass>(1.);
+        let age = Time::new::<gigayear>(1.);
+        let from_observer =
+            get_star_data_if_visible(&config, DEFAULT_METALLICITY, mass, age, pos.clone());
+        assert!(from_observer.is_some());
+        let default_config = GalaxyGenerationConfig::default();
+        let from_origin =
+            get_star_data_if_visible(&default_config, DEFAULT_METALLICITY, mass, age, pos);
+        assert!(from_origin.is_none());
+    }
+
+    fn sunlike_star_on_track(age_fraction: f64) -> (InterpolatedTrack, StarData) {
+        let config =
+            GalaxyGenerationConfig::default().with_evolution_mode(EvolutionMode::ParsecTrack);
+        let track = InterpolatedTrack::new(
+            metallicity_grid_index(DEFAULT_METALLICITY),
+            Mass::new::<solar_mass>(1.),
+        );
+        let age = track.lifetime * age_fraction;
+        let star = get_star(
+            &config,
+            DEFAULT_METALLICITY,
+            &track,
+            age,
+            Cartesian::origin(),
+        );
+        (track, star)
+    }
+
+    #[test]
+    fn star_evolving_along_its_track_matches_an_older_star() {
+        assert!(parsec_access::getters::is_data_ready());
+        let (track, star) = sunlike_star_on_track(0.5);
+        assert!(star.get_evolution().follows_parsec_track());
+        let config = GalaxyGenerationConfig::default();
+        for fraction in [0.6, 0.9, 0.99] {
+            let time = track.lifetime * (fraction - 0.5);
+            let older = get_star(
+                &config,
+                DEFAULT_METALLICITY,
+                &track,
+                track.lifetime * fraction,
+                Cartesian::origin(),
+            );
+            let expected = older.get_luminous_intensity_at_epoch().value;
+            let actual = star.get_luminous_intensity(time).value;
+            assert!((actual / expected - 1.).abs() < 1e-9);
+            let expected = older.get_temperature_at_epoch().value;
+            let actual = star.get_temperature(time).value;
+            assert!((actual / expected - 1.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn star_evolving_along_its_track_stays_physical() {
+        assert!(parsec_access::getters::is_data_ready());
+        let (track, star) = sunlike_star_on_track(0.9);
+        for step in 0..100 {
+            let time = track.lifetime * (step as f64 * 1e-3);
+            assert!(star.get_luminous_intensity(time).value > 0.);
+            assert!(star.get_temperature(time).value > 0.);
+            assert!(star.get_radius(time).unwrap().value > 0.);
+            assert!(star.get_mass(time).unwrap().value > 0.);
+        }
+    }
+
+    #[test]
+    fn star_on_its_track_changes_only_noticeably() {
+        assert!(parsec_access::getters::is_data_ready());
+        let (track, star) = sunlike_star_on_track(0.5);
+        let zero = Time::new::<year>(0.);
+        assert!(!star.has_changed(zero, Time::new::<year>(10_000.)));
+        assert!(star.has_changed(zero, track.lifetime * 0.4));
+    }
 }
@@ -0,0 +1,304 @@
+use astro_units::mass::solar_mass;
+use parsec_access::getters::{get_masses_in_solar, get_trajectory};
+use parsec_access::line::ParsecLine;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uom::si::f64::{Length, Mass, ThermodynamicTemperature, Time};
+use uom::si::length::meter;
+use uom::si::thermodynamic_temperature::kelvin;
+use uom::si::time::year;
+
+/*
+ * A stellar track for an arbitrary initial mass, interpolated between the two neighbouring PARSEC tracks.
+ * Tracks of different masses evolve at different speeds, so they are compared at the same fraction of their
+ * lifetime rather than at the same age. This keeps the main sequence turnoff and the giant branch aligned.
+ * Between the tracks, quantities spanning orders of magnitude are interpolated logarithmically in mass.
+ * https://en.wikipedia.org/wiki/Bilinear_interpolation
+ * https://ui.adsabs.harvard.edu/abs/2016ApJ...823..102D/abstract
+ */
+#[derive(Debug, Clone)]
+pub(crate) struct InterpolatedTrack {
+    metallicity_index: usize,
+    lower_mass_index: usize,
+    upper_mass_index: usize,
+    weight: f64,
+    pub(crate) initial_mass: Mass,
+    pub(crate) lifetime: Time,
+}
+
+// The serializable identity of an interpolated track, from which it is rebuilt when needed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ParsecTrack {
+    metallicity_index: usize,
+    initial_mass: Mass,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct InterpolatedLine {
+    pub(crate) mass: Mass,
+    pub(crate) luminosity_in_solar: f64,
+    pub(crate) temperature: ThermodynamicTemperature,
+    pub(crate) radius: Length,
+}
+
+impl InterpolatedTrack {
+    // Masses outside of the grid are clamped to the lightest or heaviest track.
+    pub(crate) fn new(metallicity_index: usize, initial_mass: Mass) -> Self {
+        let masses = get_masses_in_solar(metallicity_index);
+        let mass_in_solar = initial_mass
+            .get::<solar_mass>()
+            .clamp(masses[0], masses[masses.len() - 1]);
+        let upper = masses
+            .partition_point(|m| *m < mass_in_solar)
+            .clamp(1, masses.len() - 1);
+        let lower = upper - 1;
+        let weight = if masses[upper] > masses[lower] {
+            (mass_in_solar / masses[lower]).ln() / (masses[upper] / masses[lower]).ln()
+        } else {
+            0.
+        };
+        let lower_lifetime = get_trajectory(metallicity_index, lower).lifetime;
+        let upper_lifetime = get_trajectory(metallicity_index, upper).lifetime;
+        let lifetime = Time::new::<year>(log_lerp(
+            lower_lifetime.get::<year>(),
+            upper_lifetime.get::<year>(),
+            weight,
+        ));
+        Self {
+            metallicity_index,
+            lower_mass_index: lower,
+            upper_mass_index: upper,
+            weight,
+            initial_mass: Mass::new::<solar_mass>(mass_in_solar),
+            lifetime,
+        }
+    }
+
+    pub(crate) fn to_parsec_track(&self) -> ParsecTrack {
+        ParsecTrack {
+            metallicity_index: self.metallicity_index,
+            initial_mass: self.initial_mass,
+        }
+    }
+
+    // Ages beyond the lifetime yield the last point of the track.
+    pub(crate) fn at_age(&self, age: Time) -> InterpolatedLine {
+        let phase = if self.lifetime.value > 0. {
+            (age / self.lifetime).value.clamp(0., 1.)
+        } else {
+            0.
+        };
+        let lower = line_at_phase(self.metallicity_index, self.lower_mass_index, phase);
+        let upper = line_at_phase(self.metallicity_index, self.upper_mass_index, phase);
+        let w = self.weight;
+
+        let lower_initial =
+            get_trajectory(self.metallicity_index, self.lower_mass_index).initial_mass;
+        let upper_initial =
+            get_trajectory(self.metallicity_index, self.upper_mass_index).initial_mass;
+        let remaining_fraction = lerp(
+            (lower.mass / lower_initial).value,
+            (upper.mass / upper_initial).value,
+            w,
+        );
+        InterpolatedLine {
+            mass: self.initial_mass * remaining_fraction,
+            luminosity_in_solar: log_lerp(lower.luminosity_in_solar, upper.luminosity_in_solar, w),
+            temperature: ThermodynamicTemperature::new::<kelvin>(log_lerp(
+                lower.temperature.get::<kelvin>(),
+                upper.temperature.get::<kelvin>(),
+                w,
+            )),
+            radius: Length::new::<meter>(log_lerp(
+                lower.radius.get::<meter>(),
+                upper.radius.get::<meter>(),
+                w,
+            )),
+        }
+    }
+}
+
+impl ParsecTrack {
+    pub(crate) fn interpolated(&self) -> InterpolatedTrack {
+        InterpolatedTrack::new(self.metallicity_index, self.initial_mass)
+    }
+}
+
+/*
+ * Draws a continuous initial mass from the range a grid point stands for,
+ * i.e. up to the geometric means with its neighbours, restricted to the grid.
+ * Within such a narrow range the initial mass function is approximated as flat in log mass.
+ */
+pub(crate) fn random_mass_in_bin<R: Rng + ?Sized>(
+    metallicity_index: usize,
+    mass_index: usize,
+    rng: &mut R,
+) -> Mass {
+    let masses = get_masses_in_solar(metallicity_index);
+    let mass = masses[mass_index];
+    let lower = match mass_index {
+        0 => mass,
+        _ => (masses[mass_index - 1] * mass).sqrt(),
+    };
+    let upper = match masses.get(mass_index + 1) {
+        Some(next) => (mass * next).sqrt(),
+        None => mass,
+    };
+    let log_mass = lower.ln() + rng.random::<f64>() * (upper.ln() - lower.ln());
+    Mass::new::<solar_mass>(log_mass.exp())
+}
+
+fn line_at_phase(metallicity_index: usize, mass_index: usize, phase: f64) -> InterpolatedLine {
+    let trajectory = get_trajectory(metallicity_index, mass_index);
+    let lines = &trajectory.params;
+    if lines.len() < 2 {
+        return to_interpolated_line(&lines[0]);
+    }
+    let age = trajectory.lifetime * phase;
+    let upper = lines
+        .partition_point(|line| line.age < age)
+        .clamp(1, lines.len() - 1);
+    let (before, after) = (&lines[upper - 1], &lines[upper]);
+    let fraction = if after.age > before.age {
+        ((age - before.age) / (after.age - before.age))
+            .value
+            .clamp(0., 1.)
+    } else {
+        0.
+    };
+    InterpolatedLine {
+        mass: before.mass + (after.mass - before.mass) * fraction,
+        luminosity_in_solar: lerp(
+            before.luminosity_in_solar,
+            after.luminosity_in_solar,
+            fraction,
+        ),
+        temperature: ThermodynamicTemperature::new::<kelvin>(lerp(
+            before.temperature.get::<kelvin>(),
+            after.temperature.get::<kelvin>(),
+            fraction,
+        )),
+        radius: before.radius + (after.radius - before.radius) * fraction,
+    }
+}
+
+fn to_interpolated_line(line: &ParsecLine) -> InterpolatedLine {
+    InterpolatedLine {
+        mass: line.mass,
+        luminosity_in_solar: line.luminosity_in_solar,
+        temperature: line.temperature,
+        radius: line.radius,
+    }
+}
+
+fn lerp(a: f64, b: f64, weight: f64) -> f64 {
+    a + (b - a) * weight
+}
+
+fn log_lerp(a: f64, b: f64, weight: f64) -> f64 {
+    if a > 0. && b > 0. {
+        (a.ln() + (b.ln() - a.ln()) * weight).exp()
+    } else {
+        lerp(a, b, weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parsec_access::getters::{get_parameters, is_data_ready};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::stars::random::metallicity::{metallicity_grid_index, DEFAULT_METALLICITY};
+    use crate::tests::eq_within;
+
+    fn solar_index() -> usize {
+        metallicity_grid_index(DEFAULT_METALLICITY)
+    }
+
+    #[test]
+    fn grid_points_are_reproduced() {
+        assert!(is_data_ready());
+        let metallicity_index = solar_index();
+        let last = get_masses_in_solar(metallicity_index).len() - 1;
+        for mass_index in [0, 10, 40, last] {
+            let trajectory = get_trajectory(metallicity_index, mass_index);
+            let track = InterpolatedTrack::new(metallicity_index, trajectory.initial_mass);
+            assert!(eq_within(
+                track.lifetime.value,
+                trajectory.lifetime.value,
+                1e-6 * trajectory.lifetime.value
+            ));
+            for age_index in [0, trajectory.params.len() / 2] {
+                let expected = get_parameters(metallicity_index, mass_index, age_index);
+                let line = track.at_age(expected.age);
+                let relative = |a: f64, b: f64| (a / b - 1.).abs() < 1e-6;
+                assert!(relative(line.mass.value, expected.mass.value));
+                assert!(relative(
+                    line.luminosity_in_solar,
+                    expected.luminosity_in_solar
+                ));
+                assert!(relative(line.temperature.value, expected.temperature.value));
+                assert!(relative(line.radius.value, expected.radius.value));
+            }
+        }
+    }
+
+    #[test]
+    fn values_between_grid_masses_lie_between_the_neighbouring_tracks() {
+        assert!(is_data_ready());
+        let metallicity_index = solar_index();
+        let masses = get_masses_in_solar(metallicity_index);
+        let (lower, upper) = (masses[30], masses[31]);
+        let lower_track = InterpolatedTrack::new(metallicity_index, Mass::new::<solar_mass>(lower));
+        let upper_track = InterpolatedTrack::new(metallicity_index, Mass::new::<solar_mass>(upper));
+        let track = InterpolatedTrack::new(
+            metallicity_index,
+            Mass::new::<solar_mass>((lower * upper).sqrt()),
+        );
+        assert!(track.lifetime < lower_track.lifetime);
+        assert!(track.lifetime > upper_track.lifetime);
+
+        let age = upper_track.lifetime * 0.3;
+        let (a, b, line) = (
+            lower_track.at_age(age),
+            upper_track.at_age(age),
+            track.at_age(age),
+        );
+        let between = |x: f64, a: f64, b: f64| x >= a.min(b) && x <= a.max(b);
+        assert!(between(line.mass.value, a.mass.value, b.mass.value));
+        assert!(between(
+            line.luminosity_in_solar,
+            a.luminosity_in_solar,
+            b.luminosity_in_solar
+        ));
+        assert!(between(line.radius.value, a.radius.value, b.radius.value));
+    }
+
+    #[test]
+    fn random_masses_are_not_banded() {
+        assert!(is_data_ready());
+        let metallicity_index = solar_index();
+        let masses = get_masses_in_solar(metallicity_index);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut drawn: Vec<f64> = (0..1_000)
+            .map(|_| random_mass_in_bin(metallicity_index, 30, &mut rng).get::<solar_mass>())
+            .collect();
+        assert!(drawn
+            .iter()
+            .all(|m| *m >= (masses[29] * masses[30]).sqrt()
+                && *m <= (masses[30] * masses[31]).sqrt()));
+        drawn.sort_by(f64::total_cmp);
+        drawn.dedup();
+        assert_eq!(drawn.len(), 1_000);
+
+        let luminosities: Vec<f64> = drawn
+            .iter()
+            .map(|m| {
+                let track = InterpolatedTrack::new(metallicity_index, Mass::new::<solar_mass>(*m));
+                track.at_age(track.lifetime * 0.5).luminosity_in_solar
+            })
+            .collect();
+        assert!(luminosities.windows(2).all(|w| w[0] < w[1]));
+    }
+}
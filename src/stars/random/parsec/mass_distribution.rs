@@ -1,17 +1,54 @@
-use crate::{error::AstroUtilError, stars::random::random_stars::METALLICITY_INDEX};
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::{error::AstroUtilError, stars::random::initial_mass_function::InitialMassFunction};
 
 use parsec_access::getters::get_masses_in_solar;
 use rand_distr::weighted::WeightedAliasIndex;
 
-const MIN_MASS_FOR_HYDROGEN_FUSION: f64 = 0.08;
+// Masses above the heaviest grid point are assigned to it.
+const MAX_MASS: f64 = 1000.;
 
-pub(crate) fn get_mass_index_distribution() -> Result<WeightedAliasIndex<f64>, AstroUtilError> {
-    let weights = kroupa_weights();
+pub(crate) fn get_mass_index_distribution(
+    metallicity_index: usize,
+    initial_mass_function: &dyn InitialMassFunction,
+) -> Result<WeightedAliasIndex<f64>, AstroUtilError> {
+    let weights = imf_weights(metallicity_index, initial_mass_function);
     WeightedAliasIndex::new(weights).map_err(AstroUtilError::from)
 }
 
-fn kroupa_weights() -> Vec<f64> {
-    let masses = get_masses_in_solar(METALLICITY_INDEX);
+// The mass grids differ between metallicities, so each of them needs its own distribution.
+pub(crate) struct MassIndexDistributions {
+    distributions: HashMap<usize, WeightedAliasIndex<f64>>,
+}
+
+impl MassIndexDistributions {
+    pub(crate) fn new(
+        metallicity_indices: &[usize],
+        initial_mass_function: &dyn InitialMassFunction,
+    ) -> Result<Self, AstroUtilError> {
+        let mut distributions = HashMap::new();
+        for &metallicity_index in metallicity_indices {
+            if let Entry::Vacant(entry) = distributions.entry(metallicity_index) {
+                entry.insert(get_mass_index_distribution(
+                    metallicity_index,
+                    initial_mass_function,
+                )?);
+            }
+        }
+        Ok(Self { distributions })
+    }
+
+    pub(crate) fn get(&self, metallicity_index: usize) -> Option<&WeightedAliasIndex<f64>> {
+        self.distributions.get(&metallicity_index)
+    }
+}
+
+// Every grid point stands for the masses up to the geometric means with its neighbours.
+fn imf_weights(
+    metallicity_index: usize,
+    initial_mass_function: &dyn InitialMassFunction,
+) -> Vec<f64> {
+    let masses = get_masses_in_solar(metallicity_index);
     let mut weights = Vec::new();
     for m in 0..masses.len() {
         let lower = if m == 0 {
@@ -20,11 +57,11 @@ fn kroupa_weights() -> Vec<f64> {
             geometric_mean(masses[m - 1], masses[m])
         };
         let upper = if m == masses.len() - 1 {
-            1000.
+            MAX_MASS
         } else {
             geometric_mean(masses[m], masses[m + 1])
         };
-        let weight = integrate_kroupa(lower, upper);
+        let weight = initial_mass_function.fraction_between(lower, upper);
         weights.push(weight);
     }
     weights
@@ -34,52 +71,29 @@ fn geometric_mean(a: f64, b: f64) -> f64 {
     (a * b).sqrt()
 }
 
-fn kroupa_mass_distribution(m_in_solar_masses: f64) -> f64 {
-    const NORMALIZATION: f64 = 0.12499960249873866;
-    if m_in_solar_masses < MIN_MASS_FOR_HYDROGEN_FUSION {
-        return 0.; // Brown dwarfs
-    }
-    let (alpha, prefactor) = if m_in_solar_masses <= 0.5 {
-        (1.3, 0.5f64.powf(-2.3) / 0.5f64.powf(-1.3))
-    } else if m_in_solar_masses <= 1. {
-        (2.3, 1.)
-    } else if m_in_solar_masses <= 20. {
-        (2.7, 1.)
-    } else {
-        (5., 20f64.powf(-2.7) / 20f64.powf(-5.)) //Adjusted high mass tail
-    };
-    prefactor * m_in_solar_masses.powf(-alpha) * NORMALIZATION
-}
-
-fn integrate_kroupa(lower: f64, upper: f64) -> f64 {
-    let mut integral = 0.;
-    let mut x = lower;
-    while x < upper {
-        let dx = (upper - x).min(0.01);
-        integral += kroupa_mass_distribution(x) * dx;
-        x += dx;
-    }
-    integral
-}
-
 #[cfg(test)]
 mod tests {
     use rand_distr::Distribution;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
     use uom::si::{f64::Length, length::light_year};
 
-    use crate::stars::random::random_stars::{number_in_sphere, STARS_PER_LY_CUBED};
+    use crate::stars::random::{
+        initial_mass_function::{Kroupa, Salpeter, MIN_MASS_FOR_HYDROGEN_FUSION},
+        metallicity::{metallicity_grid_index, DEFAULT_METALLICITY},
+        random_stars::{number_in_sphere, STARS_PER_LY_CUBED},
+    };
 
     use super::*;
 
     #[test]
     fn kroupa_is_smooth() {
+        let kroupa = Kroupa::adjusted();
         let stepsize = 0.01;
         let mut mass = MIN_MASS_FOR_HYDROGEN_FUSION;
-        let mut last = kroupa_mass_distribution(mass);
+        let mut last = kroupa.density(mass);
         while mass < 100. {
             mass += stepsize;
-            let current = kroupa_mass_distribution(mass);
+            let current = kroupa.density(mass);
             let derivative = (current - last) / stepsize;
             assert!(
                 derivative < 0.01,
@@ -97,7 +111,7 @@ mod tests {
     fn kroupa_integrates_to_1() {
         let lower = 0.0;
         let upper = 2000.;
-        let integral = integrate_kroupa(lower, upper);
+        let integral = Kroupa::adjusted().fraction_between(lower, upper);
         assert!(
             (integral - 1.).abs() < 1e-5,
             "Integral is {},\nso normalization should be {}",
@@ -106,11 +120,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn weights_cover_the_whole_initial_mass_function() {
+        let metallicity_index = metallicity_grid_index(DEFAULT_METALLICITY);
+        for imf in [
+            &Kroupa::adjusted() as &dyn InitialMassFunction,
+            &Salpeter::new(),
+        ] {
+            let total: f64 = imf_weights(metallicity_index, imf).iter().sum();
+            assert!((total - 1.).abs() < 1e-5, "Weights sum up to {}", total);
+        }
+    }
+
+    #[test]
+    fn salpeter_has_more_massive_stars_than_adjusted_kroupa() {
+        let metallicity_index = metallicity_grid_index(DEFAULT_METALLICITY);
+        let masses = get_masses_in_solar(metallicity_index);
+        let fraction_above_20_solar_masses = |imf: &dyn InitialMassFunction| {
+            imf_weights(metallicity_index, imf)
+                .iter()
+                .zip(masses.iter())
+                .filter(|(_, mass)| **mass > 20.)
+                .map(|(weight, _)| weight)
+                .sum::<f64>()
+        };
+        assert!(
+            fraction_above_20_solar_masses(&Salpeter::new())
+                > 2. * fraction_above_20_solar_masses(&Kroupa::adjusted())
+        );
+    }
+
     #[test]
     fn kroupa_integral_and_sampling_agree() {
-        let masses = get_masses_in_solar(METALLICITY_INDEX);
+        let metallicity_index = metallicity_grid_index(DEFAULT_METALLICITY);
+        let masses = get_masses_in_solar(metallicity_index);
         let num_stars = 100_000;
-        let distribution = get_mass_index_distribution().unwrap();
+        let distribution =
+            get_mass_index_distribution(metallicity_index, &Kroupa::adjusted()).unwrap();
         let gen_masses = (0..num_stars).map(|_| masses[distribution.sample(&mut rand::rng())]);
         let mut thresholds = Vec::new();
         for i in 0..masses.len() - 1 {
@@ -120,7 +166,7 @@ mod tests {
             let count = gen_masses.clone().filter(|&m| m >= threshold).count();
             let uncertainty = 10. / (count as f64).sqrt();
             let fraction = count as f64 / num_stars as f64;
-            let integral = integrate_kroupa(threshold as f64, 1000.);
+            let integral = Kroupa::adjusted().fraction_between(threshold, MAX_MASS);
             let lower = integral - uncertainty;
             let upper = integral + uncertainty;
             assert!(
@@ -137,11 +183,13 @@ mod tests {
     #[test]
     fn there_are_less_than_10_supermassive_stars_within_1000_lyr() {
         // The closest star above 50 Sun masses ist 3000 lyr away.
-        let masses = get_masses_in_solar(METALLICITY_INDEX);
+        let metallicity_index = metallicity_grid_index(DEFAULT_METALLICITY);
+        let masses = get_masses_in_solar(metallicity_index);
         let max_distance = Length::new::<light_year>(1000.);
         let num_stars = number_in_sphere(STARS_PER_LY_CUBED, max_distance);
         println!("Number of stars: {}", num_stars);
-        let distribution = get_mass_index_distribution().unwrap();
+        let distribution =
+            get_mass_index_distribution(metallicity_index, &Kroupa::adjusted()).unwrap();
         let num_supermassive_stars = (0..num_stars)
             .into_par_iter()
             .map(|_| {
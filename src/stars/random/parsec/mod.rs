@@ -1,2 +1,3 @@
 pub(super) mod getters;
+pub(crate) mod interpolation;
 pub(super) mod mass_distribution;
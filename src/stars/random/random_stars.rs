@@ -5,7 +5,7 @@ use astro_units::{
     illuminance::{lux, Illuminance},
     time::{kiloyear, megayear},
 };
-use rand::{distr::Uniform, rngs::ThreadRng, Rng};
+use rand::{distr::Uniform, rngs::StdRng, Rng, SeedableRng};
 use rand_distr::{weighted::WeightedAliasIndex, Distribution};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use uom::si::{
@@ -16,10 +16,19 @@ use uom::si::{
 
 use crate::{
     error::AstroUtilError,
-    stars::{data::StarData, random::parsec::mass_distribution::get_mass_index_distribution},
+    stars::{
+        data::StarData,
+        random::parsec::mass_distribution::{get_mass_index_distribution, MassIndexDistributions},
+    },
 };
 
-use super::{params::GenerationParams, parsec::getters::get_star_data_if_visible};
+use super::{
+    cluster::{GeneratedCluster, StarCluster},
+    config::GalaxyGenerationConfig,
+    metallicity::{metallicity_grid_index, Metallicity, DEFAULT_METALLICITY},
+    params::GenerationParams,
+    parsec::{getters::get_star_data_if_visible, interpolation::random_mass_in_bin},
+};
 
 // https://en.wikipedia.org/wiki/Stellar_density
 // But more or less arbitrarily adjusted to reproduce Gaia data.
@@ -34,7 +43,7 @@ pub(super) fn nursery_lifetime() -> Time {
 pub(super) fn age_of_milky_way_thin_disk() -> Time {
     Time::new::<megayear>(8.8e3)
 }
-const NURSERIES_PER_LY_CUBED: f64 = 6_000. / 8e12 * 10.; //* AGE_OF_MILKY_WAY_THIN_DISK.s / NURSERY_LIFETIME.s;
+pub(super) const NURSERIES_PER_LY_CUBED: f64 = 6_000. / 8e12 * 10.; //* AGE_OF_MILKY_WAY_THIN_DISK.s / NURSERY_LIFETIME.s;
 pub(super) const NUMBER_OF_STARS_FORMED_IN_NURSERY: usize = 20_000;
 #[inline(always)]
 pub(super) fn stellar_velocity() -> Velocity {
@@ -45,101 +54,273 @@ pub(super) fn dimmest_illuminance() -> Illuminance {
     Illuminance::new::<lux>(6.5309e-9)
 }
 
-pub(super) const METALLICITY_INDEX: usize = 8;
-
 pub fn generate_random_stars(max_distance: Length) -> Result<Vec<StarData>, AstroUtilError> {
+    generate_random_stars_with_seed(max_distance, rand::random())
+}
+
+/*
+ * Every star forming region draws its random numbers from its own generator, seeded with a seed derived from
+ * the given one and the index of the region. That way the result does not depend on how rayon schedules the regions.
+ */
+pub fn generate_random_stars_with_seed(
+    max_distance: Length,
+    seed: u64,
+) -> Result<Vec<StarData>, AstroUtilError> {
+    generate_random_stars_with_metallicity(max_distance, &Metallicity::default(), seed)
+}
+
+/*
+ * A fixed metallicity applies to all stars. When it is drawn from an age-metallicity relation,
+ * every star forming region gets a single value, while the old stars draw theirs individually.
+ */
+pub fn generate_random_stars_with_metallicity(
+    max_distance: Length,
+    metallicity: &Metallicity,
+    seed: u64,
+) -> Result<Vec<StarData>, AstroUtilError> {
+    let config = GalaxyGenerationConfig::default().with_metallicity(*metallicity);
+    generate_random_stars_with_config(max_distance, &config, seed)
+}
+
+pub fn generate_random_stars_visible_from(
+    observer: &Cartesian,
+    max_distance: Length,
+    seed: u64,
+) -> Result<Vec<StarData>, AstroUtilError> {
+    let config = GalaxyGenerationConfig::default().with_observer_position(observer.clone());
+    generate_random_stars_with_config(max_distance, &config, seed)
+}
+
+// Generates the stars within max_distance of the observer that are visible from there.
+pub fn generate_random_stars_with_config(
+    max_distance: Length,
+    config: &GalaxyGenerationConfig,
+    seed: u64,
+) -> Result<Vec<StarData>, AstroUtilError> {
+    let (old_stars, clusters) = generate_old_stars_and_clusters(max_distance, config, seed)?;
+    Ok(old_stars
+        .into_iter()
+        .chain(
+            clusters
+                .into_iter()
+                .flat_map(GeneratedCluster::into_members),
+        )
+        .collect())
+}
+
+// The same star forming regions as above, leaving out those without any visible members.
+pub fn generate_random_clusters_with_config(
+    max_distance: Length,
+    config: &GalaxyGenerationConfig,
+    seed: u64,
+) -> Result<Vec<GeneratedCluster>, AstroUtilError> {
+    let (_, clusters) = generate_old_stars_and_clusters(max_distance, config, seed)?;
+    Ok(clusters
+        .into_iter()
+        .filter(|cluster| !cluster.members.is_empty())
+        .collect())
+}
+
+fn generate_old_stars_and_clusters(
+    max_distance: Length,
+    config: &GalaxyGenerationConfig,
+    seed: u64,
+) -> Result<(Vec<StarData>, Vec<GeneratedCluster>), AstroUtilError> {
     if !parsec_access::getters::is_data_ready() {
         return Err(AstroUtilError::DataNotAvailable(
             "Parsec data not ready".to_string(),
         ));
     }
-    let mass_index_distr = get_mass_index_distribution()?;
-
-    let number_star_forming_regions = number_in_sphere(NURSERIES_PER_LY_CUBED, max_distance) + 1;
-    let age_distribution = Uniform::new(0., age_of_milky_way_thin_disk().get::<megayear>())?;
+    let mass_index_distributions = MassIndexDistributions::new(
+        &config.metallicity.possible_grid_indices(),
+        config.get_initial_mass_function(),
+    )?;
+
+    let max_nursery_density = config.galactic_structure.as_ref().map_or(1., |structure| {
+        structure.max_relative_nursery_density_in_sphere(&config.observer_position, max_distance)
+    });
+    let star_formation_history = &config.star_formation_history;
+    let age_distribution = star_formation_history.age_distribution(config.age_of_disk);
+    let number_star_forming_regions = number_in_sphere(
+        config.nurseries_per_cubic_light_year
+            * max_nursery_density
+            * star_formation_history.mean_rate(config.age_of_disk),
+        max_distance,
+    ) + 1;
     println!(
         "Number of star forming regions: {}",
         number_star_forming_regions
     );
-    let stars = (0..number_star_forming_regions)
-        .into_par_iter()
-        .map(|i| {
-            let mut rng = rand::rng();
-            let mut params = if i == 0 {
-                GenerationParams::old_stars(max_distance)
-            } else {
-                let pos = random_point_in_sphere(&mut rng, max_distance);
-                let max_age = Time::new::<megayear>(rng.sample(age_distribution));
-                GenerationParams::nursery(pos, max_age)
-            };
-            params.adjust_distance_for_performance();
-            generate_random_stars_with_params(params, &mass_index_distr)
-        })
-        .flatten()
-        .collect();
-    Ok(stars)
+    let generate_old_stars = || {
+        let mut rng = StdRng::seed_from_u64(derive_seed(seed, 0));
+        let mut params = GenerationParams::old_stars(max_distance, config);
+        params.adjust_distance_for_performance(config);
+        generate_old_stars(params, config, &mass_index_distributions, &mut rng)
+    };
+    let generate_clusters = || {
+        (1..number_star_forming_regions)
+            .into_par_iter()
+            .filter_map(|i| {
+                let mut rng = StdRng::seed_from_u64(derive_seed(seed, i as u64));
+                let pos =
+                    &config.observer_position + &random_point_in_sphere(&mut rng, max_distance);
+                let max_age = age_distribution.as_ref()?.sample(&mut rng);
+                let mass_fraction = config.metallicity.mass_fraction(max_age, &mut rng);
+                if let Some(structure) = &config.galactic_structure {
+                    let acceptance = structure.relative_nursery_density(&pos) / max_nursery_density;
+                    if rng.random::<f64>() >= acceptance {
+                        return None;
+                    }
+                }
+                let name = format!("Cluster {}", i);
+                let cluster = StarCluster::from_nursery(name, pos, max_age, mass_fraction, config);
+                let members = cluster.generate_members_within_sight(
+                    config,
+                    &mass_index_distributions,
+                    &mut rng,
+                );
+                Some(GeneratedCluster { cluster, members })
+            })
+            .collect::<Vec<_>>()
+    };
+    Ok(rayon::join(generate_old_stars, generate_clusters))
 }
 
-pub(crate) fn get_min_age(max_age: Time) -> Time {
-    max_age - nursery_lifetime() - Time::new::<kiloyear>(10.)
+// SplitMix64, which scrambles neighbouring indices into unrelated seeds.
+// https://prng.di.unimi.it/splitmix64.c
+pub(crate) fn derive_seed(seed: u64, index: u64) -> u64 {
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+pub(crate) fn get_min_age(max_age: Time, nursery_lifetime: Time) -> Time {
+    max_age - nursery_lifetime - Time::new::<kiloyear>(10.)
 }
 
 pub(super) fn number_in_sphere(num_per_lyr: f64, max_distance: Length) -> usize {
     (num_per_lyr * 4. / 3. * PI * max_distance.get::<light_year>().powi(3)) as usize
 }
 
-fn generate_random_stars_with_params(
-    params: GenerationParams,
-    mass_index_distr: &WeightedAliasIndex<f64>,
+fn generate_random_stars_with_params<R: Rng + ?Sized>(
+    mut params: GenerationParams,
+    config: &GalaxyGenerationConfig,
+    mass_index_distributions: &MassIndexDistributions,
+    rng: &mut R,
 ) -> Vec<StarData> {
-    let age_distribution = match Uniform::new(0., nursery_lifetime().get::<megayear>()) {
+    // The sphere has always been populated with one star more than its expected number.
+    params.number += 1;
+    let (origin, radius) = (params.pos.clone(), params.radius);
+    generate_random_stars_in_volume(&params, config, mass_index_distributions, rng, |rng| {
+        Some(&origin + &random_point_in_sphere(rng, radius))
+    })
+}
+
+/*
+ * With a galactic structure, the old stars are drawn with the highest density that occurs within the sphere,
+ * and each of them is kept with the ratio of the density at its position to that maximum.
+ * https://en.wikipedia.org/wiki/Poisson_point_process#Thinning
+ */
+fn generate_old_stars<R: Rng + ?Sized>(
+    mut params: GenerationParams,
+    config: &GalaxyGenerationConfig,
+    mass_index_distributions: &MassIndexDistributions,
+    rng: &mut R,
+) -> Vec<StarData> {
+    let structure = match &config.galactic_structure {
+        Some(structure) => structure,
+        None => {
+            return generate_random_stars_with_params(params, config, mass_index_distributions, rng)
+        }
+    };
+    let (origin, radius) = (params.pos.clone(), params.radius);
+    let max_density = structure.max_relative_star_density_in_sphere(&origin, radius);
+    params.number = (params.number as f64 * max_density) as usize + 1;
+    generate_random_stars_in_volume(&params, config, mass_index_distributions, rng, |rng| {
+        let pos = &origin + &random_point_in_sphere(rng, radius);
+        let acceptance = structure.relative_star_density(&pos) / max_density;
+        (rng.random::<f64>() < acceptance).then_some(pos)
+    })
+}
+
+/*
+ * The random numbers drawn for a star do not depend on whether it turns out to be visible,
+ * so the same stars end up at the same places no matter where the observer is.
+ */
+pub(super) fn generate_random_stars_in_volume<R, F>(
+    params: &GenerationParams,
+    config: &GalaxyGenerationConfig,
+    mass_index_distributions: &MassIndexDistributions,
+    rng: &mut R,
+    random_position: F,
+) -> Vec<StarData>
+where
+    R: Rng + ?Sized,
+    F: Fn(&mut R) -> Option<Cartesian>,
+{
+    let age_distribution = match Uniform::new(0., config.nursery_lifetime.get::<megayear>()) {
         Ok(distr) => distr,
         Err(e) => {
             eprintln!("Error creating age distribution: {}", e);
             return vec![];
         }
     };
-    (0..=params.number)
+    (0..params.number)
         .filter_map(|_| {
-            let mut rng = rand::rng();
             let age = params.max_age - Time::new::<megayear>(rng.sample(age_distribution));
-            generate_visible_random_star(
-                &params.pos,
-                params.radius,
-                age,
-                &mut rng,
-                mass_index_distr,
-            )
+            let mass_fraction = params
+                .metallicity
+                .unwrap_or_else(|| config.metallicity.mass_fraction(age, rng));
+            let metallicity_index = metallicity_grid_index(mass_fraction);
+            let mass_index_distr = mass_index_distributions.get(metallicity_index)?;
+            let mass_index = mass_index_distr.sample(rng);
+            let mass = random_mass_in_bin(metallicity_index, mass_index, rng);
+            let pos = random_position(rng)?;
+            get_star_data_if_visible(config, mass_fraction, mass, age, pos)
         })
         .collect::<Vec<StarData>>()
 }
 
 pub fn generate_random_star(max_distance: Option<Length>) -> Result<StarData, AstroUtilError> {
+    generate_random_star_with_rng(max_distance, &mut rand::rng())
+}
+
+pub fn generate_random_star_with_rng<R: Rng + ?Sized>(
+    max_distance: Option<Length>,
+    rng: &mut R,
+) -> Result<StarData, AstroUtilError> {
     let max_distance_or_1 = max_distance.unwrap_or(Length::new::<astronomical_unit>(1.));
 
-    let mass_index_distr = get_mass_index_distribution()?;
+    let mass_index_distr = get_mass_index_distribution(
+        metallicity_grid_index(DEFAULT_METALLICITY),
+        GalaxyGenerationConfig::default().get_initial_mass_function(),
+    )?;
 
-    let mut star = definetely_generate_visible_random_star(max_distance_or_1, mass_index_distr);
+    let mut star =
+        definetely_generate_visible_random_star(max_distance_or_1, mass_index_distr, rng);
     if max_distance.is_none() {
         star.pos = Cartesian::origin();
     }
     Ok(star)
 }
 
-fn definetely_generate_visible_random_star(
+fn definetely_generate_visible_random_star<R: Rng + ?Sized>(
     max_distance_or_1: Length,
     mass_distr: WeightedAliasIndex<f64>,
+    rng: &mut R,
 ) -> StarData {
-    let mut rng = rand::rng();
     let mut star = None;
     loop {
         match star {
             None => {
                 star = generate_visible_random_star(
+                    &GalaxyGenerationConfig::default(),
                     &Cartesian::origin(),
                     max_distance_or_1,
                     age_of_milky_way_thin_disk(),
-                    &mut rng,
+                    DEFAULT_METALLICITY,
+                    rng,
                     &mass_distr,
                 );
             }
@@ -148,20 +329,23 @@ fn definetely_generate_visible_random_star(
     }
 }
 
-fn generate_visible_random_star(
+fn generate_visible_random_star<R: Rng + ?Sized>(
+    config: &GalaxyGenerationConfig,
     origin: &Cartesian,
     max_distance: Length,
     age: Time,
-    rng: &mut ThreadRng,
+    metallicity: f64,
+    rng: &mut R,
     mass_index_distr: &WeightedAliasIndex<f64>,
 ) -> Option<StarData> {
     let mass_index = mass_index_distr.sample(rng);
+    let mass = random_mass_in_bin(metallicity_grid_index(metallicity), mass_index, rng);
     let pos = origin + &random_point_in_sphere(rng, max_distance);
-    let star = get_star_data_if_visible(mass_index, age, pos)?;
+    let star = get_star_data_if_visible(config, metallicity, mass, age, pos)?;
     Some(star)
 }
 
-fn random_point_in_unit_sphere(rng: &mut ThreadRng) -> Cartesian {
+fn random_point_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Cartesian {
     let distr = match Uniform::new(-1., 1.) {
         Ok(distr) => distr,
         Err(e) => {
@@ -179,12 +363,15 @@ fn random_point_in_unit_sphere(rng: &mut ThreadRng) -> Cartesian {
     Cartesian::new(x, y, z)
 }
 
-fn random_point_in_sphere(rng: &mut ThreadRng, max_distance: Length) -> Cartesian {
+pub(super) fn random_point_in_sphere<R: Rng + ?Sized>(
+    rng: &mut R,
+    max_distance: Length,
+) -> Cartesian {
     let point = random_point_in_unit_sphere(rng);
     point * max_distance.get::<meter>()
 }
 
-pub(crate) fn random_direction(rng: &mut ThreadRng) -> Direction {
+pub(crate) fn random_direction<R: Rng + ?Sized>(rng: &mut R) -> Direction {
     let mut point = random_point_in_unit_sphere(rng);
     let mut dir = point.to_direction();
     loop {
@@ -200,11 +387,26 @@ pub(crate) fn random_direction(rng: &mut ThreadRng) -> Direction {
 
 #[cfg(test)]
 mod tests {
-    use astro_units::{illuminance::illuminance_to_apparent_magnitude, mass::solar_mass};
+    use astro_units::{
+        illuminance::illuminance_to_apparent_magnitude, luminous_intensity::calc_illuminance,
+        mass::solar_mass,
+    };
     use parsec_access::getters::get_closest_metallicity_index_from_mass_fraction;
     use uom::si::{f64::Mass, time::year};
 
-    use crate::{astro_display::AstroDisplay, stars::fate::StarFate, tests::eq};
+    use crate::{
+        astro_display::AstroDisplay,
+        stars::{
+            extinction::ExponentialDustDisk,
+            fate::StarFate,
+            random::{
+                galactic_structure::GalacticStructure,
+                initial_mass_function::CustomInitialMassFunction, metallicity::SOLAR_METALLICITY,
+                star_formation_history::StarFormationHistory,
+            },
+        },
+        tests::eq,
+    };
 
     use super::*;
     use std::time::Instant;
@@ -212,7 +414,7 @@ mod tests {
     #[test]
     fn metallicity_index_corresponds_to_that_of_sun() {
         let correct_index = get_closest_metallicity_index_from_mass_fraction(0.01);
-        assert_eq!(correct_index, METALLICITY_INDEX);
+        assert_eq!(correct_index, metallicity_grid_index(DEFAULT_METALLICITY));
     }
 
     #[test]
@@ -274,7 +476,7 @@ mod tests {
         let star_data: Vec<StarData> = generate_random_stars(max_distance).unwrap();
         for star in star_data {
             if star.params.mass.unwrap() < Mass::new::<solar_mass>(8.0) {
-                assert_eq!(star.get_fate(), &StarFate::WhiteDwarf);
+                assert_eq!(star.get_fate(), &StarFate::PlanetaryNebula);
             }
         }
     }
@@ -285,7 +487,7 @@ mod tests {
         let star_data: Vec<StarData> = generate_random_stars(max_distance).unwrap();
         for star in star_data {
             if star.params.mass.unwrap() > Mass::new::<solar_mass>(8.0) {
-                assert_eq!(star.get_fate(), &StarFate::TypeIISupernova);
+                assert!(star.get_fate().is_supernova());
             }
         }
     }
@@ -298,4 +500,211 @@ mod tests {
             assert!(star.get_age_at_epoch().is_some());
         }
     }
+
+    #[test]
+    fn same_seed_generates_same_stars() {
+        let max_distance = Length::new::<light_year>(300.);
+        let first = generate_random_stars_with_seed(max_distance, 42).unwrap();
+        let second = generate_random_stars_with_seed(max_distance, 42).unwrap();
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_seeds_generate_different_stars() {
+        let max_distance = Length::new::<light_year>(300.);
+        let first = generate_random_stars_with_seed(max_distance, 1).unwrap();
+        let second = generate_random_stars_with_seed(max_distance, 2).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn same_seed_generates_same_single_star() {
+        let max_distance = Some(Length::new::<light_year>(1000.));
+        let first = generate_random_star_with_rng(max_distance, &mut StdRng::seed_from_u64(7));
+        let second = generate_random_star_with_rng(max_distance, &mut StdRng::seed_from_u64(7));
+        assert_eq!(first.unwrap(), second.unwrap());
+    }
+
+    #[test]
+    fn derived_seeds_differ_between_nurseries() {
+        let seeds: Vec<u64> = (0..100).map(|i| derive_seed(42, i)).collect();
+        for (i, seed) in seeds.iter().enumerate() {
+            assert!(!seeds[i + 1..].contains(seed));
+        }
+    }
+
+    #[test]
+    fn generated_stars_record_their_metallicity() {
+        let max_distance = Length::new::<light_year>(200.);
+        let metallicity = Metallicity::from_iron_abundance(-1.);
+        let stars = generate_random_stars_with_metallicity(max_distance, &metallicity, 3).unwrap();
+        assert!(!stars.is_empty());
+        for star in stars {
+            assert!(eq(star.get_metallicity().unwrap(), SOLAR_METALLICITY / 10.));
+        }
+    }
+
+    #[test]
+    fn age_metallicity_relation_gives_varying_metallicities() {
+        let max_distance = Length::new::<light_year>(300.);
+        let metallicity = Metallicity::milky_way_thin_disk();
+        let stars = generate_random_stars_with_metallicity(max_distance, &metallicity, 4).unwrap();
+        let first = stars[0].get_metallicity().unwrap();
+        assert!(stars
+            .iter()
+            .any(|star| star.get_metallicity().unwrap() != first));
+        let again = generate_random_stars_with_metallicity(max_distance, &metallicity, 4).unwrap();
+        assert_eq!(stars, again);
+    }
+
+    #[test]
+    fn default_config_reproduces_default_generation() {
+        let max_distance = Length::new::<light_year>(200.);
+        let config = GalaxyGenerationConfig::default();
+        let with_config = generate_random_stars_with_config(max_distance, &config, 5).unwrap();
+        let with_seed = generate_random_stars_with_seed(max_distance, 5).unwrap();
+        assert_eq!(with_config, with_seed);
+    }
+
+    #[test]
+    fn lower_limiting_magnitude_shows_fewer_stars() {
+        let max_distance = Length::new::<light_year>(300.);
+        let config = GalaxyGenerationConfig::default();
+        let naked_eye = generate_random_stars_with_config(max_distance, &config, 6).unwrap();
+        let config = config.with_limiting_magnitude(4.);
+        let bright = generate_random_stars_with_config(max_distance, &config, 6).unwrap();
+        assert!(bright.len() < naked_eye.len());
+    }
+
+    #[test]
+    fn stars_are_generated_around_the_observer() {
+        let max_distance = Length::new::<light_year>(100.);
+        let observer = Direction::X.to_cartesian(Length::new::<light_year>(10_000.));
+        let config = GalaxyGenerationConfig::default().with_observer_position(observer.clone());
+        let stars = generate_random_stars_with_config(max_distance, &config, 7).unwrap();
+        assert!(!stars.is_empty());
+        for star in stars {
+            assert!(star.get_pos_at_epoch().distance(&observer) < max_distance * 1.01);
+        }
+    }
+
+    #[test]
+    fn stars_are_visible_from_the_observer() {
+        let max_distance = Length::new::<light_year>(300.);
+        let observer = Direction::Y.to_cartesian(Length::new::<light_year>(-5_000.));
+        let stars = generate_random_stars_visible_from(&observer, max_distance, 8).unwrap();
+        assert!(!stars.is_empty());
+        let dimmest = GalaxyGenerationConfig::default().get_dimmest_illuminance();
+        for star in stars {
+            if star.get_time_until_death(Time::new::<year>(0.)).unwrap() < Time::new::<year>(0.) {
+                continue;
+            }
+            let distance = star.get_pos_at_epoch().distance(&observer);
+            let illuminance = calc_illuminance(star.get_luminous_intensity_at_epoch(), distance);
+            assert!(illuminance >= dimmest * 0.99);
+        }
+    }
+
+    #[test]
+    fn galactic_structure_concentrates_stars_towards_the_plane() {
+        let max_distance = Length::new::<light_year>(5_000.);
+        let structure = GalacticStructure::milky_way();
+        let uniform = GalaxyGenerationConfig::default()
+            .with_stellar_density(1e-7)
+            .with_nursery_density(0.)
+            .with_limiting_magnitude(40.);
+        let structured = uniform.clone().with_galactic_structure(structure.clone());
+        let fraction_near_plane = |config: &GalaxyGenerationConfig| {
+            let stars = generate_random_stars_with_config(max_distance, config, 9).unwrap();
+            let near_plane = stars
+                .iter()
+                .filter(|star| {
+                    structure.height_above_plane(star.get_pos_at_epoch()).abs()
+                        < Length::new::<light_year>(1_000.)
+                })
+                .count();
+            near_plane as f64 / stars.len() as f64
+        };
+        assert!(fraction_near_plane(&uniform) < 0.35);
+        assert!(fraction_near_plane(&structured) > 0.45);
+    }
+
+    #[test]
+    fn dust_hides_stars_behind_it() {
+        let max_distance = Length::new::<light_year>(300.);
+        let observer = Cartesian::new(
+            Length::new::<light_year>(50.),
+            Length::new::<light_year>(0.),
+            Length::new::<light_year>(20.),
+        );
+        let clear = GalaxyGenerationConfig::default().with_observer_position(observer);
+        let dusty = clear.clone().with_dust_map(ExponentialDustDisk::new(
+            10.,
+            Length::new::<light_year>(7_300.),
+            Length::new::<light_year>(440.),
+        ));
+        let visible = generate_random_stars_with_config(max_distance, &clear, 12).unwrap();
+        let through_dust = generate_random_stars_with_config(max_distance, &dusty, 12).unwrap();
+        assert!(!through_dust.is_empty());
+        assert!(through_dust.len() < visible.len());
+        let dust_map = dusty.get_dust_map().unwrap();
+        let dimmest = dusty.get_dimmest_illuminance();
+        for star in through_dust {
+            if star.get_time_until_death(Time::new::<year>(0.)).unwrap() < Time::new::<year>(0.) {
+                continue;
+            }
+            let appearance = star.to_star_appearance_through_dust(
+                dust_map,
+                dusty.get_observer_position(),
+                Time::new::<year>(0.),
+            );
+            assert!(appearance.get_illuminance() >= dimmest * 0.99);
+        }
+    }
+
+    #[test]
+    fn initial_mass_function_shapes_the_generated_masses() {
+        let max_distance = Length::new::<light_year>(100.);
+        let config = GalaxyGenerationConfig::default()
+            .with_stellar_density(1e-4)
+            .with_nursery_density(0.)
+            .with_limiting_magnitude(40.)
+            .with_initial_mass_function(CustomInitialMassFunction::new(1., 2., |m| m.powi(-2)));
+        let stars = generate_random_stars_with_config(max_distance, &config, 10).unwrap();
+        assert!(!stars.is_empty());
+        for star in stars {
+            let mass = star.get_mass_at_epoch().unwrap().get::<solar_mass>();
+            assert!(mass > 0.7 && mass < 2.5, "Mass is {}", mass);
+        }
+    }
+
+    #[test]
+    fn star_formation_history_drives_nursery_ages_and_counts() {
+        let max_distance = Length::new::<light_year>(500.);
+        let constant = GalaxyGenerationConfig::default()
+            .with_stellar_density(0.)
+            .with_nursery_density(1e-6)
+            .with_stars_per_nursery(100)
+            .with_limiting_magnitude(40.);
+        // Twice the constant rate on average, but all of it within 5 million years.
+        let burst =
+            constant
+                .clone()
+                .with_star_formation_history(StarFormationHistory::single_burst(
+                    Time::new::<megayear>(20.),
+                    Time::new::<megayear>(5.),
+                    2. * 8_800. / 5.,
+                ));
+        let constant_clusters =
+            generate_random_clusters_with_config(max_distance, &constant, 11).unwrap();
+        let burst_clusters =
+            generate_random_clusters_with_config(max_distance, &burst, 11).unwrap();
+        for cluster in &burst_clusters {
+            let age = cluster.get_cluster().get_age().get::<megayear>();
+            assert!((15. ..=20.).contains(&age));
+        }
+        let ratio = burst_clusters.len() as f64 / constant_clusters.len() as f64;
+        assert!((1.8..2.2).contains(&ratio), "Ratio is {}", ratio);
+    }
 }
@@ -0,0 +1,294 @@
+use astro_units::time::megayear;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uom::si::f64::Time;
+
+/*
+ * The rate at which star forming regions are born, as a function of lookback time, i.e. the age their stars have today.
+ * Rates are relative to the constant rate that reproduces the solar neighbourhood, so a rate of 2 means twice as many
+ * star forming regions per time as in the default galaxy. They drive both the ages of the regions and how many there are.
+ * https://en.wikipedia.org/wiki/Star_formation#Star_formation_rate
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub enum StarFormationHistory {
+    #[default]
+    Constant,
+    // Higher in the past for a positive timescale, and rising towards today for a negative one.
+    ExponentialDecay {
+        present_day_rate: f64,
+        timescale: Time,
+    },
+    Bursts {
+        background_rate: f64,
+        bursts: Vec<StarBurst>,
+    },
+    // Pairs of age and rate, sorted by age and interpolated linearly. Beyond the first and last age the rate is constant.
+    Tabulated(Vec<(Time, f64)>),
+}
+
+// A period of enhanced star formation that began the given time ago.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StarBurst {
+    pub age: Time,
+    pub duration: Time,
+    pub rate: f64,
+}
+
+// The cumulative rate tabulated over lookback time, from which ages are drawn by inversion.
+pub(super) struct AgeDistribution {
+    ages_in_megayears: Vec<f64>,
+    cumulative: Vec<f64>,
+}
+
+const AGE_DISTRIBUTION_STEPS: usize = 2_000;
+
+impl StarBurst {
+    pub fn new(age: Time, duration: Time, rate: f64) -> Self {
+        Self {
+            age,
+            duration,
+            rate,
+        }
+    }
+
+    fn contains(&self, age: Time) -> bool {
+        age <= self.age && age > self.age - self.duration
+    }
+}
+
+impl StarFormationHistory {
+    // A single burst, for example for a young star forming region.
+    pub fn single_burst(age: Time, duration: Time, rate: f64) -> Self {
+        StarFormationHistory::Bursts {
+            background_rate: 0.,
+            bursts: vec![StarBurst::new(age, duration, rate)],
+        }
+    }
+
+    pub fn rate(&self, age: Time) -> f64 {
+        let rate = match self {
+            StarFormationHistory::Constant => 1.,
+            StarFormationHistory::ExponentialDecay {
+                present_day_rate,
+                timescale,
+            } => present_day_rate * (age / *timescale).value.exp(),
+            StarFormationHistory::Bursts {
+                background_rate,
+                bursts,
+            } => {
+                background_rate
+                    + bursts
+                        .iter()
+                        .filter(|burst| burst.contains(age))
+                        .map(|burst| burst.rate)
+                        .sum::<f64>()
+            }
+            StarFormationHistory::Tabulated(table) => interpolate(table, age),
+        };
+        rate.max(0.)
+    }
+
+    // The average rate over the age of the disk, which scales the number of star forming regions.
+    pub fn mean_rate(&self, age_of_disk: Time) -> f64 {
+        match self.age_distribution(age_of_disk) {
+            Some(distribution) => {
+                distribution.cumulative.last().copied().unwrap_or(0.)
+                    / age_of_disk.get::<megayear>()
+            }
+            None => 0.,
+        }
+    }
+
+    /*
+     * The rate is tabulated on a regular grid, refined by the edges of bursts and table entries,
+     * so that even short bursts are resolved exactly.
+     */
+    pub(super) fn age_distribution(&self, age_of_disk: Time) -> Option<AgeDistribution> {
+        let max_age = age_of_disk.get::<megayear>();
+        if max_age <= 0. {
+            return None;
+        }
+        let mut ages: Vec<f64> = (0..=AGE_DISTRIBUTION_STEPS)
+            .map(|i| i as f64 / AGE_DISTRIBUTION_STEPS as f64 * max_age)
+            .collect();
+        match self {
+            StarFormationHistory::Bursts { bursts, .. } => {
+                for burst in bursts {
+                    let end = burst.age.get::<megayear>();
+                    let start = end - burst.duration.get::<megayear>();
+                    ages.extend([start, end, start.next_up(), end.next_up()]);
+                }
+            }
+            StarFormationHistory::Tabulated(table) => {
+                ages.extend(table.iter().map(|(age, _)| age.get::<megayear>()));
+            }
+            _ => {}
+        }
+        ages.retain(|age| (0. ..=max_age).contains(age));
+        ages.sort_by(f64::total_cmp);
+        ages.dedup();
+
+        let mut total = 0.;
+        let mut cumulative = vec![0.];
+        for window in ages.windows(2) {
+            let lower = self.rate(Time::new::<megayear>(window[0]));
+            let upper = self.rate(Time::new::<megayear>(window[1]));
+            total += (lower + upper) / 2. * (window[1] - window[0]);
+            cumulative.push(total);
+        }
+        if total <= 0. {
+            return None;
+        }
+        Some(AgeDistribution {
+            ages_in_megayears: ages,
+            cumulative,
+        })
+    }
+}
+
+impl AgeDistribution {
+    pub(super) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time {
+        let total = self.cumulative.last().copied().unwrap_or(0.);
+        let target = rng.random::<f64>() * total;
+        let upper = self
+            .cumulative
+            .partition_point(|c| *c < target)
+            .clamp(1, self.cumulative.len() - 1);
+        let (c0, c1) = (self.cumulative[upper - 1], self.cumulative[upper]);
+        let (a0, a1) = (
+            self.ages_in_megayears[upper - 1],
+            self.ages_in_megayears[upper],
+        );
+        let fraction = if c1 > c0 {
+            (target - c0) / (c1 - c0)
+        } else {
+            0.
+        };
+        Time::new::<megayear>(a0 + fraction * (a1 - a0))
+    }
+}
+
+fn interpolate(table: &[(Time, f64)], age: Time) -> f64 {
+    let (first, last) = match (table.first(), table.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0.,
+    };
+    if age <= first.0 {
+        return first.1;
+    }
+    if age >= last.0 {
+        return last.1;
+    }
+    let upper = table.partition_point(|(a, _)| *a < age);
+    let (a0, r0) = table[upper - 1];
+    let (a1, r1) = table[upper];
+    r0 + (r1 - r0) * ((age - a0) / (a1 - a0)).value
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::tests::eq_within;
+
+    use super::*;
+
+    fn age_of_disk() -> Time {
+        Time::new::<megayear>(8_800.)
+    }
+
+    fn sample_ages(history: &StarFormationHistory, n: usize) -> Vec<f64> {
+        let distribution = history.age_distribution(age_of_disk()).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        (0..n)
+            .map(|_| distribution.sample(&mut rng).get::<megayear>())
+            .collect()
+    }
+
+    #[test]
+    fn constant_history_has_uniform_ages() {
+        let history = StarFormationHistory::Constant;
+        assert!(eq_within(history.mean_rate(age_of_disk()), 1., 1e-10));
+        let ages = sample_ages(&history, 100_000);
+        let mean = ages.iter().sum::<f64>() / ages.len() as f64;
+        assert!((mean / 4_400. - 1.).abs() < 0.01);
+        assert!(ages.iter().all(|age| (0. ..=8_800.).contains(age)));
+    }
+
+    #[test]
+    fn exponential_decay_favours_old_ages() {
+        let history = StarFormationHistory::ExponentialDecay {
+            present_day_rate: 0.5,
+            timescale: Time::new::<megayear>(4_000.),
+        };
+        let ages = sample_ages(&history, 100_000);
+        let mean = ages.iter().sum::<f64>() / ages.len() as f64;
+        assert!(mean > 5_000.);
+        // The integral of 0.5 * exp(t / 4000) over 8800 Myr, divided by 8800 Myr.
+        let expected = 0.5 * 4_000. * ((8_800f64 / 4_000.).exp() - 1.) / 8_800.;
+        assert!(eq_within(history.mean_rate(age_of_disk()), expected, 1e-3));
+    }
+
+    #[test]
+    fn young_burst_only_produces_young_ages() {
+        let history = StarFormationHistory::single_burst(
+            Time::new::<megayear>(20.),
+            Time::new::<megayear>(5.),
+            100.,
+        );
+        let ages = sample_ages(&history, 10_000);
+        assert!(ages.iter().all(|age| (15. ..=20.).contains(age)));
+        let expected = 100. * 5. / 8_800.;
+        assert!(eq_within(history.mean_rate(age_of_disk()), expected, 1e-3));
+    }
+
+    #[test]
+    fn bursts_add_to_the_background() {
+        let history = StarFormationHistory::Bursts {
+            background_rate: 1.,
+            bursts: vec![StarBurst::new(
+                Time::new::<megayear>(1_000.),
+                Time::new::<megayear>(500.),
+                3.,
+            )],
+        };
+        assert_eq!(history.rate(Time::new::<megayear>(100.)), 1.);
+        assert_eq!(history.rate(Time::new::<megayear>(800.)), 4.);
+        let ages = sample_ages(&history, 100_000);
+        let in_burst = ages
+            .iter()
+            .filter(|age| (500. ..=1_000.).contains(*age))
+            .count();
+        // 2000 of 10300 star forming regions form during the burst.
+        assert!((in_burst as f64 / ages.len() as f64 - 2_000. / 10_300.).abs() < 0.01);
+    }
+
+    #[test]
+    fn tabulated_history_is_interpolated() {
+        let history = StarFormationHistory::Tabulated(vec![
+            (Time::new::<megayear>(0.), 0.),
+            (Time::new::<megayear>(1_000.), 2.),
+            (Time::new::<megayear>(3_000.), 0.),
+        ]);
+        assert!(eq_within(
+            history.rate(Time::new::<megayear>(500.)),
+            1.,
+            1e-10
+        ));
+        assert!(eq_within(
+            history.rate(Time::new::<megayear>(2_000.)),
+            1.,
+            1e-10
+        ));
+        assert_eq!(history.rate(Time::new::<megayear>(5_000.)), 0.);
+        let ages = sample_ages(&history, 10_000);
+        assert!(ages.iter().all(|age| *age <= 3_000.));
+    }
+
+    #[test]
+    fn vanishing_history_has_no_age_distribution() {
+        let history = StarFormationHistory::Tabulated(vec![]);
+        assert!(history.age_distribution(age_of_disk()).is_none());
+        assert_eq!(history.mean_rate(age_of_disk()), 0.);
+    }
+}
@@ -11,11 +11,11 @@ use uom::si::{
     time::year,
 };
 
-use crate::color::srgb::sRGBColor;
+use crate::{color::srgb::sRGBColor, kinematics::velocity_vector::VelocityVector};
 
 use super::{
-    appearance::StarAppearance, data::StarData, evolution::StarDataEvolution, fate::StarFate,
-    physical_parameters,
+    appearance::StarAppearance, data::StarData, evolution::StarDataEvolution, motion::StarMotion,
+    physical_parameters, spectral_type::SpectralType, variability::Variability,
 };
 
 pub struct RealData {
@@ -28,10 +28,12 @@ pub struct RealData {
     pub apparent_magnitude: f64,
     pub temperature: ThermodynamicTemperature,
     pub age: Option<Time>,
-    pub lifetime: Time,
     pub right_ascension: RightAscension,
     pub declination: Declination,
     pub distance: Length,
+    pub motion: Option<StarMotion>,
+    pub variability: Option<Variability>,
+    pub spectral_type: &'static str, // As catalogued, or empty if unknown
 }
 
 impl RealData {
@@ -61,18 +63,35 @@ impl RealData {
         let pos = EarthEquatorial::new(ra, dec)
             .to_direction()
             .to_cartesian(self.distance);
+        let velocity = match &self.motion {
+            Some(motion) => motion.to_velocity(ra, dec, self.distance),
+            None => VelocityVector::zero(),
+        };
 
-        let evolution =
-            StarDataEvolution::new(None, self.age, self.lifetime, StarFate::new(self.mass));
+        let evolution = StarDataEvolution::from_catalogue(
+            self.mass,
+            None,
+            self.age,
+            luminous_intensity,
+            self.temperature,
+        );
         StarData {
             name: name.to_string(),
             constellation,
             params: physical_parameters,
             pos,
+            velocity,
             evolution,
+            metallicity: None,
+            variability: self.variability.clone(),
         }
     }
 
+    // Composite spectra of unresolved multiple stars and other notations outside of O to M are not parsed.
+    pub fn get_spectral_type(&self) -> Option<SpectralType> {
+        self.spectral_type.parse().ok()
+    }
+
     pub fn to_star_appearance(&self) -> StarAppearance {
         let name = if self.common_name.is_empty() {
             self.astronomical_name
@@ -106,7 +125,38 @@ mod tests {
         time::year,
     };
 
-    use crate::real_data::stars::all::get_many_stars;
+    use crate::{real_data::stars::all::get_many_stars, stars::spectral_type::LuminosityClass};
+
+    #[test]
+    fn catalogued_spectral_types_agree_with_the_derived_ones() {
+        let mut compared = 0;
+        let mut same_temperature = 0;
+        let mut same_luminosity = 0;
+        for star_data in get_many_stars() {
+            let catalogued = match star_data.get_spectral_type() {
+                Some(spectral_type) => spectral_type,
+                None => continue,
+            };
+            let derived = star_data
+                .to_star_data()
+                .get_spectral_type(Time::new::<year>(0.))
+                .unwrap();
+            compared += 1;
+            if catalogued.subclasses_between(&derived) <= 3. {
+                same_temperature += 1;
+            }
+            // Dwarfs and subgiants, giants, and supergiants.
+            let group = |luminosity_class: Option<LuminosityClass>| {
+                luminosity_class.map(|class| (class.is_giant(), class.is_supergiant()))
+            };
+            if group(catalogued.get_luminosity_class()) == group(derived.get_luminosity_class()) {
+                same_luminosity += 1;
+            }
+        }
+        assert!(compared > 300);
+        assert!(same_temperature as f64 > 0.9 * compared as f64);
+        assert!(same_luminosity as f64 > 0.75 * compared as f64);
+    }
 
     #[test]
     fn calculate_apparent_magnitude() {
@@ -183,14 +233,13 @@ mod tests {
     #[test]
     fn no_star_is_older_than_its_lifetime() {
         for star_data in get_many_stars() {
-            assert!(star_data.lifetime > Time::new::<year>(0.));
-            if let Some(age) = star_data.age {
-                assert!(
-                    age < star_data.lifetime,
-                    "{} is older than its lifetime",
-                    star_data.astronomical_name
-                );
-            }
+            let star = star_data.to_star_data();
+            assert!(star.get_lifetime() > Time::new::<year>(0.));
+            assert!(
+                star.get_age_at_epoch().unwrap() < star.get_lifetime(),
+                "{} is older than its lifetime",
+                star_data.astronomical_name
+            );
         }
     }
 
@@ -212,15 +261,58 @@ mod tests {
 
     #[test]
     fn real_stars_age_is_passed_to_star_data() {
+        let mut with_age = 0;
+        let mut rejected = 0;
         for star_data in get_many_stars() {
             if let Some(age) = star_data.age {
+                with_age += 1;
+                let star = star_data.to_star_data();
+                let fit = star.get_evolution().get_isochrone_fit().unwrap();
+                if fit.get_rejected_age().is_some() {
+                    rejected += 1;
+                    assert_eq!(fit.get_rejected_age(), Some(age));
+                    assert!(age >= star.get_lifetime());
+                    assert!(!fit.is_plausible());
+                    continue;
+                }
                 assert_eq!(
-                    star_data.to_star_data().get_age_at_epoch().unwrap(),
+                    star.get_age_at_epoch().unwrap(),
                     age,
                     "{}",
                     star_data.astronomical_name
                 );
             }
         }
+        // Mostly massive stars, whose catalogued ages are older than their PARSEC tracks allow.
+        assert!(rejected * 5 < with_age, "{} of {} ages", rejected, with_age);
+    }
+
+    #[test]
+    fn every_real_star_gets_an_isochrone_fit() {
+        for star_data in get_many_stars() {
+            let star = star_data.to_star_data();
+            let fit = star.get_evolution().get_isochrone_fit().unwrap();
+            assert!(fit.get_chi_squared() >= 0.);
+            if star_data.age.is_none() {
+                assert_eq!(star.get_age_at_epoch(), Some(fit.get_age()));
+                assert!(fit.get_age() < star.get_lifetime());
+            }
+        }
+    }
+
+    #[test]
+    fn betelgeuse_fits_its_track_shortly_before_its_death() {
+        let betelgeuse = get_many_stars()
+            .into_iter()
+            .find(|star_data| star_data.common_name == "Betelgeuse")
+            .unwrap()
+            .to_star_data();
+        let time_until_death = betelgeuse
+            .get_time_until_death(Time::new::<year>(0.))
+            .unwrap();
+        assert!(time_until_death > Time::new::<year>(0.));
+        assert!(time_until_death < Time::new::<year>(1_000.));
+        let fit = betelgeuse.get_evolution().get_isochrone_fit().unwrap();
+        assert!(fit.is_plausible(), "chi squared: {}", fit.get_chi_squared());
     }
 }
@@ -0,0 +1,272 @@
+use astro_units::{length::solar_radius, mass::solar_mass};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{Length, LuminousIntensity, Mass, Power, ThermodynamicTemperature, Time},
+    length::{kilometer, meter},
+    mass::kilogram,
+    power::watt,
+    thermodynamic_temperature::kelvin,
+    time::year,
+};
+
+use crate::astro_display::AstroDisplay;
+
+use super::photometry::{luminous_intensity_from_bolometric_luminosity, SOLAR_LUMINOSITY_IN_WATTS};
+
+// What is left of a star after its death.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Remnant {
+    WhiteDwarf,
+    NeutronStar,
+    BlackHole,
+}
+
+pub(super) const CHANDRASEKHAR_MASS: f64 = 1.44;
+const NEUTRON_STAR_MASS: f64 = 1.4;
+const NEUTRON_STAR_RADIUS_IN_KM: f64 = 12.;
+const SOLAR_TEMPERATURE: f64 = 5_772.;
+
+impl Remnant {
+    /*
+     * The mass of the star before its death stands in for its initial mass.
+     * White dwarfs follow the semi-empirical initial-final mass relation,
+     * and black holes keep roughly the helium core.
+     * https://ui.adsabs.harvard.edu/abs/2018ApJ...866...21C/abstract
+     */
+    pub(super) fn mass(&self, progenitor_mass: Option<Mass>) -> Mass {
+        let progenitor = self.progenitor_mass_in_solar(progenitor_mass);
+        let mass = match self {
+            Remnant::WhiteDwarf => {
+                let mass = if progenitor < 2.85 {
+                    0.08 * progenitor + 0.489
+                } else if progenitor < 3.6 {
+                    0.187 * progenitor + 0.184
+                } else {
+                    0.107 * progenitor + 0.471
+                };
+                mass.min(CHANDRASEKHAR_MASS).min(progenitor)
+            }
+            Remnant::NeutronStar => NEUTRON_STAR_MASS,
+            Remnant::BlackHole => (0.3 * progenitor).clamp(5., 40.),
+        };
+        Mass::new::<solar_mass>(mass)
+    }
+
+    pub(super) fn radius(&self, progenitor_mass: Option<Mass>) -> Length {
+        let mass = self.mass(progenitor_mass);
+        match self {
+            Remnant::WhiteDwarf => white_dwarf_radius(mass),
+            Remnant::NeutronStar => Length::new::<kilometer>(NEUTRON_STAR_RADIUS_IN_KM),
+            Remnant::BlackHole => schwarzschild_radius(mass),
+        }
+    }
+
+    // Black holes do not shine.
+    pub(super) fn temperature(
+        &self,
+        progenitor_mass: Option<Mass>,
+        time_since_death: Time,
+    ) -> Option<ThermodynamicTemperature> {
+        let temperature = match self {
+            Remnant::WhiteDwarf => {
+                let luminosity =
+                    white_dwarf_luminosity_in_solar(self.mass(progenitor_mass), time_since_death);
+                let radius = self.radius(progenitor_mass).get::<solar_radius>();
+                SOLAR_TEMPERATURE * luminosity.powf(0.25) / radius.sqrt()
+            }
+            Remnant::NeutronStar => neutron_star_temperature_in_kelvin(time_since_death),
+            Remnant::BlackHole => return None,
+        };
+        Some(ThermodynamicTemperature::new::<kelvin>(temperature))
+    }
+
+    /*
+     * Hot remnants radiate most of their power in the ultraviolet and X-rays,
+     * so only a small part of it reaches the visual band.
+     */
+    pub(super) fn luminous_intensity(
+        &self,
+        progenitor_mass: Option<Mass>,
+        time_since_death: Time,
+    ) -> Option<LuminousIntensity> {
+        let luminosity_in_solar = match self {
+            Remnant::WhiteDwarf => {
+                white_dwarf_luminosity_in_solar(self.mass(progenitor_mass), time_since_death)
+            }
+            Remnant::NeutronStar => {
+                let radius = self.radius(progenitor_mass).get::<solar_radius>();
+                let temperature = neutron_star_temperature_in_kelvin(time_since_death);
+                radius.powi(2) * (temperature / SOLAR_TEMPERATURE).powi(4)
+            }
+            Remnant::BlackHole => return None,
+        };
+        let temperature = self.temperature(progenitor_mass, time_since_death)?;
+        let luminosity = Power::new::<watt>(luminosity_in_solar * SOLAR_LUMINOSITY_IN_WATTS);
+        Some(luminous_intensity_from_bolometric_luminosity(
+            luminosity,
+            temperature,
+        ))
+    }
+
+    fn progenitor_mass_in_solar(&self, progenitor_mass: Option<Mass>) -> f64 {
+        let typical = match self {
+            Remnant::WhiteDwarf => 1.,
+            Remnant::NeutronStar => 15.,
+            Remnant::BlackHole => 25.,
+        };
+        progenitor_mass
+            .map(|m| m.get::<solar_mass>())
+            .unwrap_or(typical)
+    }
+}
+
+/*
+ * Electron degeneracy makes heavier white dwarfs smaller, down to zero at the Chandrasekhar mass.
+ * https://ui.adsabs.harvard.edu/abs/1972ApJ...175..417N/abstract
+ */
+fn white_dwarf_radius(mass: Mass) -> Length {
+    let ratio = (mass.get::<solar_mass>() / CHANDRASEKHAR_MASS).min(0.999);
+    let radius = 0.0112 * (ratio.powf(-2. / 3.) - ratio.powf(2. / 3.)).sqrt();
+    Length::new::<solar_radius>(radius)
+}
+
+/*
+ * Mestel's law for a carbon-oxygen core, which radiates away its thermal energy ever more slowly.
+ * Its luminosity diverges for young white dwarfs, which are still hidden in their planetary nebula.
+ * https://en.wikipedia.org/wiki/White_dwarf#Radiation_and_cooling
+ * https://ui.adsabs.harvard.edu/abs/1952MNRAS.112..583M/abstract
+ */
+fn white_dwarf_luminosity_in_solar(mass: Mass, time_since_death: Time) -> f64 {
+    const MESTEL_TIMESCALE_IN_YEARS: f64 = 8.8e6;
+    const MEAN_ATOMIC_WEIGHT: f64 = 14.;
+    const MIN_COOLING_YEARS: f64 = 1_000.;
+    let years = time_since_death.get::<year>().max(MIN_COOLING_YEARS);
+    let timescale = MESTEL_TIMESCALE_IN_YEARS * 12. / MEAN_ATOMIC_WEIGHT
+        * mass.get::<solar_mass>().powf(5. / 7.);
+    (timescale / years).powf(7. / 5.)
+}
+
+/*
+ * For the first hundred thousand years, neutrinos cool the interior while the surface stays near a million Kelvin.
+ * Afterwards photons from the surface take over, and the star cools much faster.
+ * https://en.wikipedia.org/wiki/Neutron_star#Temperature
+ * https://ui.adsabs.harvard.edu/abs/2006NuPhA.777..497P/abstract
+ */
+fn neutron_star_temperature_in_kelvin(time_since_death: Time) -> f64 {
+    const TEMPERATURE_AFTER_1000_YEARS: f64 = 1e6;
+    const NEUTRINO_ERA_IN_YEARS: f64 = 1e5;
+    const MIN_COOLING_YEARS: f64 = 1.;
+    let years = time_since_death.get::<year>().max(MIN_COOLING_YEARS);
+    let neutrino_cooling =
+        |years: f64| TEMPERATURE_AFTER_1000_YEARS * (years / 1e3).powf(-1. / 12.);
+    if years < NEUTRINO_ERA_IN_YEARS {
+        neutrino_cooling(years)
+    } else {
+        neutrino_cooling(NEUTRINO_ERA_IN_YEARS) * (years / NEUTRINO_ERA_IN_YEARS).powf(-0.5)
+    }
+}
+
+// https://en.wikipedia.org/wiki/Schwarzschild_radius
+fn schwarzschild_radius(mass: Mass) -> Length {
+    const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-11;
+    const SPEED_OF_LIGHT: f64 = 299_792_458.;
+    let radius = 2. * GRAVITATIONAL_CONSTANT * mass.get::<kilogram>() / SPEED_OF_LIGHT.powi(2);
+    Length::new::<meter>(radius)
+}
+
+impl AstroDisplay for Remnant {
+    fn astro_display(&self) -> String {
+        match self {
+            Remnant::WhiteDwarf => "White Dwarf".to_string(),
+            Remnant::NeutronStar => "Neutron Star".to_string(),
+            Remnant::BlackHole => "Black Hole".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_units::{
+        luminous_intensity::luminous_intensity_to_absolute_magnitude, time::gigayear,
+    };
+
+    use super::*;
+
+    fn solar_masses(mass: f64) -> Option<Mass> {
+        Some(Mass::new::<solar_mass>(mass))
+    }
+
+    #[test]
+    fn heavier_white_dwarfs_are_smaller() {
+        let light = Remnant::WhiteDwarf.radius(solar_masses(1.));
+        let heavy = Remnant::WhiteDwarf.radius(solar_masses(6.));
+        assert!(heavy < light);
+        // Sirius B has about one solar mass and 0.0084 solar radii.
+        let sirius_b = white_dwarf_radius(Mass::new::<solar_mass>(1.02));
+        assert!((sirius_b.get::<solar_radius>() - 0.0084).abs() < 0.001);
+    }
+
+    #[test]
+    fn white_dwarfs_cool_and_fade() {
+        let mut last_temperature = f64::INFINITY;
+        let mut last_luminous_intensity = f64::INFINITY;
+        for gigayears in [0.01, 0.1, 1., 10.] {
+            let time = Time::new::<gigayear>(gigayears);
+            let temperature = Remnant::WhiteDwarf
+                .temperature(solar_masses(1.), time)
+                .unwrap()
+                .get::<kelvin>();
+            let luminous_intensity = Remnant::WhiteDwarf
+                .luminous_intensity(solar_masses(1.), time)
+                .unwrap()
+                .value;
+            assert!(temperature < last_temperature);
+            assert!(luminous_intensity < last_luminous_intensity);
+            last_temperature = temperature;
+            last_luminous_intensity = luminous_intensity;
+        }
+        // After ten billion years, the coolest white dwarfs are at about 4000 K.
+        assert!((3_000. ..6_000.).contains(&last_temperature));
+    }
+
+    #[test]
+    fn sirius_b_is_roughly_reproduced() {
+        // Sirius B formed from a star of about 5 solar masses roughly 100 million years ago.
+        let time = Time::new::<gigayear>(0.1);
+        let temperature = Remnant::WhiteDwarf
+            .temperature(solar_masses(5.), time)
+            .unwrap()
+            .get::<kelvin>();
+        assert!((15_000. ..40_000.).contains(&temperature));
+    }
+
+    #[test]
+    fn neutron_stars_stay_hot_for_a_long_time() {
+        let young = Remnant::NeutronStar
+            .temperature(None, Time::new::<year>(1e3))
+            .unwrap();
+        let old = Remnant::NeutronStar
+            .temperature(None, Time::new::<year>(1e7))
+            .unwrap();
+        assert!((young.get::<kelvin>() - 1e6).abs() < 1.);
+        assert!(old < young);
+        assert!(old.get::<kelvin>() > 1e4);
+    }
+
+    #[test]
+    fn neutron_stars_are_optically_faint() {
+        for years in [1e3, 1e5, 1e7] {
+            let luminous_intensity = Remnant::NeutronStar
+                .luminous_intensity(None, Time::new::<year>(years))
+                .unwrap();
+            assert!(luminous_intensity_to_absolute_magnitude(luminous_intensity) > 20.);
+        }
+    }
+
+    #[test]
+    fn black_holes_are_dark() {
+        let time = Time::new::<year>(1e3);
+        assert!(Remnant::BlackHole.temperature(None, time).is_none());
+        assert!(Remnant::BlackHole.luminous_intensity(None, time).is_none());
+    }
+}
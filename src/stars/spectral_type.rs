@@ -0,0 +1,619 @@
+use std::str::FromStr;
+
+use astro_units::{
+    luminous_intensity::{
+        absolute_magnitude_to_luminous_intensity, luminous_intensity_to_absolute_magnitude,
+    },
+    mass::solar_mass,
+};
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{LuminousIntensity, Mass, ThermodynamicTemperature},
+    thermodynamic_temperature::kelvin,
+};
+
+use crate::{astro_display::AstroDisplay, error::AstroUtilError};
+
+use super::{
+    photometry::{bolometric_luminosity, radius_from_bolometric_luminosity},
+    physical_parameters::StarPhysicalParameters,
+};
+
+/*
+ * The Morgan-Keenan classification of a star by its spectrum.
+ * The spectral class and its subclass order the stars by temperature, from O0 to M9.5,
+ * the luminosity class by their luminosity at that temperature, from the hypergiants (Ia0) to the white dwarfs (VII).
+ * Catalogues give a range when a star lies between two subclasses or luminosity classes, as in "M1-2Ia-ab".
+ * https://en.wikipedia.org/wiki/Stellar_classification
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpectralType {
+    pub(super) class: SpectralClass,
+    pub(super) subclass: f64,
+    pub(super) subclass_range_end: Option<f64>,
+    pub(super) luminosity_class: Option<LuminosityClass>,
+    pub(super) luminosity_class_range_end: Option<LuminosityClass>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum SpectralClass {
+    O,
+    B,
+    A,
+    F,
+    G,
+    K,
+    M,
+}
+
+// Sorted from the most to the least luminous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LuminosityClass {
+    Ia0,
+    Ia,
+    Iab,
+    Ib,
+    II,
+    III,
+    IV,
+    V,
+    VI,
+    VII,
+}
+
+const SPECTRAL_CLASSES: [SpectralClass; 7] = [
+    SpectralClass::O,
+    SpectralClass::B,
+    SpectralClass::A,
+    SpectralClass::F,
+    SpectralClass::G,
+    SpectralClass::K,
+    SpectralClass::M,
+];
+
+const LUMINOSITY_CLASSES: [LuminosityClass; 10] = [
+    LuminosityClass::Ia0,
+    LuminosityClass::Ia,
+    LuminosityClass::Iab,
+    LuminosityClass::Ib,
+    LuminosityClass::II,
+    LuminosityClass::III,
+    LuminosityClass::IV,
+    LuminosityClass::V,
+    LuminosityClass::VI,
+    LuminosityClass::VII,
+];
+
+// Longer notations come first, so that "Iab" is not read as "Ia".
+const LUMINOSITY_CLASS_NOTATIONS: [(&str, LuminosityClass); 13] = [
+    ("Ia0", LuminosityClass::Ia0),
+    ("Ia+", LuminosityClass::Ia0),
+    ("Iab", LuminosityClass::Iab),
+    ("Ia", LuminosityClass::Ia),
+    ("Ib", LuminosityClass::Ib),
+    ("III", LuminosityClass::III),
+    ("II", LuminosityClass::II),
+    ("IV", LuminosityClass::IV),
+    ("VII", LuminosityClass::VII),
+    ("VI", LuminosityClass::VI),
+    ("V", LuminosityClass::V),
+    ("I", LuminosityClass::Iab),
+    ("0", LuminosityClass::Ia0),
+];
+
+/*
+ * The temperature and absolute visual magnitude of dwarfs and giants along the spectral sequence,
+ * together with the mass of the dwarfs. Giants are cooler than dwarfs of the same type, except for the M giants.
+ * The spectral code counts subclasses from O0, so that G2 is 42.
+ * https://ui.adsabs.harvard.edu/abs/2013ApJS..208....9P/abstract
+ * https://www.pas.rochester.edu/~emamajek/EEM_dwarf_UBVIJHK_colors_Teff.txt
+ * https://ui.adsabs.harvard.edu/abs/1982lbg6.conf.....A/abstract
+ */
+struct SpectralSequenceEntry {
+    code: f64,
+    dwarf_temperature: f64,
+    dwarf_magnitude: f64,
+    dwarf_mass: f64,
+    giant_temperature: f64,
+    giant_magnitude: f64,
+}
+
+const fn entry(values: [f64; 6]) -> SpectralSequenceEntry {
+    SpectralSequenceEntry {
+        code: values[0],
+        dwarf_temperature: values[1],
+        dwarf_magnitude: values[2],
+        dwarf_mass: values[3],
+        giant_temperature: values[4],
+        giant_magnitude: values[5],
+    }
+}
+
+// Sorted by increasing spectral code and thus decreasing temperature.
+const SPECTRAL_SEQUENCE: [SpectralSequenceEntry; 26] = [
+    entry([3., 44_900., -5.8, 59., 42_500., -6.2]),
+    entry([5., 41_400., -5.5, 40., 39_000., -6.0]),
+    entry([7., 36_500., -4.9, 27., 35_000., -5.6]),
+    entry([9., 32_500., -4.3, 19., 31_500., -5.3]),
+    entry([10., 31_400., -3.9, 17., 29_000., -5.0]),
+    entry([12., 20_600., -2.4, 8.5, 20_000., -3.6]),
+    entry([15., 15_700., -1.2, 4.6, 15_000., -2.2]),
+    entry([18., 12_300., -0.2, 3.2, 12_000., -1.2]),
+    entry([20., 9_700., 0.6, 2.3, 9_500., 0.0]),
+    entry([25., 8_100., 1.9, 1.9, 8_000., 0.7]),
+    entry([30., 7_220., 2.7, 1.6, 7_100., 1.5]),
+    entry([35., 6_510., 3.5, 1.3, 6_400., 1.6]),
+    entry([40., 5_920., 4.4, 1.1, 5_600., 1.0]),
+    entry([42., 5_770., 4.7, 1.0, 5_400., 0.9]),
+    entry([45., 5_660., 5.1, 0.98, 5_100., 0.9]),
+    entry([48., 5_490., 5.6, 0.94, 4_950., 0.8]),
+    entry([50., 5_280., 5.9, 0.88, 4_800., 0.7]),
+    entry([52., 5_040., 6.4, 0.82, 4_500., 0.5]),
+    entry([55., 4_450., 7.35, 0.70, 4_000., -0.2]),
+    entry([57., 4_050., 8.2, 0.63, 3_900., -0.3]),
+    entry([60., 3_850., 8.9, 0.57, 3_850., -0.4]),
+    entry([62., 3_560., 10.3, 0.44, 3_650., -0.6]),
+    entry([64., 3_210., 12.8, 0.23, 3_450., -0.5]),
+    entry([65., 3_060., 14.3, 0.16, 3_350., -0.3]),
+    entry([67., 2_680., 17.8, 0.10, 3_150., -0.2]),
+    entry([69., 2_380., 19.9, 0.08, 2_950., 0.0]),
+];
+
+/*
+ * Supergiants span a narrow range of absolute magnitudes over the whole sequence,
+ * while subdwarfs lie somewhat and white dwarfs far below the main sequence.
+ * https://ui.adsabs.harvard.edu/abs/1982lbg6.conf.....A/abstract
+ */
+const FAINTEST_SUPERGIANT_MAGNITUDE: f64 = -4.5;
+const SUPERGIANT_MAGNITUDE_STEP: f64 = 1.2;
+const HYPERGIANT_MAGNITUDE_OFFSET: f64 = 6.;
+const SUBDWARF_MAGNITUDE_OFFSET: f64 = 2.;
+const BRIGHTEST_WHITE_DWARF_MAGNITUDE: f64 = 11.;
+
+impl SpectralType {
+    pub fn new(
+        class: SpectralClass,
+        subclass: f64,
+        luminosity_class: Option<LuminosityClass>,
+    ) -> Self {
+        Self {
+            class,
+            subclass,
+            subclass_range_end: None,
+            luminosity_class,
+            luminosity_class_range_end: None,
+        }
+    }
+
+    pub fn with_subclass_range_end(mut self, subclass: f64) -> Self {
+        self.subclass_range_end = Some(subclass);
+        self
+    }
+
+    pub fn with_luminosity_class_range_end(mut self, luminosity_class: LuminosityClass) -> Self {
+        self.luminosity_class_range_end = Some(luminosity_class);
+        self
+    }
+
+    /*
+     * The spectral type of a star at the given position in the HR diagram.
+     * The luminosity class is the one whose typical absolute magnitude is closest,
+     * and the temperature scale of the giants applies to stars above the subgiants.
+     */
+    pub fn from_temperature_and_luminous_intensity(
+        temperature: ThermodynamicTemperature,
+        luminous_intensity: LuminousIntensity,
+    ) -> Self {
+        let absolute_magnitude = luminous_intensity_to_absolute_magnitude(luminous_intensity);
+        let mut code = spectral_code(temperature, false);
+        let mut luminosity_class = LuminosityClass::closest(code, absolute_magnitude);
+        if luminosity_class.is_giant() {
+            code = spectral_code(temperature, true);
+            luminosity_class = LuminosityClass::closest(code, absolute_magnitude);
+        }
+        let code = code.round();
+        let class_index = ((code / 10.) as usize).min(SPECTRAL_CLASSES.len() - 1);
+        Self::new(
+            SPECTRAL_CLASSES[class_index],
+            code - 10. * class_index as f64,
+            Some(luminosity_class),
+        )
+    }
+
+    pub fn get_class(&self) -> SpectralClass {
+        self.class
+    }
+
+    pub fn get_subclass(&self) -> f64 {
+        self.subclass
+    }
+
+    pub fn get_subclass_range_end(&self) -> Option<f64> {
+        self.subclass_range_end
+    }
+
+    pub fn get_luminosity_class(&self) -> Option<LuminosityClass> {
+        self.luminosity_class
+    }
+
+    pub fn get_luminosity_class_range_end(&self) -> Option<LuminosityClass> {
+        self.luminosity_class_range_end
+    }
+
+    // How many subclasses lie between the middles of the two types, so that G8 and K1 are 3 apart.
+    pub fn subclasses_between(&self, other: &SpectralType) -> f64 {
+        (self.code() - other.code()).abs()
+    }
+
+    // Without a luminosity class, a star is assumed to lie on the main sequence.
+    pub fn typical_temperature(&self) -> ThermodynamicTemperature {
+        let is_giant = self.luminosity_class.is_some_and(|class| class.is_giant());
+        let entry = interpolated_sequence(self.code());
+        let temperature = if is_giant {
+            entry.giant_temperature
+        } else {
+            entry.dwarf_temperature
+        };
+        ThermodynamicTemperature::new::<kelvin>(temperature)
+    }
+
+    pub fn typical_absolute_magnitude(&self) -> f64 {
+        let code = self.code();
+        let first = self.luminosity_class.unwrap_or(LuminosityClass::V);
+        let last = self.luminosity_class_range_end.unwrap_or(first);
+        (first.typical_absolute_magnitude(code) + last.typical_absolute_magnitude(code)) / 2.
+    }
+
+    pub fn typical_luminous_intensity(&self) -> LuminousIntensity {
+        absolute_magnitude_to_luminous_intensity(self.typical_absolute_magnitude())
+    }
+
+    // Only for dwarfs does the spectral type determine the mass.
+    pub fn typical_parameters(&self) -> StarPhysicalParameters {
+        let temperature = self.typical_temperature();
+        let luminous_intensity = self.typical_luminous_intensity();
+        let luminosity = bolometric_luminosity(None, temperature, luminous_intensity);
+        let radius = radius_from_bolometric_luminosity(luminosity, temperature);
+        let mass = match self.luminosity_class {
+            None | Some(LuminosityClass::V) => Some(Mass::new::<solar_mass>(
+                interpolated_sequence(self.code()).dwarf_mass,
+            )),
+            Some(_) => None,
+        };
+        StarPhysicalParameters::new(mass, Some(radius), luminous_intensity, temperature)
+    }
+
+    fn code(&self) -> f64 {
+        let subclass = match self.subclass_range_end {
+            Some(end) => (self.subclass + end) / 2.,
+            None => self.subclass,
+        };
+        10. * self.class as usize as f64 + subclass
+    }
+}
+
+impl LuminosityClass {
+    pub fn is_giant(&self) -> bool {
+        *self <= LuminosityClass::III
+    }
+
+    pub fn is_supergiant(&self) -> bool {
+        *self <= LuminosityClass::Ib
+    }
+
+    fn typical_absolute_magnitude(&self, code: f64) -> f64 {
+        let entry = interpolated_sequence(code);
+        let dwarf = entry.dwarf_magnitude;
+        let giant = entry.giant_magnitude;
+        let supergiant = FAINTEST_SUPERGIANT_MAGNITUDE.min(giant - 1.);
+        match self {
+            LuminosityClass::Ia0 => supergiant - HYPERGIANT_MAGNITUDE_OFFSET,
+            LuminosityClass::Ia => supergiant - 2. * SUPERGIANT_MAGNITUDE_STEP,
+            LuminosityClass::Iab => supergiant - SUPERGIANT_MAGNITUDE_STEP,
+            LuminosityClass::Ib => supergiant,
+            LuminosityClass::II => (giant + supergiant) / 2.,
+            LuminosityClass::III => giant,
+            LuminosityClass::IV => (dwarf + giant) / 2.,
+            LuminosityClass::V => dwarf,
+            LuminosityClass::VI => dwarf + SUBDWARF_MAGNITUDE_OFFSET,
+            LuminosityClass::VII => BRIGHTEST_WHITE_DWARF_MAGNITUDE.max(dwarf + 5.),
+        }
+    }
+
+    fn closest(code: f64, absolute_magnitude: f64) -> Self {
+        let distance = |class: &LuminosityClass| {
+            (class.typical_absolute_magnitude(code) - absolute_magnitude).abs()
+        };
+        LUMINOSITY_CLASSES
+            .into_iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or(LuminosityClass::V)
+    }
+}
+
+// The temperature scale of dwarfs or giants, linear in log temperature and clamped to the ends of the sequence.
+fn spectral_code(temperature: ThermodynamicTemperature, is_giant: bool) -> f64 {
+    let temperature_of = |entry: &SpectralSequenceEntry| {
+        if is_giant {
+            entry.giant_temperature
+        } else {
+            entry.dwarf_temperature
+        }
+    };
+    let first = &SPECTRAL_SEQUENCE[0];
+    let last = &SPECTRAL_SEQUENCE[SPECTRAL_SEQUENCE.len() - 1];
+    let temperature = temperature
+        .get::<kelvin>()
+        .clamp(temperature_of(last), temperature_of(first));
+    let upper = SPECTRAL_SEQUENCE
+        .partition_point(|entry| temperature_of(entry) > temperature)
+        .clamp(1, SPECTRAL_SEQUENCE.len() - 1);
+    let (hot, cool) = (&SPECTRAL_SEQUENCE[upper - 1], &SPECTRAL_SEQUENCE[upper]);
+    let weight = (temperature / temperature_of(hot)).ln()
+        / (temperature_of(cool) / temperature_of(hot)).ln();
+    hot.code + (cool.code - hot.code) * weight
+}
+
+// Linear in the spectral code, clamped to the ends of the sequence.
+fn interpolated_sequence(code: f64) -> SpectralSequenceEntry {
+    let first = &SPECTRAL_SEQUENCE[0];
+    let last = &SPECTRAL_SEQUENCE[SPECTRAL_SEQUENCE.len() - 1];
+    let code = code.clamp(first.code, last.code);
+    let upper = SPECTRAL_SEQUENCE
+        .partition_point(|entry| entry.code < code)
+        .clamp(1, SPECTRAL_SEQUENCE.len() - 1);
+    let (early, late) = (&SPECTRAL_SEQUENCE[upper - 1], &SPECTRAL_SEQUENCE[upper]);
+    let weight = (code - early.code) / (late.code - early.code);
+    let lerp = |a: f64, b: f64| a + (b - a) * weight;
+    entry([
+        code,
+        lerp(early.dwarf_temperature, late.dwarf_temperature),
+        lerp(early.dwarf_magnitude, late.dwarf_magnitude),
+        lerp(early.dwarf_mass, late.dwarf_mass),
+        lerp(early.giant_temperature, late.giant_temperature),
+        lerp(early.giant_magnitude, late.giant_magnitude),
+    ])
+}
+
+/*
+ * Reads the notation of catalogues like SIMBAD.
+ * Peculiarities following the luminosity class, like the "e" of emission line stars, are ignored,
+ * and composite spectra are read up to the first component.
+ */
+impl FromStr for SpectralType {
+    type Err = AstroUtilError;
+
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        let invalid = || AstroUtilError::InvalidSpectralType(notation.to_string());
+        let rest = notation.trim();
+        let class = match rest.chars().next() {
+            Some('O') => SpectralClass::O,
+            Some('B') => SpectralClass::B,
+            Some('A') => SpectralClass::A,
+            Some('F') => SpectralClass::F,
+            Some('G') => SpectralClass::G,
+            Some('K') => SpectralClass::K,
+            Some('M') => SpectralClass::M,
+            _ => return Err(invalid()),
+        };
+        let (subclass, rest) = parse_subclass(&rest[1..]).ok_or_else(invalid)?;
+        let mut spectral_type = SpectralType::new(class, subclass, None);
+
+        let mut rest = rest;
+        if let Some((end, after_range)) = rest.strip_prefix(['-', '/']).and_then(parse_subclass) {
+            spectral_type = spectral_type.with_subclass_range_end(end);
+            rest = after_range;
+        }
+
+        if let Some((luminosity_class, after_class)) = parse_luminosity_class(rest) {
+            spectral_type.luminosity_class = Some(luminosity_class);
+            // The end of a range may continue the notation of its start, as in "Ia-ab".
+            let range_end = after_class.strip_prefix(['-', '/']).and_then(|range| {
+                if range.starts_with(['a', 'b']) {
+                    let continued = format!("I{}", range);
+                    parse_luminosity_class(&continued).map(|(class, _)| class)
+                } else {
+                    parse_luminosity_class(range).map(|(class, _)| class)
+                }
+            });
+            if let Some(end) = range_end {
+                spectral_type = spectral_type.with_luminosity_class_range_end(end);
+            }
+        }
+        Ok(spectral_type)
+    }
+}
+
+fn parse_subclass(notation: &str) -> Option<(f64, &str)> {
+    let length = notation
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(notation.len());
+    let subclass = notation[..length].parse::<f64>().ok()?;
+    if !(0. ..10.).contains(&subclass) {
+        return None;
+    }
+    Some((subclass, &notation[length..]))
+}
+
+fn parse_luminosity_class(notation: &str) -> Option<(LuminosityClass, &str)> {
+    LUMINOSITY_CLASS_NOTATIONS
+        .iter()
+        .find(|(prefix, _)| notation.starts_with(prefix))
+        .map(|(prefix, class)| (*class, &notation[prefix.len()..]))
+}
+
+fn display_subclass(subclass: f64) -> String {
+    if subclass.fract() == 0. {
+        format!("{:.0}", subclass)
+    } else {
+        format!("{}", subclass)
+    }
+}
+
+impl AstroDisplay for SpectralClass {
+    fn astro_display(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl AstroDisplay for LuminosityClass {
+    fn astro_display(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl AstroDisplay for SpectralType {
+    fn astro_display(&self) -> String {
+        let mut display = self.class.astro_display() + &display_subclass(self.subclass);
+        if let Some(end) = self.subclass_range_end {
+            display += &format!("-{}", display_subclass(end));
+        }
+        if let Some(luminosity_class) = self.luminosity_class {
+            display += &luminosity_class.astro_display();
+        }
+        if let Some(end) = self.luminosity_class_range_end {
+            let mut end_display = end.astro_display();
+            // Catalogues write Ia-Iab as Ia-ab.
+            let continues_start = self
+                .luminosity_class
+                .is_some_and(|start| start.is_supergiant() && end.is_supergiant());
+            if continues_start {
+                end_display.remove(0);
+            }
+            display += &format!("-{}", end_display);
+        }
+        display
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astro_units::{length::solar_radius, luminous_intensity::solar_luminous_intensity};
+    use uom::si::f64::Length;
+
+    use super::*;
+    use crate::tests::eq_within;
+
+    fn parsed(notation: &str) -> SpectralType {
+        notation.parse().unwrap()
+    }
+
+    #[test]
+    fn notations_are_parsed() {
+        let sun = parsed("G2V");
+        assert_eq!(sun.get_class(), SpectralClass::G);
+        assert_eq!(sun.get_subclass(), 2.);
+        assert_eq!(sun.get_luminosity_class(), Some(LuminosityClass::V));
+
+        let betelgeuse = parsed("M1-2Ia-ab");
+        assert_eq!(betelgeuse.get_subclass_range_end(), Some(2.));
+        assert_eq!(betelgeuse.get_luminosity_class(), Some(LuminosityClass::Ia));
+        assert_eq!(
+            betelgeuse.get_luminosity_class_range_end(),
+            Some(LuminosityClass::Iab)
+        );
+
+        let regulus = parsed("B8IVn");
+        assert_eq!(regulus.get_luminosity_class(), Some(LuminosityClass::IV));
+        let zeta_ophiuchi = parsed("O9.5V");
+        assert_eq!(zeta_ophiuchi.get_subclass(), 9.5);
+        let arcturus = parsed("K1.5IIIFe-0.5");
+        assert_eq!(arcturus.get_luminosity_class(), Some(LuminosityClass::III));
+        assert_eq!(arcturus.get_luminosity_class_range_end(), None);
+        assert_eq!(
+            parsed("K0III-IV").get_luminosity_class_range_end(),
+            Some(LuminosityClass::IV)
+        );
+        assert_eq!(
+            parsed("B1.5Ia+").get_luminosity_class(),
+            Some(LuminosityClass::Ia0)
+        );
+        assert_eq!(parsed("A0").get_luminosity_class(), None);
+    }
+
+    #[test]
+    fn invalid_notations_are_rejected() {
+        for notation in ["", "G", "X2V", "WC8+O7.5III", "DA2", "M12III"] {
+            assert!(notation.parse::<SpectralType>().is_err(), "{}", notation);
+        }
+    }
+
+    #[test]
+    fn display_reproduces_the_notation() {
+        for notation in [
+            "G2V",
+            "M1-2Ia-ab",
+            "O9.5Ib",
+            "K0III-IV",
+            "A0",
+            "B8Ia0",
+            "F5Ib-II",
+        ] {
+            assert_eq!(parsed(notation).astro_display(), notation);
+        }
+    }
+
+    #[test]
+    fn the_sun_is_a_g2_dwarf() {
+        let sun = SpectralType::from_temperature_and_luminous_intensity(
+            ThermodynamicTemperature::new::<kelvin>(5_772.),
+            solar_luminous_intensity(),
+        );
+        assert_eq!(sun.astro_display(), "G2V");
+
+        let parameters = parsed("G2V").typical_parameters();
+        assert!(eq_within(
+            parameters.temperature.get::<kelvin>(),
+            5_772.,
+            50.
+        ));
+        assert!(eq_within(
+            parameters.mass.unwrap().get::<solar_mass>(),
+            1.,
+            0.05
+        ));
+        let radius: Length = parameters.radius.unwrap();
+        assert!(eq_within(radius.get::<solar_radius>(), 1., 0.15));
+    }
+
+    #[test]
+    fn luminosity_class_follows_the_height_above_the_main_sequence() {
+        let temperature = ThermodynamicTemperature::new::<kelvin>(4_300.);
+        let class_at = |absolute_magnitude: f64| {
+            SpectralType::from_temperature_and_luminous_intensity(
+                temperature,
+                absolute_magnitude_to_luminous_intensity(absolute_magnitude),
+            )
+            .get_luminosity_class()
+            .unwrap()
+        };
+        assert_eq!(class_at(7.5), LuminosityClass::V);
+        assert_eq!(class_at(0.), LuminosityClass::III);
+        assert!(class_at(-6.).is_supergiant());
+        assert_eq!(class_at(-12.), LuminosityClass::Ia0);
+        assert_eq!(class_at(14.), LuminosityClass::VII);
+    }
+
+    #[test]
+    fn derived_types_reproduce_typical_parameters() {
+        for notation in ["B3V", "A5V", "F5V", "K2III", "M2Iab", "G5Ib", "K7V"] {
+            let spectral_type = parsed(notation);
+            let derived = SpectralType::from_temperature_and_luminous_intensity(
+                spectral_type.typical_temperature(),
+                spectral_type.typical_luminous_intensity(),
+            );
+            assert_eq!(derived.astro_display(), notation);
+        }
+    }
+
+    #[test]
+    fn giants_are_cooler_than_dwarfs_of_the_same_type() {
+        assert!(parsed("K0III").typical_temperature() < parsed("K0V").typical_temperature());
+        assert!(
+            parsed("K0III").typical_luminous_intensity()
+                > parsed("K0V").typical_luminous_intensity()
+        );
+        assert!(parsed("K0III").typical_parameters().mass.is_none());
+        assert_eq!(parsed("G8III").subclasses_between(&parsed("K1III")), 3.);
+    }
+}
@@ -0,0 +1,344 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+use uom::si::{
+    f64::{LuminousIntensity, ThermodynamicTemperature, Time},
+    time::day,
+};
+
+use crate::astro_display::AstroDisplay;
+
+/*
+ * A periodic change in brightness on top of the slow evolution of a star.
+ * The light curve is given in magnitudes, as the fraction of the full amplitude by which the star is fainter
+ * than at its brightest. The catalogued brightness of a variable star is its mean over a cycle.
+ * Pulsating stars change their brightness mostly through their temperature, while eclipses leave it untouched.
+ * https://en.wikipedia.org/wiki/Variable_star
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variability {
+    pub(super) kind: VariableKind,
+    pub(super) period: Time,
+    pub(super) amplitude: f64, // In magnitudes, from brightest to faintest
+    pub(super) phase_at_epoch: f64, // The fraction of a cycle that has passed at the epoch
+    pub(super) shape: LightCurveShape,
+    pub(super) temperature_share: f64, // Which part of the luminosity change is due to the temperature
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VariableKind {
+    ClassicalCepheid,
+    RRLyrae,
+    Mira,
+    SemiRegular,
+    EclipsingBinary,
+}
+
+/*
+ * Phase zero is the maximum of pulsating stars and the primary minimum of eclipsing binaries.
+ * Tabulated templates are sampled at equally spaced phases and interpolated linearly.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LightCurveShape {
+    Sinusoidal,
+    Sawtooth { rise_fraction: f64 },
+    Eclipse { width: f64, secondary_depth: f64 },
+    Template(Vec<f64>),
+}
+
+// Below this relative change in luminous intensity, a variable star is not considered to have changed.
+const NOTICEABLE_RELATIVE_CHANGE: f64 = 0.01;
+
+/*
+ * The period-luminosity relation of classical Cepheids in the V band, calibrated with HST parallaxes.
+ * https://en.wikipedia.org/wiki/Period-luminosity_relation
+ * https://ui.adsabs.harvard.edu/abs/2007AJ....133.1810B/abstract
+ */
+const CEPHEID_SLOPE: f64 = -2.43;
+const CEPHEID_ZERO_POINT: f64 = -4.05;
+
+pub fn cepheid_absolute_magnitude(period: Time) -> f64 {
+    CEPHEID_SLOPE * (period.get::<day>().log10() - 1.) + CEPHEID_ZERO_POINT
+}
+
+pub fn cepheid_period(absolute_magnitude: f64) -> Time {
+    let log_period = (absolute_magnitude - CEPHEID_ZERO_POINT) / CEPHEID_SLOPE + 1.;
+    Time::new::<day>(10f64.powf(log_period))
+}
+
+impl Variability {
+    pub fn new(
+        kind: VariableKind,
+        period: Time,
+        amplitude: f64,
+        phase_at_epoch: f64,
+        shape: LightCurveShape,
+    ) -> Self {
+        Self {
+            kind,
+            period,
+            amplitude,
+            phase_at_epoch,
+            shape,
+            temperature_share: kind.typical_temperature_share(),
+        }
+    }
+
+    // Cepheids rise steeply to their maximum and decline slowly.
+    pub fn classical_cepheid(period: Time, amplitude: f64, phase_at_epoch: f64) -> Self {
+        Self::new(
+            VariableKind::ClassicalCepheid,
+            period,
+            amplitude,
+            phase_at_epoch,
+            LightCurveShape::Sawtooth { rise_fraction: 0.3 },
+        )
+    }
+
+    pub fn with_temperature_share(mut self, temperature_share: f64) -> Self {
+        self.temperature_share = temperature_share;
+        self
+    }
+
+    pub fn get_kind(&self) -> VariableKind {
+        self.kind
+    }
+
+    pub fn get_period(&self) -> Time {
+        self.period
+    }
+
+    pub fn get_amplitude(&self) -> f64 {
+        self.amplitude
+    }
+
+    pub fn get_phase_at_epoch(&self) -> f64 {
+        self.phase_at_epoch
+    }
+
+    pub fn get_shape(&self) -> &LightCurveShape {
+        &self.shape
+    }
+
+    pub fn phase(&self, time_since_epoch: Time) -> f64 {
+        (self.phase_at_epoch + (time_since_epoch / self.period).value).rem_euclid(1.)
+    }
+
+    // The difference to the mean magnitude, positive when the star is fainter.
+    pub fn magnitude_offset(&self, time_since_epoch: Time) -> f64 {
+        let phase = self.phase(time_since_epoch);
+        self.amplitude * (self.shape.fading(phase) - self.shape.mean_fading())
+    }
+
+    pub(super) fn apply_to_luminous_intensity(
+        &self,
+        luminous_intensity: LuminousIntensity,
+        time_since_epoch: Time,
+    ) -> LuminousIntensity {
+        luminous_intensity * self.luminous_intensity_factor(time_since_epoch)
+    }
+
+    // With L ~ T^4, the temperature follows the fourth root of its share of the luminosity change.
+    pub(super) fn apply_to_temperature(
+        &self,
+        temperature: ThermodynamicTemperature,
+        time_since_epoch: Time,
+    ) -> ThermodynamicTemperature {
+        let factor = self.luminous_intensity_factor(time_since_epoch);
+        temperature * factor.powf(self.temperature_share / 4.)
+    }
+
+    pub(super) fn has_changed(&self, then: Time, now: Time) -> bool {
+        let ratio = self.luminous_intensity_factor(now) / self.luminous_intensity_factor(then);
+        (ratio - 1.).abs() > NOTICEABLE_RELATIVE_CHANGE
+    }
+
+    fn luminous_intensity_factor(&self, time_since_epoch: Time) -> f64 {
+        10f64.powf(-0.4 * self.magnitude_offset(time_since_epoch))
+    }
+}
+
+impl VariableKind {
+    /*
+     * Cepheids and RR Lyrae stars change mainly their temperature. The visual light of Miras is dominated by
+     * molecular absorption that forms as they cool, so their temperature changes only little.
+     * https://en.wikipedia.org/wiki/Mira_variable
+     */
+    fn typical_temperature_share(&self) -> f64 {
+        match self {
+            VariableKind::ClassicalCepheid => 1.,
+            VariableKind::RRLyrae => 1.,
+            VariableKind::Mira => 0.05,
+            VariableKind::SemiRegular => 0.15,
+            VariableKind::EclipsingBinary => 0.,
+        }
+    }
+}
+
+impl LightCurveShape {
+    // From 0 at the brightest to 1 at the faintest.
+    fn fading(&self, phase: f64) -> f64 {
+        match self {
+            LightCurveShape::Sinusoidal => (1. - (2. * PI * phase).cos()) / 2.,
+            LightCurveShape::Sawtooth { rise_fraction } => {
+                let decline = 1. - rise_fraction;
+                if phase < decline {
+                    phase / decline
+                } else {
+                    (1. - phase) / rise_fraction
+                }
+            }
+            LightCurveShape::Eclipse {
+                width,
+                secondary_depth,
+            } => {
+                let from_primary = phase.min(1. - phase);
+                let from_secondary = (phase - 0.5).abs();
+                let depth = |distance: f64| (1. - 2. * distance / width).max(0.);
+                depth(from_primary).max(secondary_depth * depth(from_secondary))
+            }
+            LightCurveShape::Template(samples) => {
+                if samples.is_empty() {
+                    return 0.;
+                }
+                let position = phase * samples.len() as f64;
+                let index = position.floor() as usize % samples.len();
+                let next = (index + 1) % samples.len();
+                let fraction = position - position.floor();
+                samples[index] + (samples[next] - samples[index]) * fraction
+            }
+        }
+    }
+
+    fn mean_fading(&self) -> f64 {
+        match self {
+            LightCurveShape::Sinusoidal => 0.5,
+            LightCurveShape::Sawtooth { .. } => 0.5,
+            LightCurveShape::Eclipse {
+                width,
+                secondary_depth,
+            } => width / 2. * (1. + secondary_depth),
+            LightCurveShape::Template(samples) => {
+                if samples.is_empty() {
+                    return 0.;
+                }
+                samples.iter().sum::<f64>() / samples.len() as f64
+            }
+        }
+    }
+}
+
+impl AstroDisplay for VariableKind {
+    fn astro_display(&self) -> String {
+        match self {
+            VariableKind::ClassicalCepheid => "Classical Cepheid".to_string(),
+            VariableKind::RRLyrae => "RR Lyrae Variable".to_string(),
+            VariableKind::Mira => "Mira Variable".to_string(),
+            VariableKind::SemiRegular => "Semiregular Variable".to_string(),
+            VariableKind::EclipsingBinary => "Eclipsing Binary".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{luminous_intensity::candela, thermodynamic_temperature::kelvin};
+
+    use super::*;
+    use crate::tests::eq_within;
+
+    fn delta_cephei() -> Variability {
+        Variability::classical_cepheid(Time::new::<day>(5.366), 0.89, 0.)
+    }
+
+    #[test]
+    fn brightness_repeats_after_one_period() {
+        let variable = delta_cephei();
+        for days in [0., 1.3, 2.7, 4.1] {
+            let time = Time::new::<day>(days);
+            let later = time + variable.get_period() * 3.;
+            assert!(eq_within(
+                variable.magnitude_offset(time),
+                variable.magnitude_offset(later),
+                1e-9
+            ));
+        }
+    }
+
+    #[test]
+    fn magnitude_spans_the_amplitude_around_the_mean() {
+        let shapes = [
+            LightCurveShape::Sinusoidal,
+            LightCurveShape::Sawtooth { rise_fraction: 0.3 },
+            LightCurveShape::Eclipse {
+                width: 0.15,
+                secondary_depth: 0.05,
+            },
+            LightCurveShape::Template(vec![0., 0.2, 1., 0.6, 0.1]),
+        ];
+        for shape in shapes {
+            let variable = Variability::new(
+                VariableKind::SemiRegular,
+                Time::new::<day>(100.),
+                2.,
+                0.,
+                shape,
+            );
+            let offsets: Vec<f64> = (0..10_000)
+                .map(|i| variable.magnitude_offset(Time::new::<day>(i as f64 / 100.)))
+                .collect();
+            let min = offsets.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = offsets.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let mean = offsets.iter().sum::<f64>() / offsets.len() as f64;
+            assert!(eq_within(max - min, 2., 0.01));
+            assert!(eq_within(mean, 0., 0.01));
+        }
+    }
+
+    #[test]
+    fn cepheids_are_hotter_when_brighter() {
+        let variable = delta_cephei();
+        let temperature = ThermodynamicTemperature::new::<kelvin>(6_000.);
+        let luminous_intensity = LuminousIntensity::new::<candela>(1.);
+        let at_maximum = Time::new::<day>(0.);
+        let at_minimum = variable.get_period() * 0.7;
+        assert!(
+            variable.apply_to_luminous_intensity(luminous_intensity, at_maximum)
+                > variable.apply_to_luminous_intensity(luminous_intensity, at_minimum)
+        );
+        let hot = variable.apply_to_temperature(temperature, at_maximum);
+        let cool = variable.apply_to_temperature(temperature, at_minimum);
+        assert!(hot > cool);
+        // Delta Cephei varies between about 5500 K and 6800 K.
+        assert!((1.1..1.4).contains(&(hot / cool).value));
+    }
+
+    #[test]
+    fn eclipses_leave_the_temperature_unchanged() {
+        let algol = Variability::new(
+            VariableKind::EclipsingBinary,
+            Time::new::<day>(2.867),
+            1.3,
+            0.,
+            LightCurveShape::Eclipse {
+                width: 0.145,
+                secondary_depth: 0.05,
+            },
+        );
+        let temperature = ThermodynamicTemperature::new::<kelvin>(13_000.);
+        let in_eclipse = Time::new::<day>(0.);
+        assert_eq!(
+            algol.apply_to_temperature(temperature, in_eclipse),
+            temperature
+        );
+        assert!(algol.magnitude_offset(in_eclipse) > 1.);
+    }
+
+    #[test]
+    fn period_luminosity_relation_is_invertible() {
+        let period = Time::new::<day>(30.);
+        let magnitude = cepheid_absolute_magnitude(period);
+        assert!(magnitude < cepheid_absolute_magnitude(Time::new::<day>(3.)));
+        assert!(eq_within(cepheid_period(magnitude).get::<day>(), 30., 1e-9));
+    }
+}
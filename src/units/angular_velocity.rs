@@ -0,0 +1,60 @@
+use uom::si::{angular_velocity::radian_per_second, f64::AngularVelocity};
+
+use crate::astro_display::AstroDisplay;
+
+// Julian year in seconds, the time unit of catalogued proper motions.
+const SECONDS_PER_JULIAN_YEAR: f64 = 365.25 * 86_400.;
+const RADIANS_PER_MILLIARCSECOND: f64 = std::f64::consts::PI / (180. * 3_600. * 1_000.);
+
+pub fn angular_velocity_from_mas_per_year(mas_per_year: f64) -> AngularVelocity {
+    AngularVelocity::new::<radian_per_second>(
+        mas_per_year * RADIANS_PER_MILLIARCSECOND / SECONDS_PER_JULIAN_YEAR,
+    )
+}
+
+pub fn angular_velocity_to_mas_per_year(angular_velocity: AngularVelocity) -> f64 {
+    angular_velocity.get::<radian_per_second>() * SECONDS_PER_JULIAN_YEAR
+        / RADIANS_PER_MILLIARCSECOND
+}
+
+impl AstroDisplay for AngularVelocity {
+    fn astro_display(&self) -> String {
+        format!("{:.2} mas/yr", angular_velocity_to_mas_per_year(*self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uom::si::{
+        angle::{radian, second},
+        f64::Angle,
+    };
+
+    use crate::tests::eq;
+
+    use super::*;
+
+    #[test]
+    fn mas_per_year_roundtrip() {
+        let angular_velocity = angular_velocity_from_mas_per_year(-1223.07);
+        assert!(eq(
+            angular_velocity_to_mas_per_year(angular_velocity),
+            -1223.07
+        ));
+    }
+
+    #[test]
+    fn one_arcsecond_per_julian_year() {
+        let angular_velocity = angular_velocity_from_mas_per_year(1000.);
+        let angle = Angle::new::<radian>(
+            angular_velocity.get::<radian_per_second>() * SECONDS_PER_JULIAN_YEAR,
+        );
+        assert!(eq(angle.get::<second>(), 1.));
+    }
+
+    #[test]
+    fn test_angular_velocity_display() {
+        let angular_velocity = angular_velocity_from_mas_per_year(1.23);
+        assert_eq!(angular_velocity.astro_display(), "1.23 mas/yr");
+    }
+}
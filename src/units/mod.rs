@@ -1,5 +1,6 @@
 // https://www.astro.princeton.edu/~gk/A403/constants.pdf
 
+pub mod angular_velocity;
 pub mod length;
 pub mod luminous_intensity;
 pub mod mass;